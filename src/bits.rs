@@ -0,0 +1,79 @@
+/// Determines whether or not a number is a power of two.
+///
+/// A power of two has exactly one bit set, so clearing its lowest set bit with $n \mathbin{\\&} (n - 1)$ leaves $0$.
+///
+/// [A000079](https://oeis.org/A000079)
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+///
+/// # Returns
+///
+/// Boolean representing whether or not $n = 2^k$ for some $k \ge 0$. Zero and negative numbers are never powers of two.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::bits::is_power_of_two;
+/// assert_eq!(is_power_of_two(64), true);
+/// assert_eq!(is_power_of_two(48), false);
+/// assert_eq!(is_power_of_two(1_u8), true);
+/// ```
+pub fn is_power_of_two<T>(n: T) -> bool
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + std::ops::Sub<Output = T>
+        + std::ops::BitAnd<Output = T>
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    n > T::zero() && (n & (n - T::one())) == T::zero()
+}
+
+/// Calculates the floor of the base 2 logarithm of a number.
+///
+/// $$\lfloor \log_2 n \rfloor$$
+///
+/// This is the position of the highest set bit, found by shifting right until only that bit is left.
+///
+/// # Arguments
+///
+/// * `n` - The number to find the logarithm of.
+///
+/// # Returns
+///
+/// The largest $k$ with $2^k \le n$.
+///
+/// # Panics
+///
+/// Panics if `n` is not positive, since the logarithm is undefined.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::bits::log2_floor;
+/// assert_eq!(log2_floor(1), 0);
+/// assert_eq!(log2_floor(64), 6);
+/// assert_eq!(log2_floor(100_u64), 6);
+/// ```
+pub fn log2_floor<T>(n: T) -> u32
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + std::ops::Shr<u32, Output = T>
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    if n <= T::zero() {
+        panic!("The logarithm is only defined for positive numbers");
+    }
+    let mut n: T = n;
+    let mut k: u32 = 0;
+    while n > T::one() {
+        n = n >> 1;
+        k += 1;
+    }
+    k
+}
@@ -1,5 +1,5 @@
-use crate::factorials::factorial;
-use num::{rational::Ratio, BigInt, BigRational, FromPrimitive};
+use num::{rational::Ratio, BigInt, BigRational, FromPrimitive, Integer, One, Zero};
+use std::sync::{Mutex, OnceLock};
 
 /// Calculate a ratio representing the value of $\pi$ using the *Ramanujan–Sato series*
 ///
@@ -28,30 +28,46 @@ use num::{rational::Ratio, BigInt, BigRational, FromPrimitive};
 /// assert!(estimate_pi_ratio(1).to_string().ends_with("899151951"));
 /// ```
 pub fn estimate_pi_ratio(n: u32) -> BigRational {
+    ramanujan_sato_pi(n, 10)
+}
+
+/// Sums the Ramanujan–Sato series for $\pi$ given in `estimate_pi_ratio`.
+///
+/// `estimate_pi_ratio` always uses 10 iterations for $\sqrt{2}$ (enough for several hundred digits),
+/// while `pi_cached` picks the number of iterations from the digits it needs.
+///
+/// # Arguments
+///
+/// * `n` - The number of terms of the series to add.
+/// * `sqrt_iterations` - The number of Newton iterations used to approximate $\sqrt{2}$.
+///
+/// # Returns
+///
+/// A BigRational representing $\pi$, good to about 8 digits per term
+/// as long as $\sqrt{2}$ is precise enough.
+fn ramanujan_sato_pi(n: u32, sqrt_iterations: usize) -> BigRational {
     // A
-    let a_root_two: BigRational = approx_sqrt(2, 10_usize);
-    let a_two: BigRational = BigRational::from_integer(FromPrimitive::from_u64(2).unwrap());
-    let a_denom: BigRational = BigRational::from_integer(FromPrimitive::from_u64(9801).unwrap());
-    let a: BigRational = (a_two * a_root_two) / a_denom;
+    let a: BigRational = BigRational::from_integer(BigInt::from(2))
+        * approx_sqrt(2, sqrt_iterations)
+        / BigRational::from_integer(BigInt::from(9801));
 
-    let mut i: u32 = 0;
-    let mut sum: BigRational = BigRational::from_integer(FromPrimitive::from_u64(0).unwrap());
-    while i < n {
-        // B_n
-        let b_top: BigRational =
-            BigRational::from(factorial(BigInt::from(4 * i)) * (1103 + 26390 * i));
-        let b_bot: BigRational =
-            BigRational::from(BigInt::from(4_u32).pow(4 * i) * factorial(BigInt::from(i)).pow(4));
-        let b_n: BigRational = b_top / b_bot;
-
-        // C_n
-        let c_n_big_int: BigInt = BigInt::from(99).pow(4 * i) * (factorial(BigInt::from(i))).pow(4);
-        let c_n: BigRational = BigRational::from(c_n_big_int).recip();
-
-        sum += b_n * c_n;
-        i += 1;
+    // B_n C_n = (4n)! (1103 + 26390n) / (396^{4n} (n!)^4), so each term's factorials and powers are built
+    // from the last one's, and the terms are added over their common denominator to avoid a gcd per term
+    let mut four_i_factorial: BigInt = BigInt::one();
+    let mut denominator: BigInt = BigInt::one();
+    let mut numerator: BigInt = BigInt::zero();
+    for i in 0..n as u64 {
+        if i > 0 {
+            for j in 4 * i - 3..=4 * i {
+                four_i_factorial *= j;
+            }
+            let step: BigInt = BigInt::from(396 * i).pow(4);
+            numerator *= &step;
+            denominator *= step;
+        }
+        numerator += &four_i_factorial * (1103 + 26390 * i);
     }
-    (a * sum).recip()
+    (a * BigRational::new(numerator, denominator)).recip()
 }
 
 /// Function taken from the num-crate documentation
@@ -155,20 +171,351 @@ fn approx_sqrt(number: u64, iterations: usize) -> BigRational {
 /// $$a = \frac{U_1 - U_0 \psi}{\sqrt{5}} \\\\
 /// b = \frac{U_0\varphi - U_1}{\sqrt{5}}$$
 ///
+/// The result is $\frac{L_{n-1}}{L_{n-2}}$, the ratio of the last two of the first `n` Lucas numbers
+/// (starting from $L_0 = 2$). Since consecutive Lucas numbers are coprime, this is already in lowest terms.
+///
+/// # Arguments
+///
+/// * `n` - The number of Lucas numbers to generate. Higher values give better precision.
+///
+/// # Returns
+///
+/// A ratio approximating $\varphi$, or 0 if `n` is less than 2.
+///
 /// # Example
 ///
 /// ```
-/// use num::BigInt;
+/// use num::{BigInt, rational::Ratio};
 /// use bens_number_theory::constants::golden_ratio;
 ///
 /// println!("{}", golden_ratio(BigInt::from(10)));
+/// assert_eq!(golden_ratio(BigInt::from(5)), Ratio::new(BigInt::from(7), BigInt::from(4)));
 /// ```
 pub fn golden_ratio(n: BigInt) -> Ratio<BigInt> {
     if n < BigInt::from(2) {
         return BigRational::from_i32(0_i32).unwrap();
     }
-    let mut lucas: Vec<BigInt> = crate::sequences::lucas_sequence(n);
-    let numerator: Ratio<BigInt> = BigRational::from(lucas.pop().unwrap());
-    let demom: Ratio<BigInt> = BigRational::from(lucas.pop().unwrap());
-    numerator / demom
+    // only the last two Lucas numbers are needed, so there's no need to keep the whole sequence
+    let mut previous: BigInt = BigInt::from(2);
+    let mut current: BigInt = BigInt::one();
+    let mut i: BigInt = BigInt::from(2);
+    while i < n {
+        let next: BigInt = &previous + &current;
+        previous = std::mem::replace(&mut current, next);
+        i += 1;
+    }
+    // consecutive Lucas numbers are coprime, so the fraction is already in lowest terms
+    debug_assert!(current.gcd(&previous).is_one());
+    Ratio::new_raw(current, previous)
+}
+
+/// Evaluates a power series (or polynomial) exactly using *Horner's method*.
+///
+/// $$\sum_{k=0}^{n} c_k x^k = c_0 + x(c_1 + x(c_2 + \cdots + x(c_{n-1} + x c_n)))$$
+///
+/// Since everything stays a `BigRational`, there is no rounding at any step,
+/// which makes this a good building block for estimating constants from truncated series
+/// (such as the Taylor series of $e^x$).
+///
+/// # Arguments
+///
+/// * `coeffs` - The coefficients $c_0, c_1, \ldots, c_n$, starting with the constant term.
+/// * `x` - The value to evaluate the series at.
+///
+/// # Returns
+///
+/// The exact value of the series at `x`. An empty series evaluates to 0.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::constants::eval_power_series;
+/// use num::{BigInt, BigRational};
+///
+/// let coeffs: Vec<BigRational> = [1, 2, 3]
+///     .iter()
+///     .map(|&c| BigRational::from(BigInt::from(c)))
+///     .collect();
+/// let x = BigRational::from(BigInt::from(2));
+/// assert_eq!(eval_power_series(&coeffs, &x), BigRational::from(BigInt::from(17)));
+/// ```
+pub fn eval_power_series(coeffs: &[BigRational], x: &BigRational) -> BigRational {
+    let mut result: BigRational = BigRational::zero();
+    for c in coeffs.iter().rev() {
+        result = result * x + c;
+    }
+    result
+}
+
+/// Calculate a ratio representing the natural logarithm of `x` using the *inverse hyperbolic tangent series*
+///
+/// $$\ln(x) = 2\operatorname{artanh}\left(\frac{x - 1}{x + 1}\right) = 2\sum_{k = 0}^{\infty}\frac{1}{2k + 1}\left(\frac{x - 1}{x + 1}\right)^{2k + 1}$$
+///
+/// Since $\left|\frac{x - 1}{x + 1}\right| < 1$ for every $x > 0$, this converges for all positive `x`,
+/// and much faster than the Mercator series $\ln(1 + x) = x - \frac{x^2}{2} + \frac{x^3}{3} - \cdots$
+/// (each term of $\ln(2)$ gains almost a full decimal digit).
+///
+/// # Arguments
+///
+/// * `x` - The positive value to take the logarithm of.
+/// * `terms` - The number of terms of the series to sum. Higher values give better precision.
+///
+/// # Returns
+///
+/// A BigRational representing $\ln(x)$.
+///
+/// # Panics
+///
+/// Panics if `x` is not positive.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::constants::estimate_ln_ratio;
+/// use num::{BigInt, BigRational};
+///
+/// let ln_two = estimate_ln_ratio(&BigRational::from(BigInt::from(2)), 30);
+/// let digits = (ln_two * BigRational::from(BigInt::from(10).pow(10))).to_integer();
+/// assert_eq!(digits, BigInt::from(6931471805_u64));
+/// ```
+pub fn estimate_ln_ratio(x: &BigRational, terms: usize) -> BigRational {
+    if *x <= BigRational::zero() {
+        panic!("The natural logarithm is only defined for positive numbers");
+    }
+    let one: BigRational = BigRational::one();
+    let y: BigRational = (x - &one) / (x + &one);
+    let y_squared: BigRational = &y * &y;
+
+    let mut power: BigRational = y;
+    let mut sum: BigRational = BigRational::zero();
+    for k in 0..terms {
+        sum += &power / BigRational::from(BigInt::from(2 * k + 1));
+        power *= &y_squared;
+    }
+    sum * BigRational::from(BigInt::from(2))
+}
+
+/// The most precise value of $\pi$ computed by `pi_cached` so far, along with its number of digits.
+static PI_CACHE: OnceLock<Mutex<Option<(usize, BigRational)>>> = OnceLock::new();
+/// The most precise value of $e$ computed by `e_cached` so far, along with its number of digits.
+static E_CACHE: OnceLock<Mutex<Option<(usize, BigRational)>>> = OnceLock::new();
+/// The most precise value of $\varphi$ computed by `golden_ratio_cached` so far, along with its number of digits.
+static GOLDEN_RATIO_CACHE: OnceLock<Mutex<Option<(usize, BigRational)>>> = OnceLock::new();
+
+/// Extra digits computed beyond what was asked for, so truncation never lands on an inaccurate digit.
+const GUARD_DIGITS: usize = 10;
+
+/// Calculates $\pi$ truncated to `digits` decimal places, reusing previous results.
+///
+/// The most precise value computed so far (from the series in `estimate_pi_ratio`) is kept in a thread-safe cache,
+/// and is only recomputed when more digits are requested than have been cached.
+///
+/// # Arguments
+///
+/// * `digits` - The number of digits after the decimal point.
+///
+/// # Returns
+///
+/// A BigRational equal to $\frac{\lfloor \pi \cdot 10^{d} \rfloor}{10^{d}}$.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::constants::pi_cached;
+/// use num::{BigInt, BigRational};
+///
+/// assert_eq!(pi_cached(4), BigRational::new(BigInt::from(31415), BigInt::from(10000)));
+/// ```
+pub fn pi_cached(digits: usize) -> BigRational {
+    cached_constant(&PI_CACHE, digits, |d| {
+        // each term of the Ramanujan–Sato series gives log10(99^4) ≈ 7.98 digits, so assume 7 to stay ahead,
+        // and each Newton iteration doubles the correct digits of sqrt(2)
+        let sqrt_iterations: usize = (d as u64 + 16).next_power_of_two().ilog2() as usize + 1;
+        ramanujan_sato_pi((d / 7 + 2) as u32, sqrt_iterations)
+    })
+}
+
+/// Calculates $e$ truncated to `digits` decimal places, reusing previous results.
+///
+/// $e$ is computed from its Taylor series $\sum_{k=0}^{\infty}\frac{1}{k!}$ (stopping once $\frac{1}{k!}$ is too small to matter),
+/// and the most precise value so far is kept in a thread-safe cache.
+///
+/// # Arguments
+///
+/// * `digits` - The number of digits after the decimal point.
+///
+/// # Returns
+///
+/// A BigRational equal to $\frac{\lfloor e \cdot 10^{d} \rfloor}{10^{d}}$.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::constants::e_cached;
+/// use num::{BigInt, BigRational};
+///
+/// assert_eq!(e_cached(4), BigRational::new(BigInt::from(27182), BigInt::from(10000)));
+/// ```
+pub fn e_cached(digits: usize) -> BigRational {
+    cached_constant(&E_CACHE, digits, |d| {
+        let bound: BigInt = BigInt::from(10).pow(d as u32);
+        let mut k_factorial: BigInt = BigInt::one();
+        let mut k: u32 = 0;
+        let mut sum: BigRational = BigRational::zero();
+        while k_factorial <= bound {
+            sum += BigRational::from(k_factorial.clone()).recip();
+            k += 1;
+            k_factorial *= k;
+        }
+        sum
+    })
+}
+
+/// Calculates the golden ratio $\varphi$ truncated to `digits` decimal places, reusing previous results.
+///
+/// $\varphi$ is computed by `golden_ratio`, and the most precise value so far is kept in a thread-safe cache.
+///
+/// # Arguments
+///
+/// * `digits` - The number of digits after the decimal point.
+///
+/// # Returns
+///
+/// A BigRational equal to $\frac{\lfloor \varphi \cdot 10^{d} \rfloor}{10^{d}}$.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::constants::golden_ratio_cached;
+/// use num::{BigInt, BigRational};
+///
+/// assert_eq!(golden_ratio_cached(4), BigRational::new(BigInt::from(16180), BigInt::from(10000)));
+/// ```
+pub fn golden_ratio_cached(digits: usize) -> BigRational {
+    cached_constant(&GOLDEN_RATIO_CACHE, digits, |d| {
+        // the error of L_n / L_{n-1} shrinks by a factor of phi^2 (about 0.42 digits) each term
+        golden_ratio(BigInt::from(d * 5 / 2 + 2))
+    })
+}
+
+/// Looks up a constant in a cache, computing (and caching) a more precise value if needed.
+///
+/// # Arguments
+///
+/// * `cache` - The cache holding the most precise value so far and its number of digits.
+/// * `digits` - The number of digits after the decimal point to return.
+/// * `compute` - Calculates the constant to (at least) the given number of digits.
+///
+/// # Returns
+///
+/// The constant truncated to `digits` decimal places.
+fn cached_constant(
+    cache: &OnceLock<Mutex<Option<(usize, BigRational)>>>,
+    digits: usize,
+    compute: impl Fn(usize) -> BigRational,
+) -> BigRational {
+    let mut cached = cache.get_or_init(|| Mutex::new(None)).lock().unwrap();
+    match &*cached {
+        Some((cached_digits, value)) if *cached_digits >= digits => truncate_ratio(value, digits),
+        _ => {
+            let value: BigRational = truncate_ratio(&compute(digits + GUARD_DIGITS), digits);
+            *cached = Some((digits, value.clone()));
+            value
+        }
+    }
+}
+
+/// Truncates a non-negative rational to a given number of decimal places.
+///
+/// # Arguments
+///
+/// * `r` - The rational to truncate.
+/// * `digits` - The number of digits after the decimal point to keep.
+///
+/// # Returns
+///
+/// $\frac{\lfloor r \cdot 10^{d} \rfloor}{10^{d}}$
+fn truncate_ratio(r: &BigRational, digits: usize) -> BigRational {
+    let scale: BigInt = BigInt::from(10).pow(digits as u32);
+    BigRational::new((r * &scale).floor().to_integer(), scale)
+}
+
+/// Generates the coefficients of the continued fraction of $e$.
+///
+/// Unlike most irrational numbers, $e$ has a simple pattern in its continued fraction:
+/// $$e = [2; 1, 2, 1, 1, 4, 1, 1, 6, 1, \ldots]$$
+/// where (after the leading 2) the terms come in groups of three $1, 2k, 1$.
+/// The coefficients are generated directly from this pattern, rather than from an estimate of $e$.
+///
+/// [A003417](https://oeis.org/A003417)
+///
+/// # Arguments
+///
+/// * `terms` - The number of coefficients to generate.
+///
+/// # Returns
+///
+/// Vector of the first `terms` coefficients.
+///
+/// # Example
+///
+/// ```
+/// use bens_number_theory::constants::e_continued_fraction;
+///
+/// assert_eq!(e_continued_fraction(10), vec![2, 1, 2, 1, 1, 4, 1, 1, 6, 1]);
+/// ```
+pub fn e_continued_fraction(terms: usize) -> Vec<u64> {
+    (0..terms as u64)
+        .map(|i| match i {
+            0 => 2,
+            // the middle of each group of three is 2k
+            _ if i % 3 == 2 => 2 * (i / 3 + 1),
+            _ => 1,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cached_constant, ramanujan_sato_pi, truncate_ratio};
+    use num::{BigInt, BigRational};
+    use std::str::FromStr;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Mutex, OnceLock};
+
+    #[test]
+    fn cached_constant_hits_test() {
+        static CACHE: OnceLock<Mutex<Option<(usize, BigRational)>>> = OnceLock::new();
+        let computations: AtomicUsize = AtomicUsize::new(0);
+        let compute = |d: usize| {
+            computations.fetch_add(1, Ordering::SeqCst);
+            ramanujan_sato_pi((d / 7 + 2) as u32, 12)
+        };
+
+        let first: BigRational = cached_constant(&CACHE, 40, compute);
+        assert_eq!(computations.load(Ordering::SeqCst), 1);
+        // the same or fewer digits are served from the cache
+        assert_eq!(cached_constant(&CACHE, 40, compute), first);
+        assert_eq!(
+            cached_constant(&CACHE, 10, compute),
+            truncate_ratio(&first, 10)
+        );
+        assert_eq!(computations.load(Ordering::SeqCst), 1);
+        // more digits than cached have to be computed
+        cached_constant(&CACHE, 60, compute);
+        assert_eq!(computations.load(Ordering::SeqCst), 2);
+        cached_constant(&CACHE, 50, compute);
+        assert_eq!(computations.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn ramanujan_sato_pi_test() {
+        // each term of the series adds about 8 digits
+        let pi: BigRational = ramanujan_sato_pi(5, 10);
+        let digits: BigInt = (pi * BigRational::from(BigInt::from(10).pow(38))).to_integer();
+        assert_eq!(
+            digits,
+            BigInt::from_str("314159265358979323846264338327950288419").unwrap()
+        );
+    }
 }
@@ -0,0 +1,1055 @@
+use crate::factorials::factorial;
+use crate::primes::{is_prime_lazy, passes_first_prime_bases, prime_factorization, prime_factors};
+use num::BigInt;
+use std::collections::HashSet;
+
+/// Calculates the sum of the decimal digits of a number.
+///
+/// # Arguments
+///
+/// * `n` - The (non-negative) number to sum the digits of.
+///
+/// # Returns
+///
+/// The sum of the digits of `n`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::digit_sum;
+/// assert_eq!(digit_sum(0), 0);
+/// assert_eq!(digit_sum(1234), 10);
+/// assert_eq!(digit_sum(99_u8), 18_u8);
+/// ```
+pub fn digit_sum<T>(n: T) -> T
+where
+    T: num::traits::Zero
+        + num::FromPrimitive
+        + std::ops::Div<Output = T>
+        + std::ops::Rem<Output = T>
+        + std::ops::AddAssign
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    let ten: T = T::from_u8(10).unwrap();
+    let mut n: T = n;
+    let mut sum: T = T::zero();
+    while n > T::zero() {
+        sum += n % ten;
+        n = n / ten;
+    }
+    sum
+}
+
+/// Determines whether or not a given number is a *Smith number*.
+///
+/// A Smith number is a composite number whose digit sum is equal to the sum of the digits of its prime factors
+/// (counted with multiplicity).
+/// For example, $22 = 2 \times 11$ is a Smith number, since $2 + 2 = 2 + (1 + 1)$.
+///
+/// Primes would trivially satisfy this, so they are excluded by definition.
+///
+/// [A006753](https://oeis.org/A006753)
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+///
+/// # Returns
+///
+/// Boolean representing whether or not `n` is a Smith number.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::is_smith;
+/// assert_eq!(is_smith(22), true);
+/// assert_eq!(is_smith(23), false); // prime
+/// assert_eq!(is_smith(24), false);
+/// ```
+pub fn is_smith(n: u64) -> bool {
+    let factors: Vec<u64> = prime_factors(n);
+    if factors.len() < 2 {
+        // 0, 1, and primes
+        return false;
+    }
+    let factor_digit_sum: u64 = factors.into_iter().map(digit_sum).sum();
+    digit_sum(n) == factor_digit_sum
+}
+
+/// Calculates the base 10 *repunit* $R_k$, the number made of `k` ones.
+///
+/// $$R_k = \frac{10^k - 1}{9}$$
+///
+/// [A002275](https://oeis.org/A002275)
+///
+/// # Arguments
+///
+/// * `k` - The number of ones.
+///
+/// # Returns
+///
+/// $R_k$ as a `BigInt`, since repunits quickly outgrow the primitive types.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::repunit;
+/// use num::BigInt;
+///
+/// assert_eq!(repunit(0), BigInt::from(0));
+/// assert_eq!(repunit(3), BigInt::from(111));
+/// ```
+pub fn repunit(k: u32) -> BigInt {
+    (BigInt::from(10).pow(k) - 1) / 9
+}
+
+/// Determines whether or not a given number is a base 10 *repunit* (made only of ones).
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+///
+/// # Returns
+///
+/// Boolean representing whether or not `n` is a repunit.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::is_repunit;
+/// use num::BigInt;
+///
+/// assert_eq!(is_repunit(&BigInt::from(1111)), true);
+/// assert_eq!(is_repunit(&BigInt::from(1011)), false);
+/// ```
+pub fn is_repunit(n: &BigInt) -> bool {
+    n > &BigInt::from(0) && n.to_radix_le(10).1.iter().all(|&d| d == 1)
+}
+
+/// Determines whether or not a given number is a *repdigit* (made of a single repeated digit) in a given base.
+///
+/// # Arguments
+///
+/// * `n` - The (non-negative) number to check.
+/// * `radix` - The base to write `n` in, between 2 and 36.
+///
+/// # Returns
+///
+/// Boolean representing whether or not every digit of `n` in base `radix` is the same.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::is_repdigit;
+/// use num::BigInt;
+///
+/// assert_eq!(is_repdigit(&BigInt::from(777), 10), true);
+/// assert_eq!(is_repdigit(&BigInt::from(255), 16), true); // ff
+/// assert_eq!(is_repdigit(&BigInt::from(255), 10), false);
+/// ```
+pub fn is_repdigit(n: &BigInt, radix: u32) -> bool {
+    if n < &BigInt::from(0) {
+        return false;
+    }
+    // work with the digit values directly rather than formatting a string
+    let (_, digits): (_, Vec<u8>) = n.to_radix_le(radix);
+    digits.iter().all(|&d| d == digits[0])
+}
+
+/// Determines whether or not the repunit $R_k$ is prime.
+///
+/// $R_k$ can only be prime when `k` is prime, since $R_a$ divides $R_{ab}$.
+/// For prime `k`, $R_k$ is checked with a strong probable prime test to each of the first twelve prime bases,
+/// which is deterministic for $R_k < 3.18 \times 10^{23}$ and overwhelmingly likely to be right beyond that.
+///
+/// [A004023](https://oeis.org/A004023)
+///
+/// # Arguments
+///
+/// * `k` - The number of ones in the repunit.
+///
+/// # Returns
+///
+/// Boolean representing whether or not $R_k$ is (probably) prime.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::is_repunit_prime;
+/// assert_eq!(is_repunit_prime(2), true); // 11
+/// assert_eq!(is_repunit_prime(3), false); // 111 = 3 * 37
+/// assert_eq!(is_repunit_prime(19), true);
+/// ```
+pub fn is_repunit_prime(k: u32) -> bool {
+    if !is_prime_lazy(k) {
+        return false;
+    }
+    passes_first_prime_bases(&repunit(k))
+}
+
+/// Generates the *digit factorial chain* starting at `n`.
+///
+/// Each term is the sum of the factorials of the digits of the previous term,
+/// e.g. $145 \to 1! + 4! + 5! = 145$.
+/// Every chain eventually falls into a loop, so the chain is followed until a term repeats.
+///
+/// [A014080](https://oeis.org/A014080)
+///
+/// # Arguments
+///
+/// * `n` - The first term of the chain.
+///
+/// # Returns
+///
+/// Vector of the chain up to and including the first repeated term.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::factorial_chain;
+/// assert_eq!(factorial_chain(169), vec![169, 363601, 1454, 169]);
+/// assert_eq!(factorial_chain(145), vec![145, 145]);
+/// ```
+pub fn factorial_chain(n: u64) -> Vec<u64> {
+    let mut seen: HashSet<u64> = HashSet::new();
+    let mut chain: Vec<u64> = vec![];
+    let mut term: u64 = n;
+    while seen.insert(term) {
+        chain.push(term);
+        term = digit_factorial_sum(term);
+    }
+    chain.push(term);
+    chain
+}
+
+/// Calculates the number of distinct terms in the digit factorial chain starting at `n`.
+///
+/// # Arguments
+///
+/// * `n` - The first term of the chain.
+///
+/// # Returns
+///
+/// The number of terms in the chain before the first repeat.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::factorial_chain_length;
+/// assert_eq!(factorial_chain_length(169), 3);
+/// assert_eq!(factorial_chain_length(69), 5);
+/// ```
+pub fn factorial_chain_length(n: u64) -> usize {
+    factorial_chain(n).len() - 1
+}
+
+/// Calculates the sum of the factorials of the decimal digits of `n`.
+///
+/// # Arguments
+///
+/// * `n` - The number to use the digits of.
+///
+/// # Returns
+///
+/// The sum of the factorials of the digits of `n`, where $0! = 1$ means `0` maps to `1`.
+fn digit_factorial_sum(n: u64) -> u64 {
+    if n == 0 {
+        return 1;
+    }
+    let mut n: u64 = n;
+    let mut sum: u64 = 0;
+    while n > 0 {
+        sum += factorial(n % 10);
+        n /= 10;
+    }
+    sum
+}
+
+/// Counts the digits of a number written in a given base.
+///
+/// # Arguments
+///
+/// * `n` - The number to count the digits of.
+/// * `radix` - The base to write `n` in, between 2 and 36.
+///
+/// # Returns
+///
+/// The number of digits of `n` in base `radix`. Zero is written with a single digit.
+///
+/// # Panics
+///
+/// Panics if `radix` is not between 2 and 36.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::digit_count;
+/// assert_eq!(digit_count(255, 16), 2); // ff
+/// assert_eq!(digit_count(255, 2), 8);
+/// assert_eq!(digit_count(0, 10), 1);
+/// ```
+pub fn digit_count(n: u128, radix: u32) -> u32 {
+    check_radix(radix);
+    let mut n: u128 = n / radix as u128;
+    let mut count: u32 = 1;
+    while n > 0 {
+        n /= radix as u128;
+        count += 1;
+    }
+    count
+}
+
+/// Calculates the sum of the digits of a number written in a given base.
+///
+/// # Arguments
+///
+/// * `n` - The number to sum the digits of.
+/// * `radix` - The base to write `n` in, between 2 and 36.
+///
+/// # Returns
+///
+/// The sum of the digits of `n` in base `radix`.
+///
+/// # Panics
+///
+/// Panics if `radix` is not between 2 and 36.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::digit_sum_base;
+/// assert_eq!(digit_sum_base(255, 16), 30); // 15 + 15
+/// assert_eq!(digit_sum_base(255, 2), 8);
+/// assert_eq!(digit_sum_base(1234, 10), 10);
+/// ```
+pub fn digit_sum_base(n: u128, radix: u32) -> u128 {
+    check_radix(radix);
+    let mut n: u128 = n;
+    let mut sum: u128 = 0;
+    while n > 0 {
+        sum += n % radix as u128;
+        n /= radix as u128;
+    }
+    sum
+}
+
+/// Panics unless `radix` is a supported base (between 2 and 36, like `char::to_digit`).
+///
+/// # Arguments
+///
+/// * `radix` - The base to check.
+fn check_radix(radix: u32) {
+    if !(2..=36).contains(&radix) {
+        panic!("radix must be between 2 and 36, got {}", radix);
+    }
+}
+
+/// Finds a digit of *Champernowne's constant*, $0.123456789101112\ldots$
+///
+/// The digits are the positive integers written one after another. Rather than building that string,
+/// this skips over whole blocks of numbers with the same length (9 one-digit numbers, 90 two-digit numbers, and so on)
+/// to find which number the digit is in, and then which digit of that number it is.
+///
+/// [A033307](https://oeis.org/A033307)
+///
+/// # Arguments
+///
+/// * `index` - The 1-based position of the digit after the decimal point.
+///
+/// # Returns
+///
+/// The digit at position `index`.
+///
+/// # Panics
+///
+/// Panics if `index` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::champernowne_digit;
+/// assert_eq!(champernowne_digit(1), 1);
+/// assert_eq!(champernowne_digit(12), 1); // the first 1 of 11
+/// assert_eq!(champernowne_digit(15), 2); // the 2 of 12
+/// ```
+pub fn champernowne_digit(index: u64) -> u8 {
+    if index == 0 {
+        panic!("The digits of Champernowne's constant are numbered from 1");
+    }
+    // u128 so the size of the blocks never overflows
+    let mut index: u128 = index as u128 - 1;
+    let mut length: u32 = 1;
+    let mut first: u128 = 1;
+    while index >= 9 * first * length as u128 {
+        index -= 9 * first * length as u128;
+        length += 1;
+        first *= 10;
+    }
+    let number: u128 = first + index / length as u128;
+    let position: u32 = (index % length as u128) as u32;
+    (number / 10_u128.pow(length - 1 - position) % 10) as u8
+}
+
+/// Determines whether or not a number is a *Keith number*.
+///
+/// A $k$-digit number $n$ is a Keith number if it appears in the sequence that starts with its own digits,
+/// where every later term is the sum of the previous $k$ terms.
+/// For example, 197 gives $1, 9, 7, 17, 33, 57, 107, 197$.
+///
+/// Following OEIS, single-digit numbers are not Keith numbers: their sequence would be constant,
+/// so they "appear" without the recurrence ever being used.
+///
+/// [A007629](https://oeis.org/A007629)
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+///
+/// # Returns
+///
+/// Boolean representing whether or not `n` is a Keith number.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::is_keith;
+/// assert_eq!(is_keith(197), true);
+/// assert_eq!(is_keith(198), false);
+/// assert_eq!(is_keith(7), false);
+/// ```
+pub fn is_keith(n: u64) -> bool {
+    if n < 10 {
+        return false;
+    }
+    let mut terms: Vec<u128> = decimal_digits(n).into_iter().map(|d| d as u128).collect();
+    let k: usize = terms.len();
+    loop {
+        let next: u128 = terms[terms.len() - k..].iter().sum();
+        if next >= n as u128 {
+            return next == n as u128;
+        }
+        terms.push(next);
+    }
+}
+
+/// Finds the decimal digits of a number.
+///
+/// # Arguments
+///
+/// * `n` - The number to find the digits of.
+///
+/// # Returns
+///
+/// The digits of `n`, starting with the most significant. 0 has the single digit 0.
+fn decimal_digits(n: u64) -> Vec<u8> {
+    let mut digits: Vec<u8> = vec![(n % 10) as u8];
+    let mut n: u64 = n / 10;
+    while n > 0 {
+        digits.push((n % 10) as u8);
+        n /= 10;
+    }
+    digits.reverse();
+    digits
+}
+
+/// Determines whether or not a number is a *vampire number*, and finds its fangs.
+///
+/// A vampire number $n$ with $2k$ digits can be written as $n = x \cdot y$, where the "fangs" $x$ and $y$
+/// both have $k$ digits, not both end in 0, and together their digits are a rearrangement of the digits of $n$.
+/// For example, $1260 = 21 \cdot 60$.
+///
+/// [A014575](https://oeis.org/A014575)
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+///
+/// # Returns
+///
+/// `Some` vector of every pair of fangs $(x, y)$ with $x \le y$ (in increasing order of $x$),
+/// or `None` if `n` is not a vampire number.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::is_vampire;
+/// assert_eq!(is_vampire(1260), Some(vec![(21, 60)]));
+/// assert_eq!(is_vampire(1261), None);
+/// ```
+pub fn is_vampire(n: u64) -> Option<Vec<(u64, u64)>> {
+    let mut digits: Vec<u8> = decimal_digits(n);
+    if !digits.len().is_multiple_of(2) {
+        return None;
+    }
+    digits.sort_unstable();
+    let half: u32 = digits.len() as u32 / 2;
+    let smallest: u64 = 10_u64.pow(half - 1);
+    let mut fangs: Vec<(u64, u64)> = vec![];
+    // x <= y means x <= sqrt(n)
+    for x in smallest..=n.isqrt() {
+        if !n.is_multiple_of(x) {
+            continue;
+        }
+        let y: u64 = n / x;
+        if decimal_digits(y).len() as u32 != half || (x.is_multiple_of(10) && y.is_multiple_of(10))
+        {
+            continue;
+        }
+        let mut fang_digits: Vec<u8> = decimal_digits(x);
+        fang_digits.extend(decimal_digits(y));
+        fang_digits.sort_unstable();
+        if fang_digits == digits {
+            fangs.push((x, y));
+        }
+    }
+    if fangs.is_empty() {
+        None
+    } else {
+        Some(fangs)
+    }
+}
+
+/// Calculates the first decimal digits of $\sqrt{n}$.
+///
+/// This uses the exact digit-by-digit method (like long division), so there is no loss of precision.
+/// The digits of $n$ are taken two at a time (followed by pairs of zeros after the decimal point),
+/// and with $p$ the root found so far and $c$ the current remainder, the next digit is the largest $x$ with
+/// $$x(20p + x) \le c$$
+///
+/// # Arguments
+///
+/// * `n` - The number to find the square root of.
+/// * `count` - The number of digits to calculate, including the digits before the decimal point.
+///
+/// # Returns
+///
+/// The first `count` digits of $\sqrt{n}$, starting with the most significant (the decimal point is not marked).
+/// If `n` is a perfect square, the digits after its root are all 0.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::sqrt_digits;
+/// assert_eq!(sqrt_digits(2, 10), vec![1, 4, 1, 4, 2, 1, 3, 5, 6, 2]);
+/// assert_eq!(sqrt_digits(144, 4), vec![1, 2, 0, 0]);
+/// ```
+pub fn sqrt_digits(n: u64, count: usize) -> Vec<u8> {
+    // split n into pairs of digits, starting from the decimal point
+    let mut pairs: Vec<u64> = vec![];
+    let mut rest: u64 = n;
+    while rest > 0 {
+        pairs.push(rest % 100);
+        rest /= 100;
+    }
+    if pairs.is_empty() {
+        pairs.push(0);
+    }
+    pairs.reverse();
+
+    let mut digits: Vec<u8> = vec![];
+    let mut root: BigInt = BigInt::from(0);
+    let mut remainder: BigInt = BigInt::from(0);
+    let mut pairs = pairs.into_iter().chain(std::iter::repeat(0));
+    while digits.len() < count {
+        let current: BigInt = remainder * 100 + pairs.next().unwrap();
+        let twenty_root: BigInt = &root * 20;
+        let mut x: u8 = 9;
+        while (&twenty_root + x) * x > current {
+            x -= 1;
+        }
+        remainder = current - (&twenty_root + x) * x;
+        root = root * 10 + x;
+        digits.push(x);
+    }
+    digits
+}
+
+/// Determines whether or not a number is *automorphic*, meaning its square ends in the number itself.
+///
+/// For example, $76^2 = 5776$.
+///
+/// [A003226](https://oeis.org/A003226)
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+///
+/// # Returns
+///
+/// Boolean representing whether or not the last digits of $n^2$ are the digits of $n$.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::is_automorphic;
+/// assert_eq!(is_automorphic(76), true);
+/// assert_eq!(is_automorphic(77), false);
+/// ```
+pub fn is_automorphic(n: u128) -> bool {
+    power_ends_in(n, 2)
+}
+
+/// Determines whether or not a number is *trimorphic*, meaning its cube ends in the number itself.
+///
+/// For example, $24^3 = 13824$. Every automorphic number is also trimorphic.
+///
+/// [A033819](https://oeis.org/A033819)
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+///
+/// # Returns
+///
+/// Boolean representing whether or not the last digits of $n^3$ are the digits of $n$.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::is_trimorphic;
+/// assert_eq!(is_trimorphic(24), true);
+/// assert_eq!(is_trimorphic(23), false);
+/// ```
+pub fn is_trimorphic(n: u128) -> bool {
+    power_ends_in(n, 3)
+}
+
+/// Checks if $n^k$ ends in the digits of $n$.
+///
+/// Only the last digits matter, so this calculates $n^k \bmod 10^d$ (where $n$ has $d$ digits)
+/// rather than $n^k$ itself. The modulus can be larger than a `u128`, so the powers are done with `BigInt`.
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+/// * `k` - The power to raise `n` to.
+///
+/// # Returns
+///
+/// Boolean representing whether or not $n^k \equiv n \pmod{10^d}$.
+fn power_ends_in(n: u128, k: u32) -> bool {
+    let modulus: BigInt = BigInt::from(10).pow(digit_count(n, 10));
+    let n: BigInt = BigInt::from(n);
+    n.modpow(&BigInt::from(k), &modulus) == n
+}
+
+/// Calculates the sum of the decimal digits of $n!$.
+///
+/// # Arguments
+///
+/// * `n` - The number to take the factorial of.
+///
+/// # Returns
+///
+/// The sum of the digits of `n!`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::factorial_digit_sum;
+/// assert_eq!(factorial_digit_sum(10), 27); // 3628800
+/// ```
+pub fn factorial_digit_sum(n: u64) -> u64 {
+    factorial(BigInt::from(n))
+        .to_radix_le(10)
+        .1
+        .iter()
+        .map(|&d| d as u64)
+        .sum()
+}
+
+/// A non-negative number written in an arbitrary base between 2 and 36.
+///
+/// The digits are stored least significant first, without leading zeros (zero is stored as a single `0` digit).
+/// Numbers in the same base can be added directly with `+`, which does schoolbook addition with a carry in that base.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::BaseNumber;
+///
+/// let a = BaseNumber::from_u128(100, 7); // 202 in base 7
+/// let b = BaseNumber::from_u128(48, 7); // 66 in base 7
+/// let sum = a + b;
+/// assert_eq!(sum.to_string(), "301");
+/// assert_eq!(sum.to_u128(), 148);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BaseNumber {
+    /// The digits, least significant first
+    digits: Vec<u8>,
+    /// The base the digits are written in
+    radix: u32,
+}
+
+impl BaseNumber {
+    /// Writes a number in the given base.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number to convert.
+    /// * `radix` - The base to write it in.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bens_number_theory::digits::BaseNumber;
+    /// assert_eq!(BaseNumber::from_u128(255, 16).to_string(), "ff");
+    /// assert_eq!(BaseNumber::from_u128(5, 2).digits(), &[1, 0, 1]);
+    /// ```
+    pub fn from_u128(n: u128, radix: u32) -> Self {
+        check_radix(radix);
+        let mut n: u128 = n;
+        let mut digits: Vec<u8> = vec![];
+        while n > 0 {
+            digits.push((n % radix as u128) as u8);
+            n /= radix as u128;
+        }
+        if digits.is_empty() {
+            digits.push(0);
+        }
+        BaseNumber { digits, radix }
+    }
+
+    /// Converts the number back into a `u128`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is too big to fit in a `u128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bens_number_theory::digits::BaseNumber;
+    /// assert_eq!(BaseNumber::from_u128(1234, 16).to_u128(), 1234);
+    /// ```
+    pub fn to_u128(&self) -> u128 {
+        self.digits.iter().rev().fold(0_u128, |acc, &d| {
+            acc.checked_mul(self.radix as u128)
+                .and_then(|acc| acc.checked_add(d as u128))
+                .expect("BaseNumber is too big to fit in a u128")
+        })
+    }
+
+    /// The base the number is written in.
+    pub fn radix(&self) -> u32 {
+        self.radix
+    }
+
+    /// The digits of the number, least significant first.
+    pub fn digits(&self) -> &[u8] {
+        &self.digits
+    }
+}
+
+impl std::fmt::Display for BaseNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s: String = self
+            .digits
+            .iter()
+            .rev()
+            .map(|&d| std::char::from_digit(d as u32, self.radix).unwrap())
+            .collect();
+        write!(f, "{}", s)
+    }
+}
+
+impl std::ops::Add for BaseNumber {
+    type Output = BaseNumber;
+
+    /// Adds two numbers digit by digit, carrying in their base.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two numbers are written in different bases.
+    fn add(self, other: BaseNumber) -> BaseNumber {
+        if self.radix != other.radix {
+            panic!(
+                "can't add numbers in different bases ({} and {})",
+                self.radix, other.radix
+            );
+        }
+        let radix: u32 = self.radix;
+        let length: usize = self.digits.len().max(other.digits.len());
+        let mut digits: Vec<u8> = Vec::with_capacity(length + 1);
+        let mut carry: u32 = 0;
+        for i in 0..length {
+            let a: u32 = *self.digits.get(i).unwrap_or(&0) as u32;
+            let b: u32 = *other.digits.get(i).unwrap_or(&0) as u32;
+            let total: u32 = a + b + carry;
+            digits.push((total % radix) as u8);
+            carry = total / radix;
+        }
+        if carry > 0 {
+            digits.push(carry as u8);
+        }
+        BaseNumber { digits, radix }
+    }
+}
+
+/// Finds every rotation of the decimal digits of a number.
+///
+/// Each rotation moves the leading digit to the end, so the digits of 123 become 231 and then 312.
+/// Rotations that start with 0 lose their leading zeros (the rotations of 102 are 102, 21 and 210).
+///
+/// # Arguments
+///
+/// * `n` - The number to rotate.
+///
+/// # Returns
+///
+/// A vector with one entry per digit of `n`, starting with `n` itself. Repeated digits can give repeated rotations.
+///
+/// # Panics
+///
+/// Panics if a rotation of a 20 digit `n` doesn't fit in a `u64`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::rotations;
+/// assert_eq!(rotations(123), vec![123, 231, 312]);
+/// assert_eq!(rotations(7), vec![7]);
+/// ```
+pub fn rotations(n: u64) -> Vec<u64> {
+    let mut digits: Vec<u8> = decimal_digits(n);
+    let mut result: Vec<u64> = Vec::with_capacity(digits.len());
+    for _ in 0..digits.len() {
+        result.push(digits.iter().fold(0_u64, |acc, &d| {
+            acc.checked_mul(10)
+                .and_then(|acc| acc.checked_add(d as u64))
+                .expect("rotation is too big to fit in a u64")
+        }));
+        digits.rotate_left(1);
+    }
+    result
+}
+
+/// Determines whether or not a number is a *cyclic number*, whose multiples are rotations of itself.
+///
+/// A $d$ digit number $n$ is cyclic when $n, 2n, \dots, dn$ are all rotations of its digits, like
+/// $142857 \cdot 2 = 285714$. Single digit numbers are trivially their own rotation, so they aren't counted.
+/// Cyclic numbers that need a leading zero (like 0588235294117647) can't be written as a `u64` with all their digits,
+/// so they aren't detected.
+///
+/// [A004042](https://oeis.org/A004042)
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+///
+/// # Returns
+///
+/// Boolean representing whether or not `n` is a cyclic number.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::is_cyclic_number;
+/// assert_eq!(is_cyclic_number(142857), true);
+/// assert_eq!(is_cyclic_number(142858), false);
+/// ```
+pub fn is_cyclic_number(n: u64) -> bool {
+    // 2n has to be a rotation, so it must fit in a u64 (which also keeps every rotation in range)
+    if !(10..=u64::MAX / 2).contains(&n) {
+        return false;
+    }
+    let rotations: Vec<u64> = rotations(n);
+    (1..=rotations.len() as u64).all(|k| match n.checked_mul(k) {
+        Some(product) => rotations.contains(&product),
+        None => false,
+    })
+}
+
+/// Counts the digits needed to write out the prime factorization of a number, with exponents greater than 1.
+///
+/// For example $1200 = 2^4 \cdot 3 \cdot 5^2$ is written "24352", which has 5 digits. 1 is written as "1".
+///
+/// # Arguments
+///
+/// * `n` - The number to factor.
+///
+/// # Returns
+///
+/// The number of digits in the prime factorization of `n`.
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+fn factorization_digit_count(n: u64) -> u32 {
+    if n == 0 {
+        panic!("0 has no prime factorization");
+    }
+    if n == 1 {
+        return 1;
+    }
+    prime_factorization(n)
+        .iter()
+        .map(|&(p, e)| {
+            digit_count(p as u128, 10) + if e > 1 { digit_count(e as u128, 10) } else { 0 }
+        })
+        .sum()
+}
+
+/// Determines whether or not a number is *economical*, meaning its prime factorization takes fewer digits to write than the number itself.
+///
+/// Exponents of 1 aren't written, so $125 = 5^3$ is written "53", with 2 digits instead of 3.
+///
+/// [A046759](https://oeis.org/A046759)
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+///
+/// # Returns
+///
+/// Boolean representing whether or not `n` is economical.
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::is_economical;
+/// assert_eq!(is_economical(125), true); // 5^3
+/// assert_eq!(is_economical(126), false); // 2 * 3^2 * 7
+/// ```
+pub fn is_economical(n: u64) -> bool {
+    factorization_digit_count(n) < digit_count(n as u128, 10)
+}
+
+/// Determines whether or not a number is *equidigital*, meaning its prime factorization takes the same number of digits to write as the number itself.
+///
+/// Every prime is equidigital, since its factorization is just itself.
+///
+/// [A046758](https://oeis.org/A046758)
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+///
+/// # Returns
+///
+/// Boolean representing whether or not `n` is equidigital.
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::is_equidigital;
+/// assert_eq!(is_equidigital(10), true); // 2 * 5
+/// assert_eq!(is_equidigital(12), false); // 2^2 * 3
+/// ```
+pub fn is_equidigital(n: u64) -> bool {
+    factorization_digit_count(n) == digit_count(n as u128, 10)
+}
+
+/// Determines whether or not a number is *extravagant* (or wasteful), meaning its prime factorization takes more digits to write than the number itself.
+///
+/// For example $4 = 2^2$ is written "22", with 2 digits instead of 1.
+///
+/// [A046760](https://oeis.org/A046760)
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+///
+/// # Returns
+///
+/// Boolean representing whether or not `n` is extravagant.
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::is_extravagant;
+/// assert_eq!(is_extravagant(4), true); // 2^2
+/// assert_eq!(is_extravagant(125), false);
+/// ```
+pub fn is_extravagant(n: u64) -> bool {
+    factorization_digit_count(n) > digit_count(n as u128, 10)
+}
+
+/// Determines whether or not a number is a *palindrome*, reading the same forwards and backwards in base 10.
+///
+/// [A002113](https://oeis.org/A002113)
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+///
+/// # Returns
+///
+/// Boolean representing whether or not the decimal digits of `n` form a palindrome.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::is_palindrome;
+/// assert_eq!(is_palindrome(12321), true);
+/// assert_eq!(is_palindrome(123), false);
+/// ```
+pub fn is_palindrome(n: u64) -> bool {
+    let digits: Vec<u8> = decimal_digits(n);
+    digits.iter().eq(digits.iter().rev())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decimal_digits, digit_factorial_sum, factorization_digit_count, power_ends_in};
+
+    #[test]
+    fn factorization_digit_count_test() {
+        assert_eq!(factorization_digit_count(1), 1);
+        assert_eq!(factorization_digit_count(7), 1);
+        assert_eq!(factorization_digit_count(125), 2); // 53
+        assert_eq!(factorization_digit_count(1200), 5); // 24352
+        assert_eq!(factorization_digit_count(1024), 3); // 210
+        assert_eq!(factorization_digit_count(999999999989), 12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn factorization_digit_count_zero_test() {
+        factorization_digit_count(0);
+    }
+
+    #[test]
+    fn digit_factorial_sum_test() {
+        assert_eq!(digit_factorial_sum(0), 1);
+        assert_eq!(digit_factorial_sum(145), 145);
+        assert_eq!(digit_factorial_sum(69), 363600);
+        assert_eq!(digit_factorial_sum(999), 3 * 362880);
+    }
+
+    #[test]
+    fn decimal_digits_test() {
+        assert_eq!(decimal_digits(0), vec![0]);
+        assert_eq!(decimal_digits(7), vec![7]);
+        assert_eq!(decimal_digits(1020), vec![1, 0, 2, 0]);
+        assert_eq!(decimal_digits(u64::MAX).len(), 20);
+    }
+
+    #[test]
+    fn power_ends_in_test() {
+        assert!(power_ends_in(0, 2));
+        assert!(power_ends_in(5, 2));
+        assert!(power_ends_in(24, 3));
+        assert!(!power_ends_in(24, 2));
+        assert!(power_ends_in(24, 5)); // 24^5 = 7962624
+        assert!(!power_ends_in(10, 3));
+    }
+}
@@ -68,3 +68,256 @@ where
     }
     f
 }
+
+/// Calculates the exponent of a prime `p` in the factorization of `n!` using *Legendre's formula*.
+///
+/// $$\nu_p(n!) = \sum_{i=1}^{\infty}\left\lfloor \frac{n}{p^i} \right\rfloor$$
+///
+/// Every multiple of $p$ up to $n$ contributes one factor of $p$, every multiple of $p^2$ contributes another, and so on.
+///
+/// # Arguments
+///
+/// * `n` - The value of `n` in `n!`.
+/// * `p` - The prime to find the exponent of.
+///
+/// # Returns
+///
+/// The largest `k` such that $p^k$ divides `n!`.
+///
+/// # Panics
+///
+/// Panics if `p` is less than 2.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::factorials::factorial_prime_exponent;
+/// assert_eq!(factorial_prime_exponent(10, 2), 8); // 10! = 2^8 * 3^4 * 5^2 * 7
+/// assert_eq!(factorial_prime_exponent(10, 3), 4);
+/// ```
+pub fn factorial_prime_exponent(n: u64, p: u64) -> u64 {
+    if p < 2 {
+        panic!("p must be a prime");
+    }
+    let mut exponent: u64 = 0;
+    let mut n: u64 = n;
+    while n > 0 {
+        n /= p;
+        exponent += n;
+    }
+    exponent
+}
+
+/// Calculates the number of trailing zeros of `n!` without calculating `n!`.
+///
+/// Each trailing zero comes from a factor of $10 = 2 \times 5$, and there are always more 2s than 5s,
+/// so this is the exponent of 5 in `n!`:
+/// $$\sum_{i=1}^{\infty}\left\lfloor \frac{n}{5^i} \right\rfloor$$
+///
+/// # Arguments
+///
+/// * `n` - The value of `n` in `n!`.
+///
+/// # Returns
+///
+/// The number of trailing zeros of `n!`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::factorials::factorial_trailing_zeros;
+/// assert_eq!(factorial_trailing_zeros(10), 2); // 3628800
+/// assert_eq!(factorial_trailing_zeros(25), 6);
+/// ```
+pub fn factorial_trailing_zeros(n: u64) -> u64 {
+    factorial_prime_exponent(n, 5)
+}
+
+/// Converts a number to the *factorial number system* (factoradic).
+///
+/// In factoradic, the $i$th digit from the right (starting from $i = 0$) is worth $i!$ and is between $0$ and $i$,
+/// so every number has exactly one representation:
+/// $$463 = 3 \cdot 5! + 4 \cdot 4! + 1 \cdot 3! + 0 \cdot 2! + 1 \cdot 1! + 0 \cdot 0!$$
+///
+/// The digits are found by repeatedly dividing by $1, 2, 3, \ldots$ and keeping the remainders.
+///
+/// # Arguments
+///
+/// * `n` - The number to convert.
+///
+/// # Returns
+///
+/// The factoradic digits of `n`, starting with the most significant. The last digit is always 0, and 0 is `[0]`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::factorials::to_factoradic;
+/// assert_eq!(to_factoradic(463), vec![3, 4, 1, 0, 1, 0]);
+/// assert_eq!(to_factoradic(0), vec![0]);
+/// ```
+pub fn to_factoradic(n: u64) -> Vec<u64> {
+    let mut digits: Vec<u64> = vec![0];
+    let mut n: u64 = n;
+    let mut radix: u64 = 2;
+    while n > 0 {
+        digits.push(n % radix);
+        n /= radix;
+        radix += 1;
+    }
+    digits.reverse();
+    digits
+}
+
+/// Converts a number from the *factorial number system* (factoradic), the inverse of `to_factoradic`.
+///
+/// # Arguments
+///
+/// * `digits` - The factoradic digits, starting with the most significant.
+///
+/// # Returns
+///
+/// The number $\sum_i d_i \cdot i!$, where $d_i$ is the $i$th digit from the right.
+///
+/// # Panics
+///
+/// Panics if the $i$th digit from the right is larger than $i$, or if the result doesn't fit in a `u64`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::factorials::from_factoradic;
+/// assert_eq!(from_factoradic(&[3, 4, 1, 0, 1, 0]), 463);
+/// assert_eq!(from_factoradic(&[]), 0);
+/// ```
+pub fn from_factoradic(digits: &[u64]) -> u64 {
+    // Horner's method, since i! = i * (i - 1)!
+    let mut n: u64 = 0;
+    for (i, &digit) in digits.iter().enumerate() {
+        let place: u64 = (digits.len() - 1 - i) as u64;
+        if digit > place {
+            panic!(
+                "The factoradic digit worth {}! must be at most {}",
+                place, place
+            );
+        }
+        n = n
+            .checked_mul(place + 1)
+            .and_then(|n| n.checked_add(digit))
+            .expect("The number is too large for a u64");
+    }
+    n
+}
+
+/// Finds the `k`th permutation of some items, in lexicographic order.
+///
+/// The factoradic digits of `k` are the *Lehmer code* of the permutation:
+/// each digit says which of the remaining items (in their original order) comes next.
+///
+/// # Arguments
+///
+/// * `items` - The items to permute, which are treated as already being in order.
+/// * `k` - The index of the permutation, starting from 0 (the items in their original order).
+///
+/// # Returns
+///
+/// The `k`th permutation of `items`.
+///
+/// # Panics
+///
+/// Panics if `k` is not less than the number of permutations, $n!$.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::factorials::nth_permutation;
+/// assert_eq!(nth_permutation(&[0, 1, 2], 0), vec![0, 1, 2]);
+/// assert_eq!(nth_permutation(&['a', 'b', 'c'], 3), vec!['b', 'c', 'a']);
+/// ```
+pub fn nth_permutation<T: Clone>(items: &[T], k: u64) -> Vec<T> {
+    let mut code: Vec<u64> = to_factoradic(k);
+    if code.len() > items.len().max(1) {
+        panic!("There are fewer than {} permutations of the items", k + 1);
+    }
+    // pad the Lehmer code with leading zeros so there is one digit per item
+    let mut lehmer: Vec<u64> = vec![0; items.len().saturating_sub(code.len())];
+    lehmer.append(&mut code);
+    let mut remaining: Vec<T> = items.to_vec();
+    lehmer
+        .into_iter()
+        .take(items.len())
+        .map(|digit| remaining.remove(digit as usize))
+        .collect()
+}
+
+/// Finds the lexicographic rank of a permutation of $0, 1, \ldots, n - 1$, the inverse of `permutation_unrank`.
+///
+/// The $i$th digit of the permutation's *Lehmer code* counts the later entries that are smaller than entry $i$.
+/// Read as factoradic digits with `from_factoradic`, the Lehmer code is the rank.
+///
+/// # Arguments
+///
+/// * `perm` - A permutation of the numbers $0$ to $n - 1$.
+///
+/// # Returns
+///
+/// The index of `perm` among all permutations of $0, 1, \ldots, n - 1$ in lexicographic order, starting from 0.
+///
+/// # Panics
+///
+/// Panics if `perm` isn't a permutation of $0, 1, \ldots, n - 1$, or if the rank doesn't fit in a `u64`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::factorials::permutation_rank;
+/// assert_eq!(permutation_rank(&[0, 1, 2]), 0);
+/// assert_eq!(permutation_rank(&[1, 2, 0]), 3);
+/// assert_eq!(permutation_rank(&[2, 1, 0]), 5);
+/// ```
+pub fn permutation_rank(perm: &[usize]) -> u64 {
+    let mut seen: Vec<bool> = vec![false; perm.len()];
+    for &x in perm {
+        if x >= perm.len() || seen[x] {
+            panic!(
+                "{:?} is not a permutation of 0 to {}",
+                perm,
+                perm.len() as i64 - 1
+            );
+        }
+        seen[x] = true;
+    }
+    let lehmer: Vec<u64> = perm
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| perm[i + 1..].iter().filter(|&&y| y < x).count() as u64)
+        .collect();
+    from_factoradic(&lehmer)
+}
+
+/// Finds the permutation of $0, 1, \ldots, n - 1$ with a given lexicographic rank, the inverse of `permutation_rank`.
+///
+/// # Arguments
+///
+/// * `n` - The number of items being permuted.
+/// * `rank` - The index of the permutation, starting from 0.
+///
+/// # Returns
+///
+/// The `rank`th permutation of $0, 1, \ldots, n - 1$, found with `nth_permutation`.
+///
+/// # Panics
+///
+/// Panics if `rank` is not less than $n!$.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::factorials::permutation_unrank;
+/// assert_eq!(permutation_unrank(3, 5), vec![2, 1, 0]);
+/// assert_eq!(permutation_unrank(4, 0), vec![0, 1, 2, 3]);
+/// ```
+pub fn permutation_unrank(n: usize, rank: u64) -> Vec<usize> {
+    let items: Vec<usize> = (0..n).collect();
+    nth_permutation(&items, rank)
+}
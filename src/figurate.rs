@@ -0,0 +1,188 @@
+/// Finds the index of a *triangular number*.
+///
+/// The $k$th triangular number is $T_k = \frac{k(k + 1)}{2}$. Solving for $k$ with the quadratic formula gives
+/// $$k = \frac{\sqrt{8n + 1} - 1}{2}$$
+/// so $n$ is triangular exactly when $8n + 1$ is a perfect square.
+///
+/// [A000217](https://oeis.org/A000217)
+///
+/// # Arguments
+///
+/// * `n` - The number to find the index of.
+///
+/// # Returns
+///
+/// `Some(k)` if $n = T_k$ (with $T_0 = 0$), otherwise `None`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::figurate::triangular_root;
+/// assert_eq!(triangular_root(10), Some(4));
+/// assert_eq!(triangular_root(11), None);
+/// ```
+pub fn triangular_root(n: u64) -> Option<u64> {
+    // 8n + 1 = (2k + 1)^2
+    let s: u128 = exact_sqrt(8 * n as u128 + 1)?;
+    Some(((s - 1) / 2) as u64)
+}
+
+/// Finds the index of a *pentagonal number*.
+///
+/// The $k$th pentagonal number is $P_k = \frac{k(3k - 1)}{2}$. Solving for $k$ gives
+/// $$k = \frac{\sqrt{24n + 1} + 1}{6}$$
+/// so $n$ is pentagonal exactly when $24n + 1$ is a perfect square whose root is $5 \pmod{6}$.
+///
+/// [A000326](https://oeis.org/A000326)
+///
+/// # Arguments
+///
+/// * `n` - The number to find the index of.
+///
+/// # Returns
+///
+/// `Some(k)` if $n = P_k$ (with $P_0 = 0$), otherwise `None`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::figurate::pentagonal_root;
+/// assert_eq!(pentagonal_root(22), Some(4));
+/// assert_eq!(pentagonal_root(23), None);
+/// ```
+pub fn pentagonal_root(n: u64) -> Option<u64> {
+    if n == 0 {
+        return Some(0);
+    }
+    // 24n + 1 = (6k - 1)^2
+    let s: u128 = exact_sqrt(24 * n as u128 + 1)?;
+    if !(s + 1).is_multiple_of(6) {
+        return None;
+    }
+    Some(((s + 1) / 6) as u64)
+}
+
+/// Finds the index of a *hexagonal number*.
+///
+/// The $k$th hexagonal number is $H_k = k(2k - 1)$. Solving for $k$ gives
+/// $$k = \frac{\sqrt{8n + 1} + 1}{4}$$
+/// so $n$ is hexagonal exactly when $8n + 1$ is a perfect square whose root is $3 \pmod{4}$.
+/// Every hexagonal number is also triangular, since $H_k = T_{2k - 1}$.
+///
+/// [A000384](https://oeis.org/A000384)
+///
+/// # Arguments
+///
+/// * `n` - The number to find the index of.
+///
+/// # Returns
+///
+/// `Some(k)` if $n = H_k$ (with $H_0 = 0$), otherwise `None`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::figurate::hexagonal_root;
+/// assert_eq!(hexagonal_root(28), Some(4));
+/// assert_eq!(hexagonal_root(21), None); // triangular, but not hexagonal
+/// ```
+pub fn hexagonal_root(n: u64) -> Option<u64> {
+    if n == 0 {
+        return Some(0);
+    }
+    // 8n + 1 = (4k - 1)^2
+    let s: u128 = exact_sqrt(8 * n as u128 + 1)?;
+    if !(s + 1).is_multiple_of(4) {
+        return None;
+    }
+    Some(((s + 1) / 4) as u64)
+}
+
+/// Finds the square root of a number, if it is a perfect square.
+///
+/// # Arguments
+///
+/// * `n` - The number to find the square root of.
+///
+/// # Returns
+///
+/// `Some(s)` if $n = s^2$, otherwise `None`.
+fn exact_sqrt(n: u128) -> Option<u128> {
+    let s: u128 = n.isqrt();
+    if s * s == n {
+        Some(s)
+    } else {
+        None
+    }
+}
+
+/// Calculates the sum of the numbers on the diagonals of a *number spiral*.
+///
+/// Starting with 1 in the center and spiralling outwards, a $5 \times 5$ spiral looks like
+///
+/// ```text
+/// 21 22 23 24 25
+/// 20  7  8  9 10
+/// 19  6  1  2 11
+/// 18  5  4  3 12
+/// 17 16 15 14 13
+/// ```
+///
+/// The ring with side length $n$ ends in its top right corner at $n^2$, and the other corners are
+/// $n - 1$ apart, so the four corners sum to $4n^2 - 6(n - 1) = 4n^2 - 6n + 6$.
+/// Summing that over $n = 3, 5, \ldots, 2m + 1$ (and adding the 1 in the center) gives the closed form
+/// $$\frac{16m^3 + 30m^2 + 26m + 3}{3}$$
+///
+/// [A114254](https://oeis.org/A114254)
+///
+/// # Arguments
+///
+/// * `size` - The (odd) side length of the spiral.
+///
+/// # Returns
+///
+/// The sum of both diagonals, counting the center once.
+///
+/// # Panics
+///
+/// Panics if `size` is even, since only odd spirals have a center, or if the sum doesn't fit in a `u64`
+/// (`size` above 3024615).
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::figurate::spiral_diagonal_sum;
+/// assert_eq!(spiral_diagonal_sum(1), 1);
+/// assert_eq!(spiral_diagonal_sum(5), 101);
+/// ```
+pub fn spiral_diagonal_sum(size: u64) -> u64 {
+    if size.is_multiple_of(2) {
+        panic!("A number spiral must have an odd size");
+    }
+    let m: u128 = (size / 2) as u128;
+    // 16m^3 + 30m^2 + 26m + 3 by Horner's rule, where 16m + 30 can't overflow since m < 2^63
+    (16 * m + 30)
+        .checked_mul(m)
+        .and_then(|x| x.checked_add(26))
+        .and_then(|x| x.checked_mul(m))
+        .and_then(|x| x.checked_add(3))
+        .and_then(|x| u64::try_from(x / 3).ok())
+        .expect("The diagonal sum must fit in a u64")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::exact_sqrt;
+
+    #[test]
+    fn exact_sqrt_test() {
+        assert_eq!(exact_sqrt(0), Some(0));
+        assert_eq!(exact_sqrt(1), Some(1));
+        assert_eq!(exact_sqrt(49), Some(7));
+        assert_eq!(exact_sqrt(50), None);
+        assert_eq!(
+            exact_sqrt(u64::MAX as u128 * u64::MAX as u128),
+            Some(u64::MAX as u128)
+        );
+    }
+}
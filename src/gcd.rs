@@ -0,0 +1,335 @@
+use num::{BigInt, Integer, One, Signed, Zero};
+
+/// Calculates the greatest common divisor of two numbers using the Euclidean algorithm.
+///
+/// $$\gcd(a, b) = \gcd(b, a \bmod b), \quad \gcd(a, 0) = |a|$$
+///
+/// # Arguments
+///
+/// * `a` - The first number.
+/// * `b` - The second number.
+///
+/// # Returns
+///
+/// The largest number dividing both `a` and `b`. This is never negative, and $\gcd(0, 0) = 0$.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::gcd::gcd;
+/// assert_eq!(gcd(12, 18), 6);
+/// assert_eq!(gcd(17_u64, 5_u64), 1);
+/// assert_eq!(gcd(0, 7), 7);
+/// assert_eq!(gcd(-12, 18), 6);
+/// ```
+pub fn gcd<T>(a: T, b: T) -> T
+where
+    T: num::traits::Zero
+        + std::ops::Sub<Output = T>
+        + std::ops::Rem<Output = T>
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    let mut a: T = a;
+    let mut b: T = b;
+    while b != T::zero() {
+        let r: T = a % b;
+        a = b;
+        b = r;
+    }
+    if a < T::zero() {
+        return T::zero() - a;
+    }
+    a
+}
+
+/// Calculates the least common multiple of two numbers.
+///
+/// $$\operatorname{lcm}(a, b) = \frac{|a|}{\gcd(a, b)} \cdot |b|$$
+///
+/// # Arguments
+///
+/// * `a` - The first number.
+/// * `b` - The second number.
+///
+/// # Returns
+///
+/// The smallest non-negative number that is a multiple of both `a` and `b`.
+/// If either number is $0$, this is $0$.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::gcd::lcm;
+/// assert_eq!(lcm(4, 6), 12);
+/// assert_eq!(lcm(-4, 6), 12);
+/// assert_eq!(lcm(0, 6), 0);
+/// ```
+pub fn lcm<T>(a: T, b: T) -> T
+where
+    T: num::traits::Zero
+        + std::ops::Sub<Output = T>
+        + std::ops::Mul<Output = T>
+        + std::ops::Div<Output = T>
+        + std::ops::Rem<Output = T>
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    if a == T::zero() || b == T::zero() {
+        return T::zero();
+    }
+    let a: T = if a < T::zero() { T::zero() - a } else { a };
+    let b: T = if b < T::zero() { T::zero() - b } else { b };
+    // divide first to keep the intermediate value small
+    a / gcd(a, b) * b
+}
+
+/// Calculates the least common multiple of every number in a slice.
+///
+/// # Arguments
+///
+/// * `values` - The numbers to find the least common multiple of.
+///
+/// # Returns
+///
+/// The smallest non-negative number that is a multiple of every value.
+/// The least common multiple of an empty slice is $1$.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::gcd::lcm_all;
+/// assert_eq!(lcm_all(&[2, 3, 4, 5]), 60);
+/// assert_eq!(lcm_all::<u64>(&[]), 1);
+/// ```
+pub fn lcm_all<T>(values: &[T]) -> T
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + std::ops::Sub<Output = T>
+        + std::ops::Mul<Output = T>
+        + std::ops::Div<Output = T>
+        + std::ops::Rem<Output = T>
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    values.iter().fold(T::one(), |acc, &x| lcm(acc, x))
+}
+
+/// Calculates the smallest positive number that is evenly divisible by every number from $1$ to $n$.
+///
+/// $$\operatorname{lcm}(1, 2, \ldots, n)$$
+///
+/// [A003418](https://oeis.org/A003418)
+///
+/// This grows roughly like $e^n$, so the running least common multiple is kept as a BigInt.
+///
+/// # Arguments
+///
+/// * `n` - The largest number that must divide the result.
+///
+/// # Returns
+///
+/// $\operatorname{lcm}(1, 2, \ldots, n)$, which is $1$ when $n = 0$.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::gcd::smallest_multiple;
+/// use num::BigInt;
+///
+/// assert_eq!(smallest_multiple(10), BigInt::from(2520));
+/// ```
+pub fn smallest_multiple(n: u64) -> BigInt {
+    (1..=n).fold(BigInt::one(), |acc, k| acc.lcm(&BigInt::from(k)))
+}
+
+/// Calculates the greatest common divisor of two numbers along with its *Bézout coefficients*,
+/// using the extended Euclidean algorithm.
+///
+/// $$a x + b y = \gcd(a, b)$$
+///
+/// # Arguments
+///
+/// * `a` - The first number.
+/// * `b` - The second number.
+///
+/// # Returns
+///
+/// A tuple `(g, x, y)` where `g` is $\gcd(a, b)$ (never negative) and $a x + b y = g$.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::gcd::gcd_extended;
+/// assert_eq!(gcd_extended(240, 46), (2, -9, 47));
+/// let (g, x, y) = gcd_extended(-12, 18);
+/// assert_eq!((g, -12 * x + 18 * y), (6, 6));
+/// ```
+pub fn gcd_extended<T>(a: T, b: T) -> (T, T, T)
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + std::ops::Sub<Output = T>
+        + std::ops::Mul<Output = T>
+        + std::ops::Div<Output = T>
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    let (mut old_r, mut r): (T, T) = (a, b);
+    let (mut old_x, mut x): (T, T) = (T::one(), T::zero());
+    let (mut old_y, mut y): (T, T) = (T::zero(), T::one());
+    while r != T::zero() {
+        let q: T = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < T::zero() {
+        return (T::zero() - old_r, T::zero() - old_x, T::zero() - old_y);
+    }
+    (old_r, old_x, old_y)
+}
+
+/// Calculates the greatest common divisor of two numbers using *Stein's algorithm* (the binary GCD).
+///
+/// Instead of division, this only uses shifts, subtractions and comparisons:
+/// $$\gcd(2a, 2b) = 2\gcd(a, b), \quad \gcd(2a, b) = \gcd(a, b) \text{ for odd } b, \quad \gcd(a, b) = \gcd(a - b, b) \text{ for odd } a \ge b$$
+///
+/// # Arguments
+///
+/// * `a` - The first number.
+/// * `b` - The second number.
+///
+/// # Returns
+///
+/// The largest number dividing both `a` and `b` (the same as `gcd`), with $\gcd(0, n) = n$.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::gcd::binary_gcd;
+/// assert_eq!(binary_gcd(12, 18), 6);
+/// assert_eq!(binary_gcd(0, 7), 7);
+/// assert_eq!(binary_gcd(0, 0), 0);
+/// ```
+pub fn binary_gcd(a: u64, b: u64) -> u64 {
+    if a == 0 {
+        return b;
+    }
+    if b == 0 {
+        return a;
+    }
+    // the common factors of two
+    let shift: u32 = (a | b).trailing_zeros();
+    let mut a: u64 = a >> a.trailing_zeros();
+    let mut b: u64 = b;
+    loop {
+        // a is always odd here
+        b >>= b.trailing_zeros();
+        if a > b {
+            std::mem::swap(&mut a, &mut b);
+        }
+        b -= a;
+        if b == 0 {
+            return a << shift;
+        }
+    }
+}
+
+/// Calculates the greatest common divisor of two `BigInt`s using the Euclidean algorithm.
+///
+/// `BigInt` isn't `Copy`, so it doesn't fit the bounds of the generic `gcd`; this works on references instead.
+///
+/// # Arguments
+///
+/// * `a` - The first number.
+/// * `b` - The second number.
+///
+/// # Returns
+///
+/// The largest number dividing both `a` and `b`. This is never negative, and $\gcd(0, 0) = 0$.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::gcd::gcd_big;
+/// use num::BigInt;
+///
+/// assert_eq!(gcd_big(&BigInt::from(-12), &BigInt::from(18)), BigInt::from(6));
+/// ```
+pub fn gcd_big(a: &BigInt, b: &BigInt) -> BigInt {
+    let mut a: BigInt = a.abs();
+    let mut b: BigInt = b.abs();
+    while !b.is_zero() {
+        let r: BigInt = &a % &b;
+        a = std::mem::replace(&mut b, r);
+    }
+    a
+}
+
+/// Calculates the least common multiple of two `BigInt`s.
+///
+/// $$\operatorname{lcm}(a, b) = \frac{|a|}{\gcd(a, b)} \cdot |b|$$
+///
+/// # Arguments
+///
+/// * `a` - The first number.
+/// * `b` - The second number.
+///
+/// # Returns
+///
+/// The smallest non-negative number that is a multiple of both `a` and `b`.
+/// If either number is $0$, this is $0$.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::gcd::lcm_big;
+/// use num::BigInt;
+///
+/// assert_eq!(lcm_big(&BigInt::from(4), &BigInt::from(-6)), BigInt::from(12));
+/// ```
+pub fn lcm_big(a: &BigInt, b: &BigInt) -> BigInt {
+    if a.is_zero() || b.is_zero() {
+        return BigInt::zero();
+    }
+    a.abs() / gcd_big(a, b) * b.abs()
+}
+
+/// Finds the length of the longest contiguous run in which every *adjacent* pair is coprime.
+///
+/// Only neighbours are compared, so a run like `[2, 3, 4]` counts as length 3 even though 2 and 4 share a factor.
+/// A pair breaks the run when $\gcd(a_i, a_{i+1}) \neq 1$.
+///
+/// # Arguments
+///
+/// * `nums` - The numbers to search through.
+///
+/// # Returns
+///
+/// The length of the longest adjacent-coprime subslice. This is $0$ for an empty slice and $1$ for a single element.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::gcd::longest_coprime_run;
+/// // gcd(13, 26) = 13 breaks the run after [6, 35, 11, 13]
+/// assert_eq!(longest_coprime_run(&[6, 35, 11, 13, 26]), 4);
+/// ```
+pub fn longest_coprime_run(nums: &[u64]) -> usize {
+    if nums.is_empty() {
+        return 0;
+    }
+    let mut longest: usize = 1;
+    let mut current: usize = 1;
+    for pair in nums.windows(2) {
+        if gcd(pair[0], pair[1]) == 1 {
+            current += 1;
+            longest = longest.max(current);
+        } else {
+            current = 1;
+        }
+    }
+    longest
+}
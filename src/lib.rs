@@ -1,10 +1,127 @@
+/// Functions related to the binary representation of numbers
+pub mod bits;
 /// Functions that mathematically generate mathematical constants
 pub mod constants;
+/// Functions related to the digits of numbers
+pub mod digits;
 /// Functions related to factorial generation
 pub mod factorials;
+/// Functions related to figurate numbers
+pub mod figurate;
+/// Functions related to the greatest common divisor
+pub mod gcd;
+/// Functions related to modular arithmetic
+pub mod modular;
+/// Functions related to integer partitions
+pub mod partitions;
 /// Functions related to perfect numbers
 pub mod perfect_numbers;
 /// Functions related to prime numbers
 pub mod primes;
+/// Functions related to rational numbers
+pub mod rationals;
+/// Functions related to integer roots
+pub mod roots;
 /// Functions that generate mathematical sequences
 pub mod sequences;
+/// Functions related to sums of squares and cubes
+pub mod squares;
+/// Functions related to Euler's totient function
+pub mod totient;
+/// Functions related to writing numbers as words
+pub mod words;
+
+use num::{BigInt, BigRational, Signed, Zero};
+
+/// Calculates the absolute value of a rational.
+///
+/// `BigRational::new` always moves the sign into the numerator, but `BigRational::new_raw` doesn't,
+/// so this looks at both the numerator and denominator.
+///
+/// # Arguments
+///
+/// * `r` - The rational to take the absolute value of.
+///
+/// # Returns
+///
+/// $|r|$, in lowest terms with a positive denominator.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::rational_abs;
+/// use num::{BigInt, BigRational};
+///
+/// let r = BigRational::new_raw(BigInt::from(1), BigInt::from(-2));
+/// assert_eq!(rational_abs(&r), BigRational::new(BigInt::from(1), BigInt::from(2)));
+/// ```
+pub fn rational_abs(r: &BigRational) -> BigRational {
+    BigRational::new(r.numer().abs(), r.denom().abs())
+}
+
+/// Calculates the sign of a rational.
+///
+/// # Arguments
+///
+/// * `r` - The rational to find the sign of.
+///
+/// # Returns
+///
+/// `-1` if `r` is negative, `0` if it is zero, and `1` if it is positive,
+/// regardless of whether the minus sign is stored in the numerator or the denominator.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::rational_signum;
+/// use num::{BigInt, BigRational};
+///
+/// assert_eq!(rational_signum(&BigRational::new_raw(BigInt::from(1), BigInt::from(-2))), -1);
+/// assert_eq!(rational_signum(&BigRational::new_raw(BigInt::from(-1), BigInt::from(-2))), 1);
+/// assert_eq!(rational_signum(&BigRational::from(BigInt::from(0))), 0);
+/// ```
+pub fn rational_signum(r: &BigRational) -> i32 {
+    bigint_signum(r.numer()) * bigint_signum(r.denom())
+}
+
+/// Determines whether or not a rational is negative.
+///
+/// # Arguments
+///
+/// * `r` - The rational to check.
+///
+/// # Returns
+///
+/// Boolean representing whether or not `r` is less than zero.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::rational_is_negative;
+/// use num::{BigInt, BigRational};
+///
+/// assert_eq!(rational_is_negative(&BigRational::new_raw(BigInt::from(1), BigInt::from(-2))), true);
+/// assert_eq!(rational_is_negative(&BigRational::new(BigInt::from(1), BigInt::from(2))), false);
+/// ```
+pub fn rational_is_negative(r: &BigRational) -> bool {
+    rational_signum(r) < 0
+}
+
+/// Calculates the sign of a `BigInt` as an `i32`.
+///
+/// # Arguments
+///
+/// * `n` - The number to find the sign of.
+///
+/// # Returns
+///
+/// `-1`, `0`, or `1`.
+fn bigint_signum(n: &BigInt) -> i32 {
+    if n.is_zero() {
+        0
+    } else if n.is_negative() {
+        -1
+    } else {
+        1
+    }
+}
@@ -0,0 +1,397 @@
+use crate::gcd::{gcd, gcd_extended};
+use crate::primes::prime_factorization;
+use crate::totient::euler_totient;
+use num::integer::ExtendedGcd;
+use num::{BigInt, Integer, One, Signed, Zero};
+
+/// Calculates $b^e \bmod m$ using *binary exponentiation* (square-and-multiply).
+///
+/// Intermediate products are done in `u128`, so any `u64` modulus is safe from overflow.
+///
+/// # Arguments
+///
+/// * `base` - The base $b$.
+/// * `exp` - The exponent $e$.
+/// * `modulus` - The modulus $m$, which must not be 0.
+///
+/// # Returns
+///
+/// $b^e \bmod m$.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::modular::mod_pow;
+/// assert_eq!(mod_pow(2, 10, 1000), 24);
+/// assert_eq!(mod_pow(3, 0, 7), 1);
+/// assert_eq!(mod_pow(5, 3, 1), 0);
+/// ```
+pub fn mod_pow(base: u64, exp: u64, modulus: u64) -> u64 {
+    let m: u128 = modulus as u128;
+    let mut result: u128 = 1 % m;
+    let mut b: u128 = base as u128 % m;
+    let mut e: u64 = exp;
+    while e > 0 {
+        if e & 1 == 1 {
+            result = result * b % m;
+        }
+        b = b * b % m;
+        e >>= 1;
+    }
+    result as u64
+}
+
+/// Calculates $a \cdot b \bmod m$ for `u128`s without overflowing.
+///
+/// When both factors fit in 64 bits the product is done directly. Otherwise this uses *double-and-add*
+/// (the additive version of square-and-multiply), keeping every intermediate value below $m$
+/// so that nothing ever needs more than 128 bits.
+///
+/// # Arguments
+///
+/// * `a` - The first factor.
+/// * `b` - The second factor.
+/// * `m` - The modulus, which must not be 0.
+///
+/// # Returns
+///
+/// $a \cdot b \bmod m$.
+///
+/// # Panics
+///
+/// Panics if `m` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::modular::mod_mul;
+/// assert_eq!(mod_mul(6, 7, 10), 2);
+/// // u128::MAX = -1 (mod u128::MAX - 1), so this is (-1)(-1) = 1
+/// assert_eq!(mod_mul(u128::MAX, u128::MAX, u128::MAX - 1), 1);
+/// ```
+pub fn mod_mul(a: u128, b: u128, m: u128) -> u128 {
+    let mut a: u128 = a % m;
+    let mut b: u128 = b % m;
+    if a <= u64::MAX as u128 && b <= u64::MAX as u128 {
+        return a * b % m;
+    }
+    let mut result: u128 = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = mod_add(result, a, m);
+        }
+        a = mod_add(a, a, m);
+        b >>= 1;
+    }
+    result
+}
+
+/// Calculates $a + b \bmod m$ for `a` and `b` already reduced modulo `m`, without overflowing.
+///
+/// # Arguments
+///
+/// * `a` - The first term, less than `m`.
+/// * `b` - The second term, less than `m`.
+/// * `m` - The modulus.
+///
+/// # Returns
+///
+/// $a + b \bmod m$.
+fn mod_add(a: u128, b: u128, m: u128) -> u128 {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// Calculates the *multiplicative order* of `a` modulo `n`.
+///
+/// The order is the smallest positive $k$ such that $a^k \equiv 1 \pmod{n}$.
+/// By Euler's theorem $a^{\varphi(n)} \equiv 1 \pmod{n}$, and the order always divides $\varphi(n)$,
+/// so starting from $k = \varphi(n)$ each prime factor $q$ of $\varphi(n)$ is divided out of $k$
+/// for as long as $a^{k / q} \equiv 1$ still holds.
+/// Finding $\varphi(n)$ and factoring it are done by trial division, so this takes $O(\sqrt{n})$ time.
+///
+/// # Arguments
+///
+/// * `a` - The number to find the order of.
+/// * `n` - The modulus.
+///
+/// # Returns
+///
+/// `Some(k)` with the order of `a`, or `None` if `a` and `n` are not coprime (or `n` is 0), since then no such $k$ exists.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::modular::multiplicative_order;
+/// assert_eq!(multiplicative_order(2, 7), Some(3)); // 2^3 = 8 = 1 (mod 7)
+/// assert_eq!(multiplicative_order(3, 7), Some(6));
+/// assert_eq!(multiplicative_order(2, 6), None);
+/// ```
+pub fn multiplicative_order(a: u64, n: u64) -> Option<u64> {
+    if n == 0 || gcd(a, n) != 1 {
+        return None;
+    }
+    if n == 1 {
+        return Some(1);
+    }
+    let phi: u64 = euler_totient(n);
+    let mut order: u64 = phi;
+    for (q, _) in prime_factorization(phi) {
+        while order.is_multiple_of(q) && mod_pow(a, order / q, n) == 1 {
+            order /= q;
+        }
+    }
+    Some(order)
+}
+
+/// Calculates the binomial coefficient $\binom{n}{k} \bmod p$ for a prime $p$ using *Lucas' theorem*.
+///
+/// Writing $n$ and $k$ in base $p$ as $n = n_r \cdots n_1 n_0$ and $k = k_r \cdots k_1 k_0$,
+/// $$\binom{n}{k} \equiv \prod_{i=0}^{r}\binom{n_i}{k_i} \pmod{p}$$
+///
+/// Each of the smaller binomials has $n_i, k_i < p$, so it can be found with factorials
+/// and Fermat's little theorem ($a^{-1} \equiv a^{p-2} \pmod{p}$) without ever forming the huge number $\binom{n}{k}$.
+/// Each digit takes up to $\min(k_i, n_i - k_i)$ multiplications, which is $O(p)$, so this is fast for small primes
+/// (or small `k`) but not for huge `n` and `k` modulo a large prime.
+///
+/// # Arguments
+///
+/// * `n` - The size of the set to choose from.
+/// * `k` - The number of elements to choose.
+/// * `p` - The prime modulus.
+///
+/// # Returns
+///
+/// $\binom{n}{k} \bmod p$, which is 0 when $k > n$.
+///
+/// # Panics
+///
+/// Panics if `p` is less than 2.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::modular::binomial_mod_prime;
+/// assert_eq!(binomial_mod_prime(10, 3, 7), 1); // 120 = 17 * 7 + 1
+/// assert_eq!(binomial_mod_prime(1000000, 500000, 1000000007), 996692777);
+/// ```
+pub fn binomial_mod_prime(n: u64, k: u64, p: u64) -> u64 {
+    if p < 2 {
+        panic!("The modulus must be a prime");
+    }
+    if k > n {
+        return 0;
+    }
+    let mut n: u64 = n;
+    let mut k: u64 = k;
+    let mut result: u64 = 1;
+    while k > 0 {
+        let digit: u64 = small_binomial_mod_prime(n % p, k % p, p);
+        result = (result as u128 * digit as u128 % p as u128) as u64;
+        if result == 0 {
+            return 0;
+        }
+        n /= p;
+        k /= p;
+    }
+    result
+}
+
+/// Calculates $\binom{n}{k} \bmod p$ directly, for $n < p$.
+///
+/// # Arguments
+///
+/// * `n` - The size of the set to choose from, less than `p`.
+/// * `k` - The number of elements to choose.
+/// * `p` - The prime modulus.
+///
+/// # Returns
+///
+/// $\binom{n}{k} \bmod p$.
+fn small_binomial_mod_prime(n: u64, k: u64, p: u64) -> u64 {
+    if k > n {
+        return 0;
+    }
+    // C(n, k) = C(n, n - k), so use the shorter product
+    let k: u64 = k.min(n - k);
+    let mut numerator: u128 = 1;
+    let mut denominator: u128 = 1;
+    for i in 0..k {
+        numerator = numerator * (n - i) as u128 % p as u128;
+        denominator = denominator * (i + 1) as u128 % p as u128;
+    }
+    let inverse: u64 = mod_pow(denominator as u64, p - 2, p);
+    (numerator * inverse as u128 % p as u128) as u64
+}
+
+/// Solves the *linear congruence* $a x \equiv b \pmod{n}$.
+///
+/// Writing $g = \gcd(a, n)$, there are no solutions unless $g \mid b$, in which case
+/// there are exactly $g$ solutions modulo $n$, spaced $\frac{n}{g}$ apart.
+/// The first is found by dividing through by $g$ and multiplying by the inverse of $\frac{a}{g}$
+/// modulo $\frac{n}{g}$ (from the extended Euclidean algorithm).
+///
+/// # Arguments
+///
+/// * `a` - The coefficient of $x$.
+/// * `b` - The right hand side.
+/// * `n` - The modulus.
+///
+/// # Returns
+///
+/// Every solution $x$ with $0 \le x < n$, in increasing order (empty if there are none).
+///
+/// # Panics
+///
+/// Panics if `n` is 0 or larger than `i64::MAX`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::modular::solve_linear_congruence;
+/// assert_eq!(solve_linear_congruence(14, 30, 100), vec![45, 95]);
+/// assert_eq!(solve_linear_congruence(3, 4, 7), vec![6]);
+/// assert_eq!(solve_linear_congruence(2, 3, 4), vec![]);
+/// ```
+pub fn solve_linear_congruence(a: i64, b: i64, n: u64) -> Vec<i64> {
+    if n == 0 || n > i64::MAX as u64 {
+        panic!("The modulus must be between 1 and i64::MAX");
+    }
+    let n: i128 = n as i128;
+    let a: i128 = (a as i128).rem_euclid(n);
+    let b: i128 = (b as i128).rem_euclid(n);
+    let (g, inverse, _): (i128, i128, i128) = gcd_extended(a, n);
+    if b % g != 0 {
+        return vec![];
+    }
+    let step: i128 = n / g;
+    // both factors are below 2^63, so their product fits in an i128
+    let first: i128 = (inverse.rem_euclid(step) * (b / g)) % step;
+    (0..g).map(|k| (first + k * step) as i64).collect()
+}
+
+/// Calculates the *modular inverse* of `a` modulo `m` for `BigInt`s, the $x$ with $a x \equiv 1 \pmod{m}$.
+///
+/// The extended Euclidean algorithm gives $a x + m y = \gcd(a, m)$, so when $\gcd(a, m) = 1$ the coefficient $x$ is the inverse.
+/// Unlike the fixed-width helpers this never overflows, so it works for RSA-sized moduli.
+///
+/// # Arguments
+///
+/// * `a` - The number to invert. It may be negative or larger than `m`.
+/// * `m` - The modulus, which must be positive.
+///
+/// # Returns
+///
+/// `Some` inverse in the range $[0, m)$, or `None` if `a` and `m` aren't coprime (so no inverse exists).
+///
+/// # Panics
+///
+/// Panics if `m` is not positive.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::modular::mod_inverse_big;
+/// use num::BigInt;
+///
+/// assert_eq!(mod_inverse_big(&BigInt::from(3), &BigInt::from(11)), Some(BigInt::from(4))); // 3 * 4 = 12
+/// assert_eq!(mod_inverse_big(&BigInt::from(6), &BigInt::from(9)), None);
+/// ```
+pub fn mod_inverse_big(a: &BigInt, m: &BigInt) -> Option<BigInt> {
+    if !m.is_positive() {
+        panic!("Modulus must be positive, got {}", m);
+    }
+    let extended: ExtendedGcd<BigInt> = a.mod_floor(m).extended_gcd(m);
+    if !extended.gcd.is_one() {
+        return None;
+    }
+    Some(extended.x.mod_floor(m))
+}
+
+/// Solves a system of congruences $x \equiv r_i \pmod{m_i}$ using the *Chinese remainder theorem*, for `BigInt`s.
+///
+/// The congruences are merged one at a time. Two congruences $x \equiv r_1 \pmod{m_1}$ and $x \equiv r_2 \pmod{m_2}$
+/// are compatible exactly when $\gcd(m_1, m_2)$ divides $r_2 - r_1$, and then they combine into a single
+/// congruence modulo $\operatorname{lcm}(m_1, m_2)$. So the moduli don't need to be pairwise coprime.
+///
+/// # Arguments
+///
+/// * `residues` - The residues $r_i$.
+/// * `moduli` - The moduli $m_i$, which must all be positive.
+///
+/// # Returns
+///
+/// `Some` smallest non-negative solution (which is unique modulo the lcm of the moduli),
+/// or `None` if the congruences contradict each other. With no congruences, this is `Some(0)`.
+///
+/// # Panics
+///
+/// Panics if `residues` and `moduli` have different lengths, or if a modulus is not positive.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::modular::crt_big;
+/// use num::BigInt;
+///
+/// // x = 2 (mod 3), x = 3 (mod 5), x = 2 (mod 7)
+/// let residues: Vec<BigInt> = vec![2, 3, 2].into_iter().map(BigInt::from).collect();
+/// let moduli: Vec<BigInt> = vec![3, 5, 7].into_iter().map(BigInt::from).collect();
+/// assert_eq!(crt_big(&residues, &moduli), Some(BigInt::from(23)));
+/// ```
+pub fn crt_big(residues: &[BigInt], moduli: &[BigInt]) -> Option<BigInt> {
+    if residues.len() != moduli.len() {
+        panic!(
+            "Got {} residues but {} moduli",
+            residues.len(),
+            moduli.len()
+        );
+    }
+    let mut x: BigInt = BigInt::zero();
+    let mut modulus: BigInt = BigInt::one();
+    for (r, m) in residues.iter().zip(moduli) {
+        if !m.is_positive() {
+            panic!("Modulus must be positive, got {}", m);
+        }
+        let g: BigInt = modulus.gcd(m);
+        let difference: BigInt = r - &x;
+        if !difference.is_multiple_of(&g) {
+            return None;
+        }
+        // solve modulus * t = difference (mod m), then x + modulus * t satisfies both congruences
+        let reduced: BigInt = m / &g;
+        let t: BigInt =
+            (difference / &g * mod_inverse_big(&(&modulus / &g), &reduced)?).mod_floor(&reduced);
+        x += &modulus * t;
+        modulus *= reduced;
+    }
+    Some(x.mod_floor(&modulus))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mod_add, small_binomial_mod_prime};
+
+    #[test]
+    fn mod_add_test() {
+        assert_eq!(mod_add(3, 4, 10), 7);
+        assert_eq!(mod_add(6, 4, 10), 0);
+        assert_eq!(mod_add(9, 9, 10), 8);
+        assert_eq!(
+            mod_add(u128::MAX - 1, u128::MAX - 1, u128::MAX),
+            u128::MAX - 2
+        );
+    }
+
+    #[test]
+    fn small_binomial_mod_prime_test() {
+        assert_eq!(small_binomial_mod_prime(5, 2, 7), 3);
+        assert_eq!(small_binomial_mod_prime(6, 0, 7), 1);
+        assert_eq!(small_binomial_mod_prime(6, 6, 7), 1);
+        assert_eq!(small_binomial_mod_prime(3, 5, 7), 0);
+        assert_eq!(small_binomial_mod_prime(10, 5, 1000000007), 252);
+    }
+}
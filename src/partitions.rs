@@ -0,0 +1,139 @@
+use num::{BigInt, One, Zero};
+
+/// Counts the *partitions* of a number, the ways of writing it as a sum of positive integers (ignoring order).
+///
+/// Uses Euler's *pentagonal number theorem*, which gives the recurrence
+/// $$p(n) = \sum_{k \ge 1} (-1)^{k + 1} \left( p\left(n - \frac{k(3k - 1)}{2}\right) + p\left(n - \frac{k(3k + 1)}{2}\right) \right)$$
+/// with $p(0) = 1$ and $p(n) = 0$ for negative $n$.
+///
+/// [A000041](https://oeis.org/A000041)
+///
+/// # Arguments
+///
+/// * `n` - The number to partition.
+///
+/// # Returns
+///
+/// $p(n)$ as a `BigInt`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::partitions::partition_count;
+/// use num::BigInt;
+///
+/// assert_eq!(partition_count(5), BigInt::from(7)); // 5, 4+1, 3+2, 3+1+1, 2+2+1, 2+1+1+1, 1+1+1+1+1
+/// assert_eq!(partition_count(100), BigInt::from(190569292));
+/// ```
+pub fn partition_count(n: u64) -> BigInt {
+    let n: usize = n as usize;
+    let mut p: Vec<BigInt> = vec![BigInt::one()];
+    for m in 1..=n {
+        let mut total: BigInt = BigInt::zero();
+        for k in 1.. {
+            // the generalized pentagonal numbers k(3k - 1) / 2 and k(3k + 1) / 2
+            let first: usize = k * (3 * k - 1) / 2;
+            if first > m {
+                break;
+            }
+            let mut term: BigInt = p[m - first].clone();
+            let second: usize = first + k;
+            if second <= m {
+                term += &p[m - second];
+            }
+            if k % 2 == 1 {
+                total += term;
+            } else {
+                total -= term;
+            }
+        }
+        p.push(total);
+    }
+    p.swap_remove(n)
+}
+
+/// Counts the ways to make an amount from coins of the given denominations.
+///
+/// Any number of each coin may be used, and the order of the coins doesn't matter.
+/// This is the standard coin change dynamic program: the coins are added one denomination at a time,
+/// and the ways to make $a$ grow by the ways to make $a - c$ for each coin $c$.
+///
+/// With the denominations $1, 2, \ldots, n$, this counts the partitions of $n$ (see `partition_count`).
+///
+/// # Arguments
+///
+/// * `amount` - The amount to make.
+/// * `denominations` - The values of the coins. Repeated values are treated as different coins.
+///
+/// # Returns
+///
+/// The number of ways to make `amount` as a `BigInt`. There is always exactly one way to make 0.
+///
+/// # Panics
+///
+/// Panics if any denomination is 0, since then there would be infinitely many ways.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::partitions::count_ways_to_make;
+/// use num::BigInt;
+///
+/// assert_eq!(count_ways_to_make(5, &[1, 2]), BigInt::from(3)); // 1+1+1+1+1, 2+1+1+1, 2+2+1
+/// assert_eq!(count_ways_to_make(3, &[2]), BigInt::from(0));
+/// ```
+pub fn count_ways_to_make(amount: u64, denominations: &[u64]) -> BigInt {
+    if denominations.contains(&0) {
+        panic!("Coins must have a positive value");
+    }
+    let amount: usize = amount as usize;
+    let mut ways: Vec<BigInt> = vec![BigInt::zero(); amount + 1];
+    ways[0] = BigInt::one();
+    for &coin in denominations {
+        let coin: usize = coin as usize;
+        for a in coin..=amount {
+            let previous: BigInt = ways[a - coin].clone();
+            ways[a] += previous;
+        }
+    }
+    ways.swap_remove(amount)
+}
+
+/// Counts the partitions of a number into *distinct* parts.
+///
+/// This is the 0/1 version of the coin change problem: each part $1, 2, \ldots, n$ may be used at most once,
+/// so the parts are added one at a time, updating the amounts from largest to smallest.
+///
+/// By Euler's partition theorem, this is also the number of partitions of $n$ into odd parts.
+///
+/// [A000009](https://oeis.org/A000009)
+///
+/// # Arguments
+///
+/// * `n` - The number to partition.
+///
+/// # Returns
+///
+/// The number of partitions of `n` into distinct parts, as a `BigInt`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::partitions::distinct_partition_count;
+/// use num::BigInt;
+///
+/// assert_eq!(distinct_partition_count(6), BigInt::from(4)); // 6, 5+1, 4+2, 3+2+1
+/// ```
+pub fn distinct_partition_count(n: u64) -> BigInt {
+    let n: usize = n as usize;
+    let mut ways: Vec<BigInt> = vec![BigInt::zero(); n + 1];
+    ways[0] = BigInt::one();
+    for part in 1..=n {
+        // going downwards means ways[a - part] doesn't use `part` yet
+        for a in (part..=n).rev() {
+            let previous: BigInt = ways[a - part].clone();
+            ways[a] += previous;
+        }
+    }
+    ways.swap_remove(n)
+}
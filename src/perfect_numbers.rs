@@ -1,20 +1,27 @@
-use super::primes::{generate_primes, is_prime};
+use super::primes::{generate_primes, lucas_lehmer, prime_factorization};
+use num::{BigInt, One, ToPrimitive};
 
 /// Uses the Euclid-Euler theorem to calculate even perfect numbers.
 ///
+/// Each perfect number is found as a `BigInt` and then converted to `T`,
+/// stopping at the first one that doesn't fit rather than overflowing.
+/// Use `generate_even_perfect_numbers_big` to get every one of them.
+///
 /// # Arguments
 ///
 /// * `n` - The max value for p in the formula $(2^{p-1})(2^p-1)$
 ///
 /// Returns
 ///
-/// `Vec<u32>` vector containing perfect numbers
+/// `Vec<T>` vector containing the perfect numbers that fit in `T`
 ///
 /// # Examples
 ///
 /// ```
 /// use bens_number_theory::perfect_numbers::generate_even_perfect_numbers;
 /// assert_eq!(generate_even_perfect_numbers(10), vec![6, 28, 496, 8128]);
+/// assert_eq!(generate_even_perfect_numbers(100_u64).len(), 8);
+/// assert_eq!(generate_even_perfect_numbers(100_u16), vec![6, 28, 496, 8128]);
 /// ```
 pub fn generate_even_perfect_numbers<T>(n: T) -> Vec<T>
 where
@@ -28,24 +35,50 @@ where
         + std::cmp::Ord
         + Copy,
 {
-    // use the euclid-euler theorem
-    // lets start by generating prime numbers up until n
-    let primes: Vec<T> = generate_primes(n);
     let mut nums: Vec<T> = vec![];
-    for prime in primes {
-        if is_prime(2_i32.pow(prime.to_u32().unwrap()) - 1) {
-            nums.push(
-                T::from_u32(
-                    2_u32.pow((prime - T::one()).to_u32().unwrap())
-                        * (2_u32.pow(prime.to_u32().unwrap()) - 1),
-                )
-                .unwrap(),
-            );
+    for perfect in generate_even_perfect_numbers_big(n.to_u32().unwrap()) {
+        // the perfect numbers are increasing, so none of the rest fit either
+        match perfect.to_u128().and_then(T::from_u128) {
+            Some(perfect) => nums.push(perfect),
+            None => break,
         }
     }
     nums
 }
 
+/// Uses the Euclid-Euler theorem to calculate even perfect numbers as `BigInt`s.
+///
+/// Each prime $p$ below `n` whose Mersenne number $2^p - 1$ passes the Lucas–Lehmer test gives the
+/// perfect number $2^{p-1}(2^p - 1)$, which is computed exactly however large it gets.
+///
+/// [A000396](https://oeis.org/A000396)
+///
+/// # Arguments
+///
+/// * `n` - The max value for p in the formula $(2^{p-1})(2^p-1)$
+///
+/// # Returns
+///
+/// Vector containing the perfect numbers, in ascending order.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::perfect_numbers::generate_even_perfect_numbers_big;
+/// use num::BigInt;
+///
+/// let perfect: Vec<BigInt> = generate_even_perfect_numbers_big(19);
+/// assert_eq!(perfect.len(), 6);
+/// assert_eq!(perfect[5], BigInt::from(8589869056_u64));
+/// ```
+pub fn generate_even_perfect_numbers_big(n: u32) -> Vec<BigInt> {
+    generate_primes(n)
+        .into_iter()
+        .filter(|&p| lucas_lehmer(p))
+        .map(|p| (BigInt::one() << (p - 1)) * ((BigInt::one() << p) - 1))
+        .collect()
+}
+
 /// Determines wether or not a given number is a "perfect number".
 ///
 /// `n` is a perfect number if the sum of all proper devisors of `n` results in `n`.
@@ -64,30 +97,24 @@ where
 /// use bens_number_theory::perfect_numbers::is_perfect_number;
 /// assert_eq!(is_perfect_number(6), true); // 1 + 2 + 3 = 6
 /// assert_eq!(is_perfect_number(8), false); // 1 + 2 + 4 != 8
+/// assert_eq!(is_perfect_number(240_u8), false); // s(240) = 504 doesn't fit in a u8
 /// ```
 pub fn is_perfect_number<T>(n: T) -> bool
 where
     T: num::traits::Zero
         + num::traits::One
         + num::FromPrimitive
+        + num::traits::CheckedAdd
+        + num::traits::CheckedMul
+        + std::ops::Sub<Output = T>
         + std::ops::Div<Output = T>
         + std::ops::Rem<Output = T>
         + std::ops::AddAssign
         + std::cmp::Ord
         + Copy,
 {
-    let mut divisors: Vec<T> = divisors(n);
-    divisors.pop();
-    let mut i: usize = 0;
-    let mut sum: T = T::zero();
-    while i < divisors.len() {
-        sum += divisors[i];
-        i += 1;
-    }
-    if sum == n {
-        return true;
-    }
-    false
+    // if s(n) overflows it's certainly bigger than n
+    n > T::zero() && checked_sigma_proper(n) == Some(n)
 }
 
 /// Generates a list containing the proper devisors of a given number.
@@ -106,6 +133,7 @@ where
 /// use bens_number_theory::perfect_numbers::divisors;
 /// assert_eq!(divisors(10), vec![1, 2, 5, 10]);
 /// assert_eq!(divisors(20), vec![1, 2, 4, 5, 10, 20]);
+/// assert_eq!(divisors(16), vec![1, 2, 4, 8, 16]);
 /// ```
 pub fn divisors<T>(n: T) -> Vec<T>
 where
@@ -120,14 +148,762 @@ where
 {
     let mut d: Vec<T> = vec![];
     let mut i: T = T::one();
-    while i < n / T::from_i32(2).unwrap() {
+    // i <= n / i is i <= sqrt(n), written so that it can't overflow
+    while i <= n / i {
         if n % i == T::zero() {
             d.push(i);
-            d.push(n / i);
+            // the square root of a perfect square pairs with itself
+            if n / i != i {
+                d.push(n / i);
+            }
         }
         i += T::one();
     }
     d.sort();
-    d.dedup();
     d
 }
+
+/// Calculates the `k`th even perfect number.
+///
+/// Every even perfect number has the form $2^{p-1}(2^p - 1)$ where $2^p - 1$ is a Mersenne prime (the Euclid–Euler theorem),
+/// so this walks the exponents $p$ in order, keeping those that pass the Lucas–Lehmer test.
+///
+/// # Arguments
+///
+/// * `k` - Which perfect number to find, starting from 1.
+///
+/// # Returns
+///
+/// The `k`th even perfect number as a `BigInt`, since they grow extremely quickly.
+///
+/// # Panics
+///
+/// Panics if `k` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::perfect_numbers::nth_perfect_number;
+/// use num::BigInt;
+///
+/// assert_eq!(nth_perfect_number(1), BigInt::from(6));
+/// assert_eq!(nth_perfect_number(5), BigInt::from(33550336));
+/// ```
+pub fn nth_perfect_number(k: usize) -> BigInt {
+    if k == 0 {
+        panic!("Perfect numbers are indexed from 1");
+    }
+    let mut found: usize = 0;
+    let mut p: u32 = 1;
+    while found < k {
+        p += 1;
+        if lucas_lehmer(p) {
+            found += 1;
+        }
+    }
+    (BigInt::one() << (p - 1)) * ((BigInt::one() << p) - 1)
+}
+
+/// Counts the positive divisors of a given number, $d(n)$.
+///
+/// If $n = p_1^{e_1} p_2^{e_2} \cdots p_k^{e_k}$, then every divisor picks an exponent from $0$ to $e_i$ for each prime, so
+/// $$d(n) = \prod_{i=1}^{k}(e_i + 1)$$
+///
+/// # Arguments
+///
+/// * `n` - The number to count the divisors of.
+///
+/// # Returns
+///
+/// The number of positive divisors of `n`, or 0 if `n` is not positive.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::perfect_numbers::count_divisors;
+/// assert_eq!(count_divisors(1), 1);
+/// assert_eq!(count_divisors(12), 6); // 1, 2, 3, 4, 6, 12
+/// assert_eq!(count_divisors(13_u64), 2);
+/// ```
+pub fn count_divisors<T>(n: T) -> T
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + num::FromPrimitive
+        + std::ops::Div<Output = T>
+        + std::ops::Rem<Output = T>
+        + std::ops::AddAssign
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    if n <= T::zero() {
+        return T::zero();
+    }
+    let mut count: u64 = 1;
+    for (_, exponent) in prime_factorization(n) {
+        count *= exponent as u64 + 1;
+    }
+    T::from_u64(count).unwrap()
+}
+
+/// Calculates the *divisor function* $\sigma_k(n)$, the sum of the `k`th powers of the divisors of `n`.
+///
+/// $$\sigma_k(n) = \sum_{d \mid n} d^k$$
+///
+/// Rather than listing every divisor, this uses the prime factorization $n = p_1^{e_1} \cdots p_r^{e_r}$
+/// and the fact that $\sigma_k$ is multiplicative:
+/// $$\sigma_k(n) = \prod_{i=1}^{r}\left(1 + p_i^k + p_i^{2k} + \cdots + p_i^{e_i k}\right)$$
+///
+/// $\sigma_0(n)$ is the number of divisors and $\sigma_1(n)$ is the sum of the divisors.
+///
+/// # Arguments
+///
+/// * `n` - The number to find the divisors of.
+/// * `k` - The power to raise each divisor to.
+///
+/// # Returns
+///
+/// $\sigma_k(n)$, or 0 if `n` is not positive.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::perfect_numbers::sigma;
+/// assert_eq!(sigma(12, 0), 6);
+/// assert_eq!(sigma(12, 1), 28); // 1 + 2 + 3 + 4 + 6 + 12
+/// assert_eq!(sigma(6_u64, 2), 50); // 1 + 4 + 9 + 36
+/// ```
+pub fn sigma<T>(n: T, k: u32) -> T
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + num::FromPrimitive
+        + std::ops::Mul<Output = T>
+        + std::ops::Div<Output = T>
+        + std::ops::Rem<Output = T>
+        + std::ops::AddAssign
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    if n <= T::zero() {
+        return T::zero();
+    }
+    let mut total: T = T::one();
+    for (p, exponent) in prime_factorization(n) {
+        let mut p_k: T = T::one();
+        for _ in 0..k {
+            p_k = p_k * p;
+        }
+        // 1 + p^k + p^2k + ... + p^ek
+        let mut term: T = T::one();
+        let mut sum: T = T::one();
+        for _ in 0..exponent {
+            term = term * p_k;
+            sum += term;
+        }
+        total = total * sum;
+    }
+    total
+}
+
+/// Calculates the sum of the proper divisors of `n`, every positive divisor except `n` itself.
+///
+/// $$s(n) = \sigma_1(n) - n$$
+///
+/// This is also called the *aliquot sum*. A number is perfect when $s(n) = n$, abundant when $s(n) > n$ and deficient when $s(n) < n$.
+///
+/// [A001065](https://oeis.org/A001065)
+///
+/// # Arguments
+///
+/// * `n` - The number to find the proper divisors of.
+///
+/// # Returns
+///
+/// $\sigma_1(n) - n$, or 0 if `n` is not positive.
+///
+/// # Panics
+///
+/// Panics if $s(n)$ doesn't fit in `T`. The full $\sigma_1(n)$ is never formed, so `sigma_proper(120_u8)` gives 240.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::perfect_numbers::sigma_proper;
+/// assert_eq!(sigma_proper(6), 6); // 1 + 2 + 3
+/// assert_eq!(sigma_proper(12), 16); // 1 + 2 + 3 + 4 + 6
+/// assert_eq!(sigma_proper(13_u64), 1);
+/// assert_eq!(sigma_proper(1), 0);
+/// ```
+pub fn sigma_proper<T>(n: T) -> T
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + num::FromPrimitive
+        + num::traits::CheckedAdd
+        + num::traits::CheckedMul
+        + std::ops::Sub<Output = T>
+        + std::ops::Div<Output = T>
+        + std::ops::Rem<Output = T>
+        + std::ops::AddAssign
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    checked_sigma_proper(n).expect("The sum of the proper divisors doesn't fit in the type of n")
+}
+
+/// Calculates $s(n)$ like `sigma_proper`, but returns `None` instead of overflowing.
+///
+/// Going through the prime powers $p^e$ of $n = p^e m$ one at a time,
+/// $$s(p^e m) = \sigma(p^e)\,s(m) + m\,\sigma(p^{e-1})$$
+/// Every term is at most $s(n)$ and every $m$ and $p^e$ is at most $n$, so unlike $\sigma(n) - n$
+/// this only overflows when $s(n)$ itself doesn't fit.
+///
+/// # Arguments
+///
+/// * `n` - The number to find the proper divisors of.
+///
+/// # Returns
+///
+/// `Some` $s(n)$ (0 if `n` is not positive), or `None` if it doesn't fit in `T`.
+fn checked_sigma_proper<T>(n: T) -> Option<T>
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + num::FromPrimitive
+        + num::traits::CheckedAdd
+        + num::traits::CheckedMul
+        + std::ops::Sub<Output = T>
+        + std::ops::Div<Output = T>
+        + std::ops::Rem<Output = T>
+        + std::ops::AddAssign
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    let mut s: T = T::zero();
+    if n <= T::zero() {
+        return Some(s);
+    }
+    // the part of n handled so far
+    let mut m: T = T::one();
+    for (p, exponent) in prime_factorization(n) {
+        let mut p_e: T = T::one();
+        for _ in 0..exponent {
+            p_e = p_e * p;
+        }
+        // sigma(p^(e - 1)) = (p^e - 1) / (p - 1), and sigma(p^e) = p^e + sigma(p^(e - 1))
+        let sigma_lower: T = (p_e - T::one()) / (p - T::one());
+        s = p_e
+            .checked_mul(&s)?
+            .checked_add(&sigma_lower.checked_mul(&s)?)?
+            .checked_add(&m.checked_mul(&sigma_lower)?)?;
+        m = m * p_e;
+    }
+    Some(s)
+}
+
+/// Determines whether or not two numbers are an *amicable pair*, meaning each is the sum of the proper divisors of the other.
+///
+/// $$s(a) = b \text{ and } s(b) = a, \quad a \ne b$$
+///
+/// A perfect number satisfies $s(n) = n$, but isn't amicable with itself.
+///
+/// [A259180](https://oeis.org/A259180)
+///
+/// # Arguments
+///
+/// * `a` - The first number of the pair.
+/// * `b` - The second number of the pair.
+///
+/// # Returns
+///
+/// Boolean representing whether or not `a` and `b` are amicable.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::perfect_numbers::are_amicable;
+/// assert_eq!(are_amicable(220, 284), true);
+/// assert_eq!(are_amicable(284, 220), true);
+/// assert_eq!(are_amicable(6, 6), false); // perfect, not amicable
+/// assert_eq!(are_amicable(220, 221), false);
+/// ```
+pub fn are_amicable<T>(a: T, b: T) -> bool
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + num::FromPrimitive
+        + num::traits::CheckedAdd
+        + num::traits::CheckedMul
+        + std::ops::Sub<Output = T>
+        + std::ops::Mul<Output = T>
+        + std::ops::Div<Output = T>
+        + std::ops::Rem<Output = T>
+        + std::ops::AddAssign
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    a != b
+        && a > T::zero()
+        && b > T::zero()
+        && checked_sigma_proper(a) == Some(b)
+        && checked_sigma_proper(b) == Some(a)
+}
+
+/// Finds every amicable pair whose members are both at most `limit`.
+///
+/// # Arguments
+///
+/// * `limit` - The (inclusive) upper bound on both numbers of each pair.
+///
+/// # Returns
+///
+/// Vector of the amicable pairs, each listed once with the smaller number first, in ascending order.
+/// Numbers whose sum of proper divisors doesn't fit in `T` are skipped, since their partner would be past `limit`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::perfect_numbers::amicable_pairs_up_to;
+/// assert_eq!(amicable_pairs_up_to(2000), vec![(220, 284), (1184, 1210)]);
+/// assert_eq!(amicable_pairs_up_to(1000_u64), vec![(220, 284)]);
+/// assert_eq!(amicable_pairs_up_to(250), vec![]); // 284 is over the limit
+/// ```
+pub fn amicable_pairs_up_to<T>(limit: T) -> Vec<(T, T)>
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + num::FromPrimitive
+        + num::traits::CheckedAdd
+        + num::traits::CheckedMul
+        + std::ops::Sub<Output = T>
+        + std::ops::Mul<Output = T>
+        + std::ops::Div<Output = T>
+        + std::ops::Rem<Output = T>
+        + std::ops::AddAssign
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    let mut pairs: Vec<(T, T)> = vec![];
+    let mut a: T = T::zero();
+    // increment first so that a never goes past limit
+    while a < limit {
+        a += T::one();
+        // a sum of proper divisors that doesn't fit in T is certainly past limit
+        let b: T = match checked_sigma_proper(a) {
+            Some(b) => b,
+            None => continue,
+        };
+        // only look forwards so each pair is found once, from its smaller number
+        if b > a && b <= limit && checked_sigma_proper(b) == Some(a) {
+            pairs.push((a, b));
+        }
+    }
+    pairs
+}
+
+/// Calculates the *aliquot sum* $s(i) = \sigma_1(i) - i$ (the sum of the proper divisors) of every `i` up to `limit`.
+///
+/// Each `d` is added to every multiple of `d` beyond itself, which takes $O(n \log n)$ time in total.
+/// Searches over many numbers (for amicable pairs, abundant numbers, etc.) then become array lookups.
+///
+/// # Arguments
+///
+/// * `limit` - The largest number to find the aliquot sum of.
+///
+/// # Returns
+///
+/// Vector of length `limit + 1` where index `i` holds $s(i)$ (with $s(0) = 0$).
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::perfect_numbers::aliquot_sum_sieve;
+/// let s = aliquot_sum_sieve(300);
+/// assert_eq!(s[220], 284);
+/// assert_eq!(s[284], 220);
+/// assert_eq!(s[28], 28);
+/// ```
+pub fn aliquot_sum_sieve(limit: usize) -> Vec<u64> {
+    let mut s: Vec<u64> = vec![0; limit + 1];
+    for d in 1..=limit / 2 {
+        for multiple in (2 * d..=limit).step_by(d) {
+            s[multiple] += d as u64;
+        }
+    }
+    s
+}
+
+/// Calculates $\sigma_k(i)$ for every `i` up to `limit` by adding $d^k$ to every multiple of each `d`.
+///
+/// # Arguments
+///
+/// * `limit` - The largest number to find the divisor sum of.
+/// * `k` - The power each divisor is raised to.
+///
+/// # Returns
+///
+/// Vector of length `limit + 1` where index `i` holds $\sigma_k(i)$ (with $\sigma_k(0) = 0$).
+fn sigma_sieve(limit: usize, k: u32) -> Vec<u64> {
+    let mut s: Vec<u64> = vec![0; limit + 1];
+    for d in 1..=limit {
+        let power: u64 = (d as u64).pow(k);
+        for multiple in (d..=limit).step_by(d) {
+            s[multiple] += power;
+        }
+    }
+    s
+}
+
+/// Lazily yields the divisor sums $\sigma_k(n)$ for $n = 1, 2, 3, \dots$
+///
+/// The iterator keeps a divisor-sum sieve and doubles its size whenever it runs out,
+/// which is much cheaper than factoring each `n` when scanning for perfect or abundant numbers.
+///
+/// # Arguments
+///
+/// * `k` - The power each divisor is raised to.
+///
+/// # Returns
+///
+/// An endless iterator over $\sigma_k(1), \sigma_k(2), \sigma_k(3), \dots$
+///
+/// # Panics
+///
+/// The iterator panics (in debug builds) if a value overflows a `u64`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::perfect_numbers::sigma_iter;
+/// let s: Vec<u64> = sigma_iter(1).take(6).collect();
+/// assert_eq!(s, vec![1, 3, 4, 7, 6, 12]);
+/// ```
+pub fn sigma_iter(k: u32) -> impl Iterator<Item = u64> {
+    let mut s: Vec<u64> = vec![];
+    (1_usize..).map(move |n| {
+        if n >= s.len() {
+            s = sigma_sieve((2 * n).max(64), k);
+        }
+        s[n]
+    })
+}
+
+/// Generates the *highly composite numbers* below a given limit.
+///
+/// A highly composite number has more divisors than any smaller positive integer.
+/// These were studied extensively by Ramanujan.
+///
+/// [A002182](https://oeis.org/A002182)
+///
+/// # Arguments
+///
+/// * `limit` - The (exclusive) upper limit.
+///
+/// # Returns
+///
+/// Vector of the highly composite numbers below `limit`, in ascending order.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::perfect_numbers::highly_composite_below;
+/// assert_eq!(highly_composite_below(100), vec![1, 2, 4, 6, 12, 24, 36, 48, 60]);
+/// ```
+pub fn highly_composite_below(limit: u64) -> Vec<u64> {
+    let mut record: u64 = 0;
+    let mut nums: Vec<u64> = vec![];
+    for n in 1..limit {
+        let d: u64 = count_divisors(n);
+        if d > record {
+            record = d;
+            nums.push(n);
+        }
+    }
+    nums
+}
+
+/// Generates all divisors of a given number using a precomputed smallest-prime-factor table.
+///
+/// `n` is factored in $O(\log n)$ steps by repeatedly dividing out `spf[n]`,
+/// and then every divisor is built from the combinations of the prime powers.
+/// When finding the divisors of many numbers, this is much faster than trial division in `divisors`.
+///
+/// # Arguments
+///
+/// * `n` - The number to find the divisors of.
+/// * `spf` - A smallest-prime-factor table covering `n`, such as the one from `primes::smallest_prime_factors`.
+///
+/// # Returns
+///
+/// Vector containing all divisors of `n` in ascending order (including 1 and `n`). 0 has no listed divisors.
+///
+/// # Panics
+///
+/// Panics if `n` is beyond the end of `spf`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::perfect_numbers::divisors_from_spf;
+/// use bens_number_theory::primes::smallest_prime_factors;
+///
+/// let spf = smallest_prime_factors(100);
+/// assert_eq!(divisors_from_spf(36, &spf), vec![1, 2, 3, 4, 6, 9, 12, 18, 36]);
+/// assert_eq!(divisors_from_spf(97, &spf), vec![1, 97]);
+/// ```
+pub fn divisors_from_spf(n: usize, spf: &[usize]) -> Vec<usize> {
+    if n == 0 {
+        return vec![];
+    }
+    let mut d: Vec<usize> = vec![1];
+    let mut m: usize = n;
+    while m > 1 {
+        let p: usize = spf[m];
+        let mut exponent: u32 = 0;
+        while m.is_multiple_of(p) {
+            m /= p;
+            exponent += 1;
+        }
+        // multiply every divisor found so far by p, p^2, ..., p^exponent
+        let existing: usize = d.len();
+        let mut power: usize = 1;
+        for _ in 0..exponent {
+            power *= p;
+            for i in 0..existing {
+                d.push(d[i] * power);
+            }
+        }
+    }
+    d.sort();
+    d
+}
+
+/// Determines whether or not a given number is an even perfect number, using the Euclid–Euler theorem.
+///
+/// Every even perfect number has the form $2^{p-1}(2^p - 1)$ where $2^p - 1$ is a Mersenne prime.
+/// So rather than summing divisors like `is_perfect_number`, this splits `n` into its power-of-two part $2^k$
+/// and odd part $m$, and checks that $m = 2^{k+1} - 1$ with $m$ prime (using the Lucas–Lehmer test).
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+///
+/// # Returns
+///
+/// Boolean representing whether or not `n` is an even perfect number. Odd numbers always give `false`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::perfect_numbers::is_even_perfect_fast;
+/// assert_eq!(is_even_perfect_fast(8128), true);
+/// assert_eq!(is_even_perfect_fast(8130), false);
+/// assert_eq!(is_even_perfect_fast(2658455991569831744654692615953842176), true);
+/// ```
+pub fn is_even_perfect_fast(n: u128) -> bool {
+    if n == 0 {
+        return false;
+    }
+    let k: u32 = n.trailing_zeros();
+    if k == 0 || k >= 127 {
+        return false;
+    }
+    let m: u128 = n >> k;
+    m == (1 << (k + 1)) - 1 && lucas_lehmer(k + 1)
+}
+
+/// Determines whether or not a number is an *arithmetic number*.
+///
+/// A number is arithmetic when the arithmetic mean of its divisors is an integer,
+/// meaning $\sigma_1(n)$ is divisible by $\sigma_0(n)$ (the number of divisors).
+///
+/// [A003601](https://oeis.org/A003601)
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+///
+/// # Returns
+///
+/// Boolean representing whether or not `n` is an arithmetic number. 0 is not arithmetic.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::perfect_numbers::is_arithmetic_number;
+/// assert_eq!(is_arithmetic_number(6), true); // (1 + 2 + 3 + 6) / 4 = 3
+/// assert_eq!(is_arithmetic_number(2), false); // (1 + 2) / 2 = 1.5
+/// ```
+pub fn is_arithmetic_number(n: u64) -> bool {
+    n > 0 && sigma(n, 1).is_multiple_of(count_divisors(n))
+}
+
+/// Determines whether or not a number is *untouchable*, searching only up to a limit.
+///
+/// A number $n$ is untouchable if it is not the aliquot sum $s(k) = \sigma(k) - k$ of any $k$.
+/// Since there are infinitely many $k$, this can only check $k \le$ `search_limit` (using `aliquot_sum_sieve`),
+/// so a `true` result only means no $k$ was found below the limit.
+///
+/// That said, a composite $k$ has a divisor $d \ge \sqrt{k}$, so $s(k) \ge 1 + \sqrt{k}$,
+/// and a prime has $s(k) = 1$. This means a `search_limit` of at least $(n - 1)^2$ makes the answer exact.
+///
+/// [A005114](https://oeis.org/A005114)
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+/// * `search_limit` - The largest $k$ to check.
+///
+/// # Returns
+///
+/// `false` if some $k \le$ `search_limit` has $s(k) = n$, otherwise `true`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::perfect_numbers::is_untouchable;
+/// assert_eq!(is_untouchable(5, 100), true);
+/// assert_eq!(is_untouchable(6, 100), false); // s(6) = 1 + 2 + 3 = 6
+/// ```
+pub fn is_untouchable(n: u64, search_limit: u64) -> bool {
+    !aliquot_sum_sieve(search_limit as usize)
+        .iter()
+        .skip(1)
+        .any(|&s| s == n)
+}
+
+/// Finds the largest divisor of a number that is smaller than the number itself.
+///
+/// This is $\frac{n}{p}$ where $p$ is the smallest prime factor of $n$, so trial division only has to find $p$,
+/// which takes at most $O(\sqrt{n})$ steps and doesn't need the full list of divisors.
+///
+/// [A032742](https://oeis.org/A032742)
+///
+/// # Arguments
+///
+/// * `n` - The number to find the largest proper divisor of.
+///
+/// # Returns
+///
+/// `Some` largest proper divisor of `n`, which is 1 when `n` is prime, or `None` if `n` is 0 or 1.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::perfect_numbers::largest_proper_divisor;
+/// assert_eq!(largest_proper_divisor(12), Some(6));
+/// assert_eq!(largest_proper_divisor(13), Some(1));
+/// assert_eq!(largest_proper_divisor(1), None);
+/// ```
+pub fn largest_proper_divisor<T>(n: T) -> Option<T>
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + std::ops::Div<Output = T>
+        + std::ops::Rem<Output = T>
+        + std::ops::AddAssign
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    if n <= T::one() {
+        return None;
+    }
+    let mut i: T = T::one() + T::one();
+    // i <= n / i rather than i * i <= n, so nothing overflows
+    while i <= n / i {
+        if n % i == T::zero() {
+            return Some(n / i);
+        }
+        i += T::one();
+    }
+    Some(T::one())
+}
+
+/// Finds the `k` largest divisors of a number (including the number itself), in decreasing order.
+///
+/// Divisors come in pairs $(d, \frac{n}{d})$ with $d \le \sqrt{n}$, and the large halves of the pairs appear in
+/// decreasing order as $d$ increases. So trial division up to $\sqrt{n}$ finds them all,
+/// and stops early once it has `k` of them.
+///
+/// # Arguments
+///
+/// * `n` - The number to find the divisors of.
+/// * `k` - How many divisors to find.
+///
+/// # Returns
+///
+/// Vector of the `k` largest divisors of `n`, largest first. If `n` has fewer than `k` divisors, all of them are returned.
+/// 0 has no divisors here, so this is empty.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::perfect_numbers::k_largest_divisors;
+/// assert_eq!(k_largest_divisors(36, 4), vec![36, 18, 12, 9]);
+/// assert_eq!(k_largest_divisors(10, 10), vec![10, 5, 2, 1]);
+/// ```
+pub fn k_largest_divisors<T>(n: T, k: usize) -> Vec<T>
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + std::ops::Div<Output = T>
+        + std::ops::Rem<Output = T>
+        + std::ops::AddAssign
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    if n == T::zero() {
+        return vec![];
+    }
+    let mut large: Vec<T> = vec![];
+    let mut small: Vec<T> = vec![];
+    let mut i: T = T::one();
+    while i <= n / i && large.len() < k {
+        if n % i == T::zero() {
+            large.push(n / i);
+            if i != n / i {
+                small.push(i);
+            }
+        }
+        i += T::one();
+    }
+    large.extend(small.into_iter().rev());
+    large.truncate(k);
+    large
+}
+
+/// Checks that there are no odd perfect numbers below a limit, by exhaustive search.
+///
+/// Whether any odd perfect number exists is one of the oldest open problems in mathematics.
+/// Every known perfect number is even (of the Euclid–Euler form $2^{p-1}(2^p - 1)$),
+/// and computer searches have ruled out odd perfect numbers below $10^{1500}$, so this should always return `true`.
+///
+/// Rather than calling `is_perfect_number` on each odd number, the aliquot sums are found all at once
+/// with `aliquot_sum_sieve`, and every odd $n$ is checked for $s(n) = n$.
+///
+/// # Arguments
+///
+/// * `limit` - The (exclusive) upper bound of the search.
+///
+/// # Returns
+///
+/// `true` if no odd number below `limit` is perfect.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::perfect_numbers::no_odd_perfect_below;
+/// assert_eq!(no_odd_perfect_below(10000), true);
+/// ```
+pub fn no_odd_perfect_below(limit: u64) -> bool {
+    if limit < 2 {
+        return true;
+    }
+    let s: Vec<u64> = aliquot_sum_sieve(limit as usize - 1);
+    s.iter()
+        .enumerate()
+        .skip(1)
+        .step_by(2)
+        .all(|(n, &aliquot)| aliquot != n as u64)
+}
@@ -1,6 +1,14 @@
+use crate::gcd::gcd;
+use crate::modular::mod_mul;
+use crate::roots::isqrt;
+use num::{BigInt, Integer, One, Signed, Zero};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
 /// Check if a given number is prime.
 ///
-/// This function takes a number `n`
+/// This function takes a number `n` and trial divides it by the primes up to $\sqrt{n}$.
+/// The cutoff is found with integer arithmetic (stopping once $p^2 > n$), so it is exact for every integer width.
 ///
 /// # Arguments
 ///
@@ -23,6 +31,7 @@ where
         + num::traits::One
         + num::FromPrimitive
         + num::ToPrimitive
+        + std::ops::Add<Output = T>
         + std::ops::Sub<Output = T>
         + std::ops::Div<Output = T>
         + std::ops::Rem<Output = T>
@@ -37,19 +46,19 @@ where
         return false;
     }
 
-    let limit: f32 = (n.to_f32().unwrap()).sqrt();
-    let p: Vec<T> = generate_primes((n / T::from_i32(2).unwrap()) + T::one());
+    // every prime up to the exact square root of n, with no floating point rounding
+    let p: Vec<T> = generate_primes(isqrt(n) + T::one() + T::one());
     for prime in &p {
+        // prime * prime > n, written so that it can't overflow
+        if *prime > n / *prime {
+            return true;
+        }
         if n % *prime == T::zero() {
             return false;
         }
-        if prime.to_f32().unwrap() > limit {
-            return true;
-        }
     }
-    // due to Bertrand's postulate, this should never be reached
-    // (if we are calculating sequentially)
-    false
+    // no prime up to sqrt(n) divides n
+    true
 }
 
 /// Generates a list of prime numbers using the Sieve of Eratosthenes algorithm.
@@ -59,7 +68,7 @@ where
 /// * `limit`
 ///     * The `limit` parameter specifies the upper limit up to which you want to generate prime numbers.
 ///     * The function `generate_primes` will generate all prime numbers up to this limit and return
-/// them as a vector.
+///       them as a vector.
 ///
 /// # Returns
 ///
@@ -80,6 +89,7 @@ where
         + num::FromPrimitive
         + std::ops::Mul<Output = T>
         + std::ops::Sub<Output = T>
+        + std::ops::Div<Output = T>
         + std::ops::Rem<Output = T>
         + std::ops::AddAssign
         + std::cmp::PartialOrd
@@ -130,20 +140,24 @@ where
         + num::FromPrimitive
         + std::ops::Mul<Output = T>
         + std::ops::Sub<Output = T>
+        + std::ops::Div<Output = T>
         + std::ops::Rem<Output = T>
         + std::ops::AddAssign
         + std::cmp::PartialOrd
         + Clone
         + Copy,
 {
-    let limit: f32 = (n.to_f32().unwrap()).sqrt();
+    if n <= T::one() {
+        return false;
+    }
     for prime in &p {
+        // prime * prime > n, written so that it can't overflow
+        if *prime > n / *prime {
+            return true;
+        }
         if n % *prime == T::zero() {
             return false;
         }
-        if prime.to_f32().unwrap() > limit {
-            return true;
-        }
     }
     // due to Bertrand's postulate, this should never be reached
     // (if we are calculating sequentially)
@@ -238,3 +252,2277 @@ where
     }
     false
 }
+
+/// Calculates the prime factors of a given number, with multiplicity.
+///
+/// Uses trial division, first by 2 and then by every odd number up to $\sqrt{n}$.
+/// Whatever is left over after dividing out every factor below $\sqrt{n}$ is itself prime.
+///
+/// # Arguments
+///
+/// * `n` - The number to factor.
+///
+/// # Returns
+///
+/// Vector of the prime factors of `n` in ascending order, repeated according to their multiplicity.
+/// Numbers less than 2 have no prime factors.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::prime_factors;
+/// assert_eq!(prime_factors(360), vec![2, 2, 2, 3, 3, 5]);
+/// assert_eq!(prime_factors(13_u64), vec![13]);
+/// assert_eq!(prime_factors(1), vec![]);
+/// ```
+pub fn prime_factors<T>(n: T) -> Vec<T>
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + num::FromPrimitive
+        + std::ops::Div<Output = T>
+        + std::ops::Rem<Output = T>
+        + std::ops::AddAssign
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    let mut factors: Vec<T> = vec![];
+    let mut n: T = n;
+    let two: T = T::from_i32(2).unwrap();
+    while n > T::one() && n % two == T::zero() {
+        factors.push(two);
+        n = n / two;
+    }
+    let mut i: T = T::from_i32(3).unwrap();
+    // `i <= n / i` rather than `i * i <= n` so we never overflow
+    while i <= n / i {
+        while n % i == T::zero() {
+            factors.push(i);
+            n = n / i;
+        }
+        i += two;
+    }
+    if n > T::one() {
+        factors.push(n);
+    }
+    factors
+}
+
+/// Calculates the prime factorization of a given number, as pairs of each distinct prime and its exponent.
+///
+/// $$n = p_1^{e_1} p_2^{e_2} \cdots p_k^{e_k}$$
+///
+/// The primes come from `prime_factors`, which are sorted, so equal primes are next to each other.
+///
+/// # Arguments
+///
+/// * `n` - The number to factor.
+///
+/// # Returns
+///
+/// Vector of `(prime, exponent)` pairs in ascending order of prime.
+/// Numbers less than 2 have no prime factors, so give an empty vector.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::prime_factorization;
+/// assert_eq!(prime_factorization(360), vec![(2, 3), (3, 2), (5, 1)]);
+/// assert_eq!(prime_factorization(1), vec![]);
+/// ```
+pub fn prime_factorization<T>(n: T) -> Vec<(T, u32)>
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + num::FromPrimitive
+        + std::ops::Div<Output = T>
+        + std::ops::Rem<Output = T>
+        + std::ops::AddAssign
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    let mut pairs: Vec<(T, u32)> = vec![];
+    for p in prime_factors(n) {
+        match pairs.last_mut() {
+            Some((last, exponent)) if *last == p => *exponent += 1,
+            _ => pairs.push((p, 1)),
+        }
+    }
+    pairs
+}
+
+/// Checks if a given number is a *strong probable prime* to a given base.
+///
+/// Writing $n - 1 = 2^s d$ with $d$ odd, $n$ is a strong probable prime to base $a$ if either
+/// $$a^d \equiv 1 \pmod{n} \text{ or } a^{2^r d} \equiv -1 \pmod{n} \text{ for some } 0 \le r < s.$$
+///
+/// Every odd prime passes this test for every base, so failing it proves `n` is composite.
+/// Passing it for several bases makes `n` very likely to be prime (this is one round of Miller–Rabin).
+///
+/// # Arguments
+///
+/// * `n` - The number to test.
+/// * `base` - The base $a$ to test with. A base that is a multiple of `n` tells us nothing, so it passes.
+///
+/// # Returns
+///
+/// `false` if `n` is definitely composite (or less than 2), `true` if `n` is a strong probable prime to `base`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::is_strong_probable_prime;
+/// use num::BigInt;
+///
+/// assert_eq!(is_strong_probable_prime(&BigInt::from(97), &BigInt::from(2)), true);
+/// assert_eq!(is_strong_probable_prime(&BigInt::from(91), &BigInt::from(2)), false);
+/// // 2047 = 23 * 89 fools base 2, but not base 3
+/// assert_eq!(is_strong_probable_prime(&BigInt::from(2047), &BigInt::from(2)), true);
+/// assert_eq!(is_strong_probable_prime(&BigInt::from(2047), &BigInt::from(3)), false);
+/// ```
+pub fn is_strong_probable_prime(n: &BigInt, base: &BigInt) -> bool {
+    let two: BigInt = BigInt::from(2);
+    if n < &two {
+        return false;
+    }
+    if n == &two {
+        return true;
+    }
+    if n.is_even() {
+        return false;
+    }
+    let a: BigInt = base.mod_floor(n);
+    if a.is_zero() {
+        return true;
+    }
+
+    let n_minus_one: BigInt = n - 1;
+    let mut d: BigInt = n_minus_one.clone();
+    let mut s: u32 = 0;
+    while d.is_even() {
+        d >>= 1;
+        s += 1;
+    }
+
+    let mut x: BigInt = a.modpow(&d, n);
+    if x.is_one() || x == n_minus_one {
+        return true;
+    }
+    for _ in 1..s {
+        x = x.modpow(&two, n);
+        if x == n_minus_one {
+            return true;
+        }
+    }
+    false
+}
+
+/// Checks if a given number is a *Wilson prime*.
+///
+/// By Wilson's theorem, every prime $p$ satisfies $(p - 1)! \equiv -1 \pmod{p}$.
+/// A Wilson prime is a prime that satisfies the much stronger condition
+/// $$(p - 1)! \equiv -1 \pmod{p^2}$$
+///
+/// The only known Wilson primes are 5, 13, and 563.
+///
+/// [A007540](https://oeis.org/A007540)
+///
+/// # Arguments
+///
+/// * `p` - The number to check.
+///
+/// # Returns
+///
+/// Boolean value indicating whether `p` is a Wilson prime.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::is_wilson_prime;
+/// assert_eq!(is_wilson_prime(13), true);
+/// assert_eq!(is_wilson_prime(11), false);
+/// ```
+pub fn is_wilson_prime(p: u64) -> bool {
+    if !is_prime_lazy(p) {
+        return false;
+    }
+    // (p^2 - 1)^2 always fits in a u128
+    let modulus: u128 = p as u128 * p as u128;
+    let mut f: u128 = 1;
+    for k in 2..p as u128 {
+        f = (f * k) % modulus;
+    }
+    f == modulus - 1
+}
+
+/// The largest value `are_primes` will build a sieve up to.
+/// Anything larger is checked individually instead.
+const ARE_PRIMES_SIEVE_LIMIT: u64 = 10_000_000;
+
+/// Checks the primality of every number in a slice at once.
+///
+/// Rather than testing each number independently, a single sieve of Eratosthenes is built up to the
+/// largest number in `nums` (capped at ten million), and every query within it becomes a lookup.
+/// Numbers beyond the sieve are checked with a strong probable prime test to each of the first twelve prime bases,
+/// which is deterministic for every `u64`.
+///
+/// # Arguments
+///
+/// * `nums` - The numbers to check for primality.
+///
+/// # Returns
+///
+/// Vector where the `i`th value indicates whether `nums[i]` is prime.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::are_primes;
+/// assert_eq!(are_primes(&[1, 2, 9, 11, 2147483647]), vec![false, true, false, true, true]);
+/// ```
+pub fn are_primes(nums: &[u64]) -> Vec<bool> {
+    let max: u64 = nums.iter().copied().max().unwrap_or(0);
+    let sieve: Vec<bool> = sieve_of_eratosthenes(max.min(ARE_PRIMES_SIEVE_LIMIT) as usize);
+    nums.iter()
+        .map(|&n| match sieve.get(n as usize) {
+            Some(&prime) => prime,
+            _ => passes_first_prime_bases(&BigInt::from(n)),
+        })
+        .collect()
+}
+
+/// Builds a sieve of Eratosthenes.
+///
+/// # Arguments
+///
+/// * `limit` - The largest number to sieve.
+///
+/// # Returns
+///
+/// Vector of length `limit + 1` where index `i` is `true` exactly when `i` is prime.
+fn sieve_of_eratosthenes(limit: usize) -> Vec<bool> {
+    let mut sieve: Vec<bool> = vec![true; limit + 1];
+    sieve[0] = false;
+    if limit >= 1 {
+        sieve[1] = false;
+    }
+    let mut i: usize = 2;
+    while i * i <= limit {
+        if sieve[i] {
+            let mut j: usize = i * i;
+            while j <= limit {
+                sieve[j] = false;
+                j += i;
+            }
+        }
+        i += 1;
+    }
+    sieve
+}
+
+/// The first twelve primes, which as Miller–Rabin bases correctly classify every $n < 3.18 \times 10^{23}$.
+const MILLER_RABIN_BASES: [u128; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Checks if `n` is a strong probable prime to each of `MILLER_RABIN_BASES`.
+///
+/// This is deterministic for $n < 3.18 \times 10^{23}$, which covers every `u64`,
+/// and overwhelmingly likely to be right beyond that.
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+///
+/// # Returns
+///
+/// Boolean value indicating whether `n` is (probably) prime.
+pub(crate) fn passes_first_prime_bases(n: &BigInt) -> bool {
+    MILLER_RABIN_BASES
+        .iter()
+        .all(|&base| is_strong_probable_prime(n, &BigInt::from(base)))
+}
+
+/// Checks if the Mersenne number $M_p = 2^p - 1$ is prime using the *Lucas–Lehmer test*.
+///
+/// $$s_0 = 4, \quad s_{i} = s_{i-1}^2 - 2 \pmod{M_p}$$
+///
+/// For $p > 2$, $M_p$ is prime if and only if $s_{p-2} \equiv 0 \pmod{M_p}$.
+/// Since $M_p$ can only be prime when $p$ is, composite exponents are rejected before the test is run.
+///
+/// # Arguments
+///
+/// * `p` - The exponent of the Mersenne number.
+///
+/// # Returns
+///
+/// Boolean value indicating whether $2^p - 1$ is prime.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::lucas_lehmer;
+/// assert_eq!(lucas_lehmer(7), true); // 127
+/// assert_eq!(lucas_lehmer(11), false); // 2047 = 23 * 89
+/// ```
+pub fn lucas_lehmer(p: u32) -> bool {
+    if p == 2 {
+        return true;
+    }
+    if !is_prime_lazy(p) {
+        return false;
+    }
+    let m: BigInt = (BigInt::one() << p) - 1;
+    // adding m first keeps s^2 - 2 from going negative
+    let m_minus_two: BigInt = &m - 2;
+    let mut s: BigInt = BigInt::from(4);
+    for _ in 0..p - 2 {
+        s = mod_mersenne(&s * &s + &m_minus_two, p, &m);
+    }
+    s.is_zero()
+}
+
+/// Checks if a given exponent `p` produces a Mersenne prime $2^p - 1$.
+///
+/// The current `is_mersenne_prime` takes the Mersenne number itself, which caps it at `u128`.
+/// This takes the *exponent* instead and runs the Lucas–Lehmer test on `BigInt`s (which is what GIMPS does),
+/// so exponents like 89, 107, or 521 can be tested even though $2^p - 1$ is far too large for any primitive type.
+///
+/// [A000043](https://oeis.org/A000043)
+///
+/// # Arguments
+///
+/// * `p` - The exponent of the Mersenne number.
+///
+/// # Returns
+///
+/// Boolean value indicating whether $2^p - 1$ is a Mersenne prime.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::is_mersenne_prime_exponent;
+/// assert_eq!(is_mersenne_prime_exponent(89), true);
+/// assert_eq!(is_mersenne_prime_exponent(101), false);
+/// ```
+pub fn is_mersenne_prime_exponent(p: u32) -> bool {
+    lucas_lehmer(p)
+}
+
+/// Reduces a non-negative number modulo the Mersenne number $m = 2^p - 1$ without division.
+///
+/// Since $2^p \equiv 1 \pmod{m}$, the bits above position `p` can simply be added onto the low `p` bits:
+/// $$x \equiv (x \bmod 2^p) + \left\lfloor \frac{x}{2^p} \right\rfloor \pmod{m}$$
+///
+/// # Arguments
+///
+/// * `x` - The non-negative number to reduce.
+/// * `p` - The exponent of the Mersenne number.
+/// * `m` - The Mersenne number $2^p - 1$.
+///
+/// # Returns
+///
+/// $x \bmod m$.
+fn mod_mersenne(x: BigInt, p: u32, m: &BigInt) -> BigInt {
+    let mut x: BigInt = x;
+    while &x > m {
+        x = (&x & m) + (&x >> p);
+    }
+    if &x == m {
+        return BigInt::zero();
+    }
+    x
+}
+
+/// Generates a list of prime numbers using the *Sieve of Atkin*.
+///
+/// Rather than crossing off multiples of every prime, the Sieve of Atkin uses the fact that
+/// (for squarefree $n$) the number of solutions to certain quadratic forms reveals whether $n$ is prime:
+///
+/// * $n = 4x^2 + y^2$ with $n \bmod 12 \in \\{1, 5\\}$
+/// * $n = 3x^2 + y^2$ with $n \bmod 12 = 7$
+/// * $n = 3x^2 - y^2$ (where $x > y$) with $n \bmod 12 = 11$
+///
+/// $n$ is prime exactly when it has an odd number of solutions to its form and is squarefree,
+/// so each solution flips $n$ in the sieve, and then multiples of the squares of primes are removed.
+///
+/// # Arguments
+///
+/// * `limit` - The (exclusive) upper limit of the primes to generate.
+///
+/// # Returns
+///
+/// Vector of all prime numbers below `limit`.
+/// This matches `generate_primes(limit)` for every `limit` of at least 4
+/// (`generate_primes` always includes 2 and 3).
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::sieve_of_atkin;
+/// assert_eq!(sieve_of_atkin(30), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+/// assert_eq!(sieve_of_atkin(2), vec![]);
+/// ```
+pub fn sieve_of_atkin(limit: u64) -> Vec<u64> {
+    let size: usize = limit as usize;
+    let mut sieve: Vec<bool> = vec![false; size];
+
+    let mut x: u64 = 1;
+    while x * x < limit {
+        let mut y: u64 = 1;
+        while y * y < limit {
+            let n: u64 = 4 * x * x + y * y;
+            if n < limit && (n % 12 == 1 || n % 12 == 5) {
+                sieve[n as usize] ^= true;
+            }
+            let n: u64 = 3 * x * x + y * y;
+            if n < limit && n % 12 == 7 {
+                sieve[n as usize] ^= true;
+            }
+            if x > y {
+                let n: u64 = 3 * x * x - y * y;
+                if n < limit && n % 12 == 11 {
+                    sieve[n as usize] ^= true;
+                }
+            }
+            y += 1;
+        }
+        x += 1;
+    }
+
+    // remove everything divisible by the square of a prime
+    let mut r: u64 = 5;
+    while r * r < limit {
+        if sieve[r as usize] {
+            let mut m: u64 = r * r;
+            while m < limit {
+                sieve[m as usize] = false;
+                m += r * r;
+            }
+        }
+        r += 1;
+    }
+
+    let mut primes: Vec<u64> = [2, 3].into_iter().filter(|&p| p < limit).collect();
+    primes.extend((5..limit).filter(|&n| sieve[n as usize]));
+    primes
+}
+
+/// Calculates the smallest prime factor of every number up to `limit` using a *linear sieve*.
+///
+/// Unlike the sieve of Eratosthenes, each composite is crossed off exactly once (by its smallest prime factor),
+/// so this runs in $O(n)$ time.
+/// With the table, any `n` up to `limit` can be factored in $O(\log n)$ steps by repeatedly dividing by `spf[n]`.
+///
+/// # Arguments
+///
+/// * `limit` - The largest number to find the smallest prime factor of.
+///
+/// # Returns
+///
+/// Vector of length `limit + 1` where index `i` holds the smallest prime factor of `i`.
+/// Index 0 holds 0 and index 1 holds 1, since neither has a prime factor.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::smallest_prime_factors;
+/// assert_eq!(smallest_prime_factors(10), vec![0, 1, 2, 3, 2, 5, 2, 7, 2, 3, 2]);
+/// ```
+pub fn smallest_prime_factors(limit: usize) -> Vec<usize> {
+    let mut spf: Vec<usize> = vec![0; limit + 1];
+    if limit >= 1 {
+        spf[1] = 1;
+    }
+    let mut primes: Vec<usize> = vec![];
+    for i in 2..=limit {
+        if spf[i] == 0 {
+            spf[i] = i;
+            primes.push(i);
+        }
+        for &p in &primes {
+            if p > spf[i] || i * p > limit {
+                break;
+            }
+            spf[i * p] = p;
+        }
+    }
+    spf
+}
+
+/// An endless iterator over the prime numbers, using an *incremental sieve of Eratosthenes*.
+///
+/// Instead of sieving a fixed range, the iterator remembers the next odd multiple of each prime found so far
+/// (starting at its square) in a `HashMap`.
+/// A candidate that isn't in the map is prime, and a candidate that is gets its prime moved along to the next free multiple.
+///
+/// Starting past 2 would mean sieving (and remembering) every prime below the start, so `starting_from`
+/// and `typed_starting_from` check each odd candidate with `is_prime_miller_rabin` instead.
+///
+/// `new` and `starting_from` yield `u64`s. `typed` and `typed_starting_from` work with any integer type `T`,
+/// and the iterator ends once the next candidate would overflow `T`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::Primes;
+///
+/// let first: Vec<u64> = Primes::new().take(5).collect();
+/// assert_eq!(first, vec![2, 3, 5, 7, 11]);
+///
+/// let above_100: Vec<u64> = Primes::starting_from(100).take(3).collect();
+/// assert_eq!(above_100, vec![101, 103, 107]);
+///
+/// // every prime that fits in a u8
+/// assert_eq!(Primes::<u8>::typed().count(), 54);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Primes<T = u64> {
+    /// The next number to check, or `None` once it would overflow
+    candidate: Option<T>,
+    /// Maps an upcoming composite to the step (twice its prime) used to find the next one,
+    /// or `None` if candidates are checked with `is_prime_miller_rabin` instead
+    composites: Option<HashMap<T, T>>,
+}
+
+impl Primes<u64> {
+    /// Creates an iterator over every prime, starting from 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bens_number_theory::primes::Primes;
+    /// assert_eq!(Primes::new().nth(9), Some(29));
+    /// ```
+    pub fn new() -> Self {
+        Primes::typed()
+    }
+
+    /// Creates an iterator over the primes greater than or equal to `n`.
+    ///
+    /// Nothing below `n` is generated, so this is as fast for huge `n` as for small ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The smallest value that may be yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bens_number_theory::primes::Primes;
+    /// assert_eq!(Primes::starting_from(7).next(), Some(7));
+    /// assert_eq!(Primes::starting_from(8).next(), Some(11));
+    /// assert_eq!(Primes::starting_from(u64::MAX - 100).next(), Some(18446744073709551521));
+    /// ```
+    pub fn starting_from(n: u64) -> Self {
+        Primes::typed_starting_from(n)
+    }
+}
+
+impl<T> Primes<T>
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + num::traits::CheckedAdd
+        + num::traits::CheckedMul
+        + num::ToPrimitive
+        + std::ops::Div<Output = T>
+        + std::hash::Hash
+        + std::cmp::Ord
+        + Copy,
+{
+    /// Creates an iterator over every prime of type `T`, starting from 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bens_number_theory::primes::Primes;
+    /// assert_eq!(Primes::<u32>::typed().nth(9), Some(29));
+    /// assert_eq!(Primes::<i8>::typed().last(), Some(127));
+    /// ```
+    pub fn typed() -> Self {
+        Primes {
+            candidate: Some(T::one() + T::one()),
+            composites: Some(HashMap::new()),
+        }
+    }
+
+    /// Creates an iterator over the primes of type `T` greater than or equal to `n`.
+    ///
+    /// Each odd candidate from `n` on is checked with `is_prime_miller_rabin`, which is exact below
+    /// $3.18 \times 10^{23}$ (so for every 64-bit type) and otherwise wrong with a probability of at most $4^{-20}$.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The smallest value that may be yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bens_number_theory::primes::Primes;
+    /// assert_eq!(Primes::typed_starting_from(8_u16).next(), Some(11));
+    /// assert_eq!(Primes::typed_starting_from(-5_i32).next(), Some(2));
+    /// ```
+    pub fn typed_starting_from(n: T) -> Self {
+        let two: T = T::one() + T::one();
+        if n <= two {
+            return Primes::typed();
+        }
+        // 2 is the only even prime, so start at the first odd number from n
+        let odd: Option<T> = if (n / two) * two == n {
+            n.checked_add(&T::one())
+        } else {
+            Some(n)
+        };
+        Primes {
+            candidate: odd,
+            composites: None,
+        }
+    }
+
+    /// Finds the next odd prime by checking each candidate with `is_prime_miller_rabin`.
+    ///
+    /// # Returns
+    ///
+    /// The next prime, or `None` once the candidates would overflow `T`.
+    fn next_tested_prime(&mut self) -> Option<T> {
+        let two: T = T::one() + T::one();
+        loop {
+            let n: T = self.candidate?;
+            self.candidate = n.checked_add(&two);
+            if is_prime_miller_rabin(n, 20) {
+                return Some(n);
+            }
+        }
+    }
+
+    /// Finds the next prime from the sieve.
+    ///
+    /// # Returns
+    ///
+    /// The next prime, or `None` once the candidates would overflow `T`.
+    fn next_sieved_prime(&mut self) -> Option<T> {
+        let two: T = T::one() + T::one();
+        let composites: &mut HashMap<T, T> = self.composites.as_mut()?;
+        let n: T = self.candidate?;
+        if n == two {
+            self.candidate = Some(two + T::one());
+            return Some(two);
+        }
+        loop {
+            let n: T = self.candidate?;
+            self.candidate = n.checked_add(&two);
+            match composites.remove(&n) {
+                None => {
+                    // n is prime, and its first odd multiple not crossed off by a smaller prime is n^2
+                    if let Some(square) = n.checked_mul(&n) {
+                        composites.insert(square, n + n);
+                    }
+                    return Some(n);
+                }
+                Some(step) => {
+                    let mut next: Option<T> = n.checked_add(&step);
+                    while let Some(m) = next {
+                        if let Entry::Vacant(entry) = composites.entry(m) {
+                            entry.insert(step);
+                            break;
+                        }
+                        next = m.checked_add(&step);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<T> Default for Primes<T>
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + num::traits::CheckedAdd
+        + num::traits::CheckedMul
+        + num::ToPrimitive
+        + std::ops::Div<Output = T>
+        + std::hash::Hash
+        + std::cmp::Ord
+        + Copy,
+{
+    fn default() -> Self {
+        Primes::typed()
+    }
+}
+
+impl<T> Iterator for Primes<T>
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + num::traits::CheckedAdd
+        + num::traits::CheckedMul
+        + num::ToPrimitive
+        + std::ops::Div<Output = T>
+        + std::hash::Hash
+        + std::cmp::Ord
+        + Copy,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.composites.is_some() {
+            self.next_sieved_prime()
+        } else {
+            self.next_tested_prime()
+        }
+    }
+}
+
+/// A node in a *factor tree*.
+///
+/// Every branch splits its value into two factors, and every leaf is a prime,
+/// so the leaves (read left to right) are the prime factorization of the root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FactorNode {
+    /// A prime, which can't be split any further.
+    Leaf(u64),
+    /// A composite `value` split into `left * right`, with `left <= right`.
+    Branch {
+        value: u64,
+        left: Box<FactorNode>,
+        right: Box<FactorNode>,
+    },
+}
+
+impl FactorNode {
+    /// Returns the number at this node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bens_number_theory::primes::factor_tree;
+    /// assert_eq!(factor_tree(360).value(), 360);
+    /// ```
+    pub fn value(&self) -> u64 {
+        match self {
+            FactorNode::Leaf(p) => *p,
+            FactorNode::Branch { value, .. } => *value,
+        }
+    }
+
+    /// Returns the primes at the leaves of the tree, from left to right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bens_number_theory::primes::factor_tree;
+    /// assert_eq!(factor_tree(12).leaves(), vec![2, 2, 3]);
+    /// ```
+    pub fn leaves(&self) -> Vec<u64> {
+        match self {
+            FactorNode::Leaf(p) => vec![*p],
+            FactorNode::Branch { left, right, .. } => {
+                let mut leaves: Vec<u64> = left.leaves();
+                leaves.extend(right.leaves());
+                leaves
+            }
+        }
+    }
+
+    /// Draws the tree, one node per line, with each child indented below its parent.
+    ///
+    /// # Returns
+    ///
+    /// A string such as
+    ///
+    /// ```text
+    /// 12
+    /// ├── 2
+    /// └── 6
+    ///     ├── 2
+    ///     └── 3
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bens_number_theory::primes::factor_tree;
+    /// assert_eq!(factor_tree(6).pretty_print(), "6\n├── 2\n└── 3\n");
+    /// ```
+    pub fn pretty_print(&self) -> String {
+        let mut out: String = format!("{}\n", self.value());
+        self.pretty_print_children("", &mut out);
+        out
+    }
+
+    /// Draws the children of this node below it.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The indentation (and lines of the ancestors) in front of each child.
+    /// * `out` - The string to draw into.
+    fn pretty_print_children(&self, prefix: &str, out: &mut String) {
+        if let FactorNode::Branch { left, right, .. } = self {
+            out.push_str(&format!("{}├── {}\n", prefix, left.value()));
+            left.pretty_print_children(&format!("{}│   ", prefix), out);
+            out.push_str(&format!("{}└── {}\n", prefix, right.value()));
+            right.pretty_print_children(&format!("{}    ", prefix), out);
+        }
+    }
+}
+
+impl std::fmt::Display for FactorNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.pretty_print())
+    }
+}
+
+/// Builds a *factor tree* for a number.
+///
+/// Each composite is split into its smallest prime factor $p$ and the cofactor $\frac{n}{p}$, and each prime becomes a leaf.
+/// The smallest factor is found by trial division upwards, after a Miller–Rabin test has ruled out $n$ being prime,
+/// so a split only costs as much as the size of its smallest factor (a number like $2p$ is split straight away).
+///
+/// # Arguments
+///
+/// * `n` - The number to factor.
+///
+/// # Returns
+///
+/// The root of the factor tree, whose leaves multiply to `n`.
+///
+/// # Panics
+///
+/// Panics if `n` is less than 2, since neither 0 nor 1 has a prime factorization to draw.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::{factor_tree, FactorNode};
+///
+/// let tree = factor_tree(15);
+/// assert_eq!(
+///     tree,
+///     FactorNode::Branch {
+///         value: 15,
+///         left: Box::new(FactorNode::Leaf(3)),
+///         right: Box::new(FactorNode::Leaf(5)),
+///     }
+/// );
+/// ```
+pub fn factor_tree(n: u64) -> FactorNode {
+    if n < 2 {
+        panic!("Only numbers greater than 1 have a factor tree");
+    }
+    if is_prime_miller_rabin(n, 0) {
+        return FactorNode::Leaf(n);
+    }
+    // n is composite, so it has a prime factor no greater than sqrt(n) and this finds the smallest one
+    let p: u64 = (2..=n.isqrt())
+        .find(|d| n.is_multiple_of(*d))
+        .expect("A composite number has a factor below its square root");
+    FactorNode::Branch {
+        value: n,
+        left: Box::new(FactorNode::Leaf(p)),
+        right: Box::new(factor_tree(n / p)),
+    }
+}
+
+/// Checks if a given number is prime using the *Baillie–PSW* test.
+///
+/// The number must be a strong probable prime to base 2 and also a strong Lucas probable prime
+/// (with parameters chosen by Selfridge's method). These two tests fail in very different ways,
+/// and no composite number passing both has ever been found (every number below $2^{64}$ has been checked).
+///
+/// # Arguments
+///
+/// * `n` - The number to test.
+///
+/// # Returns
+///
+/// `false` if `n` is definitely composite (or less than 2), `true` if `n` is a BPSW probable prime.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::is_prime_bpsw;
+/// use num::BigInt;
+///
+/// assert_eq!(is_prime_bpsw(&BigInt::from(97)), true);
+/// // 2047 = 23 * 89 fools the base 2 test, but not the Lucas test
+/// assert_eq!(is_prime_bpsw(&BigInt::from(2047)), false);
+/// ```
+pub fn is_prime_bpsw(n: &BigInt) -> bool {
+    if !is_strong_probable_prime(n, &BigInt::from(2)) {
+        return false;
+    }
+    if n == &BigInt::from(2) {
+        return true;
+    }
+    is_strong_lucas_probable_prime(n)
+}
+
+/// Checks if an odd number is a *strong Lucas probable prime*.
+///
+/// Uses Selfridge's parameters: $D$ is the first of $5, -7, 9, -11, \ldots$ with Jacobi symbol
+/// $\left(\frac{D}{n}\right) = -1$, $P = 1$ and $Q = \frac{1 - D}{4}$.
+/// Writing $n + 1 = 2^s d$ with $d$ odd, $n$ passes if $U_d \equiv 0 \pmod{n}$
+/// or $V_{2^r d} \equiv 0 \pmod{n}$ for some $0 \le r < s$.
+///
+/// # Arguments
+///
+/// * `n` - The odd number (greater than 2) to test.
+///
+/// # Returns
+///
+/// `false` if `n` is definitely composite, `true` if `n` is a strong Lucas probable prime.
+fn is_strong_lucas_probable_prime(n: &BigInt) -> bool {
+    // no suitable D exists for a perfect square
+    if n.sqrt().pow(2) == *n {
+        return false;
+    }
+    let mut d: BigInt = BigInt::from(5);
+    loop {
+        match jacobi_symbol(&d, n) {
+            -1 => break,
+            // D shares a factor with n
+            0 if d.abs() != *n => return false,
+            _ => {}
+        }
+        d = if d.is_positive() { -d - 2 } else { -d + 2 };
+    }
+    let q: BigInt = (BigInt::one() - &d) / 4;
+
+    let mut k: BigInt = n + 1;
+    let mut s: u32 = 0;
+    while k.is_even() {
+        k >>= 1;
+        s += 1;
+    }
+
+    // halves a number mod n (n is odd, so adding n to an odd number makes it even)
+    let half = |x: BigInt| -> BigInt {
+        let x: BigInt = if x.is_odd() { x + n } else { x };
+        (x / BigInt::from(2)).mod_floor(n)
+    };
+
+    // U_1 = 1 and V_1 = P = 1, then use the doubling and increment formulas on the bits of k
+    let mut u: BigInt = BigInt::one();
+    let mut v: BigInt = BigInt::one();
+    let mut q_k: BigInt = q.mod_floor(n);
+    for i in (0..k.bits() - 1).rev() {
+        u = (&u * &v).mod_floor(n);
+        v = (&v * &v - &q_k * BigInt::from(2)).mod_floor(n);
+        q_k = (&q_k * &q_k).mod_floor(n);
+        if k.bit(i) {
+            let next_u: BigInt = half(&u + &v);
+            v = half(&d * &u + &v);
+            u = next_u;
+            q_k = (&q_k * &q).mod_floor(n);
+        }
+    }
+
+    if u.is_zero() || v.is_zero() {
+        return true;
+    }
+    for _ in 1..s {
+        v = (&v * &v - &q_k * BigInt::from(2)).mod_floor(n);
+        if v.is_zero() {
+            return true;
+        }
+        q_k = (&q_k * &q_k).mod_floor(n);
+    }
+    false
+}
+
+/// Calculates the *Jacobi symbol* $\left(\frac{a}{n}\right)$.
+///
+/// # Arguments
+///
+/// * `a` - The "numerator", which may be negative.
+/// * `n` - The "denominator", which must be odd and positive.
+///
+/// # Returns
+///
+/// $-1$, $0$, or $1$. This is $0$ exactly when $\gcd(a, n) > 1$.
+fn jacobi_symbol(a: &BigInt, n: &BigInt) -> i32 {
+    let mut a: BigInt = a.mod_floor(n);
+    let mut n: BigInt = n.clone();
+    let mut result: i32 = 1;
+    while !a.is_zero() {
+        while a.is_even() {
+            a >>= 1;
+            // (2/n) = -1 exactly when n = 3 or 5 (mod 8)
+            let r: BigInt = &n % 8;
+            if r == BigInt::from(3) || r == BigInt::from(5) {
+                result = -result;
+            }
+        }
+        // quadratic reciprocity
+        std::mem::swap(&mut a, &mut n);
+        if &a % 4 == BigInt::from(3) && &n % 4 == BigInt::from(3) {
+            result = -result;
+        }
+        a = a.mod_floor(&n);
+    }
+    if n.is_one() {
+        result
+    } else {
+        0
+    }
+}
+
+/// Generates a random prime with exactly `bits` bits, using the thread-local random number generator.
+///
+/// See `random_prime_with` for the details (and for reproducible results).
+///
+/// # Arguments
+///
+/// * `bits` - The number of bits in the prime.
+///
+/// # Returns
+///
+/// A (BPSW probable) prime $p$ with $2^{bits - 1} \le p < 2^{bits}$.
+///
+/// # Panics
+///
+/// Panics if `bits` is less than 2.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::{is_prime_bpsw, random_prime};
+///
+/// let p = random_prime(64);
+/// assert_eq!(p.bits(), 64);
+/// assert!(is_prime_bpsw(&p));
+/// ```
+#[cfg(feature = "rand")]
+pub fn random_prime(bits: u32) -> BigInt {
+    random_prime_with(bits, &mut rand::thread_rng())
+}
+
+/// Generates a random prime with exactly `bits` bits.
+///
+/// A random odd number with its highest bit set is chosen, and then increased by 2 until
+/// it passes `is_prime_bpsw`. If that runs past $2^{bits}$, a new random number is chosen.
+///
+/// # Arguments
+///
+/// * `bits` - The number of bits in the prime.
+/// * `rng` - The random number generator to use. A seeded generator gives reproducible primes.
+///
+/// # Returns
+///
+/// A (BPSW probable) prime $p$ with $2^{bits - 1} \le p < 2^{bits}$.
+///
+/// # Panics
+///
+/// Panics if `bits` is less than 2.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::{is_prime_bpsw, random_prime_with};
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let p = random_prime_with(128, &mut rng);
+/// assert_eq!(p.bits(), 128);
+/// assert!(is_prime_bpsw(&p));
+/// ```
+#[cfg(feature = "rand")]
+pub fn random_prime_with<R: rand::Rng>(bits: u32, rng: &mut R) -> BigInt {
+    if bits < 2 {
+        panic!("There are no primes with fewer than 2 bits");
+    }
+    let mut bytes: Vec<u8> = vec![0; bits.div_ceil(8) as usize];
+    loop {
+        rng.fill_bytes(&mut bytes);
+        let mut candidate: BigInt = BigInt::from_bytes_le(num::bigint::Sign::Plus, &bytes);
+        // keep only the low `bits` bits, then force the highest and lowest ones on
+        candidate &= (BigInt::one() << bits) - 1;
+        candidate.set_bit(bits as u64 - 1, true);
+        candidate.set_bit(0, true);
+        while candidate.bits() == bits as u64 {
+            if is_prime_bpsw(&candidate) {
+                return candidate;
+            }
+            candidate += 2;
+        }
+    }
+}
+
+/// Finds the prime below a limit that can be written as the sum of the most consecutive primes.
+///
+/// For example, $41 = 2 + 3 + 5 + 7 + 11 + 13$ is the longest such sum below 100.
+///
+/// Using prefix sums $S_i = p_1 + \cdots + p_i$, each run of consecutive primes sums to $S_j - S_i$.
+/// Lengths are checked from longest to shortest, so the first prime found is the answer.
+///
+/// # Arguments
+///
+/// * `limit` - The (exclusive) upper bound on the prime.
+///
+/// # Returns
+///
+/// A tuple of the prime and the number of consecutive primes summing to it.
+/// If there are several primes with the longest sum, the smallest is returned.
+/// If there are no primes below `limit`, this is `(0, 0)`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::longest_consecutive_prime_sum_below;
+/// assert_eq!(longest_consecutive_prime_sum_below(100), (41, 6));
+/// assert_eq!(longest_consecutive_prime_sum_below(1000), (953, 21));
+/// ```
+pub fn longest_consecutive_prime_sum_below(limit: u64) -> (u64, usize) {
+    if limit < 3 {
+        return (0, 0);
+    }
+    let is_prime: Vec<bool> = sieve_of_eratosthenes(limit as usize - 1);
+    let mut prefix: Vec<u64> = vec![0];
+    for (p, _) in is_prime.iter().enumerate().filter(|(_, &p)| p) {
+        prefix.push(prefix[prefix.len() - 1] + p as u64);
+    }
+    // the longest run that could possibly fit starts at 2
+    let max_length: usize = prefix.iter().take_while(|&&s| s < limit).count() - 1;
+    for length in (1..=max_length).rev() {
+        for start in 0..prefix.len() - length {
+            let sum: u64 = prefix[start + length] - prefix[start];
+            if sum >= limit {
+                break;
+            }
+            if is_prime[sum as usize] {
+                return (sum, length);
+            }
+        }
+    }
+    (0, 0)
+}
+
+/// Generates a list of prime numbers by trial division, using a *2-3-5 wheel* to pick the candidates.
+///
+/// Every prime above 5 is coprime to $30 = 2 \cdot 3 \cdot 5$, so it must be one of the 8 residues
+/// $$1, 7, 11, 13, 17, 19, 23, 29 \pmod{30}$$
+/// Only those candidates are tested (against the primes up to their square root),
+/// skipping $\frac{22}{30}$ of the numbers that `generate_primes` would otherwise check.
+///
+/// # Arguments
+///
+/// * `limit` - The (exclusive) upper limit of the primes to generate.
+///
+/// # Returns
+///
+/// Vector of all prime numbers below `limit`.
+/// This matches `generate_primes(limit)` for every `limit` of at least 4
+/// (`generate_primes` always includes 2 and 3).
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::generate_primes_wheel;
+/// assert_eq!(generate_primes_wheel(30), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+/// assert_eq!(generate_primes_wheel(2), vec![]);
+/// ```
+pub fn generate_primes_wheel(limit: u64) -> Vec<u64> {
+    const WHEEL: [u64; 8] = [1, 7, 11, 13, 17, 19, 23, 29];
+    let mut primes: Vec<u64> = [2, 3, 5].into_iter().filter(|&p| p < limit).collect();
+    let mut base: u64 = 0;
+    while base < limit {
+        for offset in WHEEL {
+            let n: u64 = base + offset;
+            if n >= limit {
+                break;
+            }
+            // 1 is on the wheel, but isn't prime; the wheel primes themselves never need testing
+            if n == 1 {
+                continue;
+            }
+            let is_prime: bool = primes[3..]
+                .iter()
+                .take_while(|&&p| p * p <= n)
+                .all(|&p| !n.is_multiple_of(p));
+            if is_prime {
+                primes.push(n);
+            }
+        }
+        base += 30;
+    }
+    primes
+}
+
+/// Calculates the *prime signature* of a number, the exponents in its prime factorization.
+///
+/// Numbers with the same signature have the same "shape", such as $12 = 2^2 \cdot 3$ and $18 = 2 \cdot 3^2$,
+/// and share many multiplicative properties (like their number of divisors).
+///
+/// [A118914](https://oeis.org/A118914)
+///
+/// # Arguments
+///
+/// * `n` - The number to find the signature of.
+///
+/// # Returns
+///
+/// The exponents of the prime factorization of `n`, sorted in ascending order.
+/// Numbers less than 2 have an empty signature.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::prime_signature;
+/// assert_eq!(prime_signature(12), vec![1, 2]);
+/// assert_eq!(prime_signature(18), vec![1, 2]);
+/// assert_eq!(prime_signature(7), vec![1]);
+/// ```
+pub fn prime_signature<T>(n: T) -> Vec<u32>
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + num::FromPrimitive
+        + std::ops::Div<Output = T>
+        + std::ops::Rem<Output = T>
+        + std::ops::AddAssign
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    let mut signature: Vec<u32> = prime_factorization(n)
+        .into_iter()
+        .map(|(_, exponent)| exponent)
+        .collect();
+    signature.sort_unstable();
+    signature
+}
+
+/// Finds every number below a limit with a given prime signature.
+///
+/// # Arguments
+///
+/// * `signature` - The exponents of the prime factorization, in any order.
+/// * `limit` - The (exclusive) upper limit of the numbers to check.
+///
+/// # Returns
+///
+/// Vector of the numbers below `limit` whose `prime_signature` is `signature`, in ascending order.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::numbers_with_signature;
+/// assert_eq!(numbers_with_signature(&[2, 1], 50), vec![12, 18, 20, 28, 44, 45]);
+/// ```
+pub fn numbers_with_signature(signature: &[u32], limit: u64) -> Vec<u64> {
+    let mut signature: Vec<u32> = signature.to_vec();
+    signature.sort_unstable();
+    (1..limit)
+        .filter(|&n| prime_signature(n) == signature)
+        .collect()
+}
+
+/// Counts the *distinct* prime factors of a number, $\omega(n)$.
+///
+/// [A001221](https://oeis.org/A001221)
+///
+/// # Arguments
+///
+/// * `n` - The number to count the prime factors of.
+///
+/// # Returns
+///
+/// The number of different primes dividing `n`. Numbers less than 2 have none.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::omega;
+/// assert_eq!(omega(360), 3); // 2^3 * 3^2 * 5
+/// ```
+pub fn omega<T>(n: T) -> u32
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + num::FromPrimitive
+        + std::ops::Div<Output = T>
+        + std::ops::Rem<Output = T>
+        + std::ops::AddAssign
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    prime_factorization(n).len() as u32
+}
+
+/// Counts the prime factors of a number *with multiplicity*, $\Omega(n)$.
+///
+/// [A001222](https://oeis.org/A001222)
+///
+/// # Arguments
+///
+/// * `n` - The number to count the prime factors of.
+///
+/// # Returns
+///
+/// The sum of the exponents in the prime factorization of `n`. Numbers less than 2 have no prime factors.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::big_omega;
+/// assert_eq!(big_omega(360), 6); // 2^3 * 3^2 * 5
+/// ```
+pub fn big_omega<T>(n: T) -> u32
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + num::FromPrimitive
+        + std::ops::Div<Output = T>
+        + std::ops::Rem<Output = T>
+        + std::ops::AddAssign
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    prime_factorization(n)
+        .iter()
+        .map(|(_, exponent)| exponent)
+        .sum()
+}
+
+/// Calculates the *Liouville function* $\lambda(n) = (-1)^{\Omega(n)}$.
+///
+/// This is $1$ when $n$ has an even number of prime factors (counted with multiplicity) and $-1$ when it has an odd number.
+///
+/// [A008836](https://oeis.org/A008836)
+///
+/// # Arguments
+///
+/// * `n` - The (positive) number to evaluate $\lambda$ at.
+///
+/// # Returns
+///
+/// $\lambda(n)$, which is either $1$ or $-1$.
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::liouville;
+/// assert_eq!(liouville(1), 1);
+/// assert_eq!(liouville(12), -1); // 2 * 2 * 3
+/// ```
+pub fn liouville(n: u64) -> i8 {
+    if n == 0 {
+        panic!("The Liouville function is only defined for positive numbers");
+    }
+    if big_omega(n).is_multiple_of(2) {
+        1
+    } else {
+        -1
+    }
+}
+
+/// Calculates the summatory Liouville function $L(n) = \sum_{k=1}^{n}\lambda(k)$.
+///
+/// Pólya conjectured in 1919 that $L(n) \le 0$ for every $n \ge 2$, meaning most numbers have an odd number of prime factors.
+/// This holds for a very long way, but was disproven in 1958, and the smallest counterexample is $n = 906150257$.
+///
+/// Rather than factoring every $k$, $\Omega(k)$ is built up from `smallest_prime_factors` using $\Omega(k) = \Omega(k / p) + 1$.
+///
+/// [A002819](https://oeis.org/A002819)
+///
+/// # Arguments
+///
+/// * `n` - The last number in the sum.
+///
+/// # Returns
+///
+/// $L(n)$, which is 0 when `n` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::liouville_summatory;
+/// assert_eq!(liouville_summatory(8), -2);
+/// ```
+pub fn liouville_summatory(n: u64) -> i64 {
+    let spf: Vec<usize> = smallest_prime_factors(n as usize);
+    let mut big_omegas: Vec<u32> = vec![0; n as usize + 1];
+    let mut sum: i64 = 0;
+    for k in 1..=n as usize {
+        if k > 1 {
+            big_omegas[k] = big_omegas[k / spf[k]] + 1;
+        }
+        sum += if big_omegas[k].is_multiple_of(2) {
+            1
+        } else {
+            -1
+        };
+    }
+    sum
+}
+
+/// Calculates the *radical* (or squarefree kernel) of a number, the product of its distinct prime factors.
+///
+/// $$\operatorname{rad}(n) = \prod_{p \mid n} p$$
+///
+/// This is the key quantity in the *abc conjecture*.
+///
+/// [A007947](https://oeis.org/A007947)
+///
+/// # Arguments
+///
+/// * `n` - The number to find the radical of.
+///
+/// # Returns
+///
+/// The product of the distinct primes dividing `n`. Numbers less than 2 have no prime factors, so give 1.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::radical;
+/// assert_eq!(radical(360), 30); // 2^3 * 3^2 * 5
+/// assert_eq!(radical(1), 1);
+/// ```
+pub fn radical<T>(n: T) -> T
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + num::FromPrimitive
+        + std::ops::Mul<Output = T>
+        + std::ops::Div<Output = T>
+        + std::ops::Rem<Output = T>
+        + std::ops::AddAssign
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    prime_factorization(n)
+        .into_iter()
+        .fold(T::one(), |product, (p, _)| product * p)
+}
+
+/// Determines whether or not a number is *powerful*, meaning every prime factor appears at least squared.
+///
+/// Equivalently, $\operatorname{rad}(n)^2$ divides $n$.
+///
+/// [A001694](https://oeis.org/A001694)
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+///
+/// # Returns
+///
+/// Boolean representing whether or not every exponent in the prime factorization of `n` is at least 2.
+/// 1 is powerful (it has no prime factors), but 0 is not.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::is_powerful;
+/// assert_eq!(is_powerful(72), true); // 2^3 * 3^2
+/// assert_eq!(is_powerful(12), false); // 2^2 * 3
+/// ```
+pub fn is_powerful(n: u64) -> bool {
+    n > 0
+        && prime_factorization(n)
+            .iter()
+            .all(|&(_, exponent)| exponent >= 2)
+}
+
+/// Generates the *powerful numbers* below a given limit.
+///
+/// # Arguments
+///
+/// * `limit` - The (exclusive) upper limit of the numbers to check.
+///
+/// # Returns
+///
+/// Vector of the powerful numbers below `limit`, in ascending order.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::powerful_numbers_below;
+/// assert_eq!(powerful_numbers_below(40), vec![1, 4, 8, 9, 16, 25, 27, 32, 36]);
+/// ```
+pub fn powerful_numbers_below(limit: u64) -> Vec<u64> {
+    (1..limit).filter(|&n| is_powerful(n)).collect()
+}
+
+/// Determines whether or not a number is a *perfect power*, $n = m^k$ for some $k \ge 2$.
+///
+/// $n$ is a perfect $k$th power exactly when $k$ divides every exponent in its prime factorization,
+/// so $n$ is a perfect power when the gcd of those exponents is at least 2.
+///
+/// [A001597](https://oeis.org/A001597)
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+///
+/// # Returns
+///
+/// Boolean representing whether or not `n` is a perfect power. 1 is a perfect power ($1^2$), but 0 is not counted as one.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::is_perfect_power;
+/// assert_eq!(is_perfect_power(36), true); // 6^2
+/// assert_eq!(is_perfect_power(72), false); // 2^3 * 3^2
+/// ```
+pub fn is_perfect_power(n: u64) -> bool {
+    let exponent_gcd: u32 = prime_factorization(n)
+        .iter()
+        .fold(0, |g, &(_, exponent)| gcd(g, exponent));
+    n == 1 || exponent_gcd >= 2
+}
+
+/// Determines whether or not a number is an *Achilles number*, which is powerful but not a perfect power.
+///
+/// For example, $72 = 2^3 \cdot 3^2$ is powerful, but isn't $m^k$ for any $k \ge 2$.
+///
+/// [A052486](https://oeis.org/A052486)
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+///
+/// # Returns
+///
+/// Boolean representing whether or not `n` is an Achilles number.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::is_achilles;
+/// assert_eq!(is_achilles(72), true);
+/// assert_eq!(is_achilles(36), false); // 6^2
+/// ```
+pub fn is_achilles(n: u64) -> bool {
+    is_powerful(n) && !is_perfect_power(n)
+}
+
+/// A sieve of Eratosthenes stored as packed bits, for answering many primality queries up to a fixed limit.
+///
+/// Only odd numbers are tracked, and each one takes a single bit of a `Vec<u64>`,
+/// so the sieve uses about $\frac{1}{16}$ of the memory of a `Vec<bool>`
+/// (a limit of $10^9$ needs around 60 MB instead of 1 GB).
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::Sieve;
+///
+/// let sieve = Sieve::new(100);
+/// assert_eq!(sieve.is_prime(97), true);
+/// assert_eq!(sieve.is_prime(91), false); // 7 * 13
+/// assert_eq!(sieve.primes().take(5).collect::<Vec<u64>>(), vec![2, 3, 5, 7, 11]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sieve {
+    /// Bit `i` is set when the odd number $2i + 1$ is composite (or 1)
+    bits: Vec<u64>,
+    /// The largest number covered by the sieve
+    limit: u64,
+}
+
+impl Sieve {
+    /// Sieves every number up to `limit`.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - The largest number that can be queried.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bens_number_theory::primes::Sieve;
+    /// assert_eq!(Sieve::new(10).limit(), 10);
+    /// ```
+    pub fn new(limit: u64) -> Self {
+        let odd_count: usize = (limit / 2 + 1) as usize;
+        let mut sieve: Sieve = Sieve {
+            bits: vec![0; odd_count.div_ceil(64)],
+            limit,
+        };
+        // 1 isn't prime
+        sieve.set_bit(0);
+        let mut i: u64 = 3;
+        while i * i <= limit {
+            if !sieve.get_bit((i / 2) as usize) {
+                for j in (i * i..=limit).step_by(2 * i as usize) {
+                    sieve.set_bit((j / 2) as usize);
+                }
+            }
+            i += 2;
+        }
+        sieve
+    }
+
+    /// The largest number covered by the sieve.
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Looks up whether or not a number is prime.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number to check.
+    ///
+    /// # Returns
+    ///
+    /// Boolean representing whether or not `n` is prime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is larger than the sieve's limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bens_number_theory::primes::Sieve;
+    /// let sieve = Sieve::new(30);
+    /// assert_eq!(sieve.is_prime(2), true);
+    /// assert_eq!(sieve.is_prime(29), true);
+    /// assert_eq!(sieve.is_prime(27), false);
+    /// ```
+    pub fn is_prime(&self, n: u64) -> bool {
+        if n > self.limit {
+            panic!("{} is past the sieve limit of {}", n, self.limit);
+        }
+        match n {
+            2 => true,
+            _ if n.is_multiple_of(2) => false,
+            _ => !self.get_bit((n / 2) as usize),
+        }
+    }
+
+    /// Iterates over every prime up to the sieve's limit, in increasing order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bens_number_theory::primes::Sieve;
+    /// assert_eq!(Sieve::new(20).primes().count(), 8);
+    /// ```
+    pub fn primes(&self) -> impl Iterator<Item = u64> + '_ {
+        (2..=self.limit).filter(move |&n| self.is_prime(n))
+    }
+
+    /// Reads bit `i`, which is set when $2i + 1$ is not prime.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The index of the bit.
+    ///
+    /// # Returns
+    ///
+    /// Whether or not the bit is set.
+    fn get_bit(&self, i: usize) -> bool {
+        self.bits[i / 64] >> (i % 64) & 1 == 1
+    }
+
+    /// Sets bit `i`, marking $2i + 1$ as not prime.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The index of the bit.
+    fn set_bit(&mut self, i: usize) {
+        self.bits[i / 64] |= 1 << (i % 64);
+    }
+}
+
+/// Checks if a `u128` is prime using *Miller–Rabin* with `rounds` random bases.
+///
+/// This uses the thread-local random number generator; see `is_probable_prime_with` for details.
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+/// * `rounds` - The number of random bases to try when $n \ge 3.18 \times 10^{23}$.
+///
+/// # Returns
+///
+/// `false` if `n` is definitely composite, and `true` if it is prime (exactly for $n < 3.18 \times 10^{23}$,
+/// and otherwise with a false-positive probability of at most $4^{-rounds}$).
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::is_probable_prime;
+/// assert_eq!(is_probable_prime((1 << 127) - 1, 20), true); // a Mersenne prime
+/// assert_eq!(is_probable_prime(u128::MAX, 20), false);
+/// ```
+#[cfg(feature = "rand")]
+pub fn is_probable_prime(n: u128, rounds: u32) -> bool {
+    is_probable_prime_with(n, rounds, &mut rand::thread_rng())
+}
+
+/// Checks if a `u128` is prime using *Miller–Rabin* with `rounds` random bases.
+///
+/// The first twelve primes are always tried as bases first, which is deterministic for $n < 3.18 \times 10^{23}$,
+/// so `rounds` is ignored there. This is the same test as `is_prime_miller_rabin`, except that the extra bases
+/// come from `rng`. Each base a composite passes has a chance of at most $\frac{1}{4}$, so more rounds trade
+/// speed for accuracy. Products are done with `mod_mul`, so no step overflows even for `n` close to `u128::MAX`.
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+/// * `rounds` - The number of random bases to try when $n \ge 3.18 \times 10^{23}$.
+/// * `rng` - The random number generator to choose bases with. A seeded generator gives reproducible results.
+///
+/// # Returns
+///
+/// `false` if `n` is definitely composite, and `true` if it is prime (exactly for $n < 3.18 \times 10^{23}$,
+/// and otherwise with a false-positive probability of at most $4^{-rounds}$).
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::is_probable_prime_with;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// assert_eq!(is_probable_prime_with(u128::MAX - 158, 10, &mut rng), true); // 2^128 - 159
+/// assert_eq!(is_probable_prime_with(561, 10, &mut rng), false); // a Carmichael number
+/// ```
+#[cfg(feature = "rand")]
+pub fn is_probable_prime_with<R: rand::Rng>(n: u128, rounds: u32, rng: &mut R) -> bool {
+    miller_rabin_u128(n, rounds as usize, || rng.gen_range(2..n - 1))
+}
+
+/// The smallest strong pseudoprime to all of `MILLER_RABIN_BASES`, below which they give an exact answer.
+const MILLER_RABIN_DETERMINISTIC_LIMIT: u128 = 318665857834031151167461;
+
+/// Runs the *Miller–Rabin* test on `n`, first with `MILLER_RABIN_BASES` and then with `rounds` extra bases.
+///
+/// The extra bases are only needed, and `next_base` is only called, when $n \ge 3.18 \times 10^{23}$.
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+/// * `rounds` - The number of extra bases to try.
+/// * `next_base` - Produces the extra bases, each between 2 and $n - 2$.
+///
+/// # Returns
+///
+/// Boolean value indicating whether `n` is (probably) prime.
+fn miller_rabin_u128<F>(n: u128, rounds: usize, mut next_base: F) -> bool
+where
+    F: FnMut() -> u128,
+{
+    if n < 2 {
+        return false;
+    }
+    if let Some(&p) = MILLER_RABIN_BASES.iter().find(|&&p| n.is_multiple_of(p)) {
+        return n == p;
+    }
+    if !MILLER_RABIN_BASES
+        .iter()
+        .all(|&a| is_strong_probable_prime_u128(n, a))
+    {
+        return false;
+    }
+    if n < MILLER_RABIN_DETERMINISTIC_LIMIT {
+        return true;
+    }
+    (0..rounds).all(|_| is_strong_probable_prime_u128(n, next_base()))
+}
+
+/// Checks if odd `n` is a strong probable prime to base `a`, using `mod_mul` so nothing overflows.
+///
+/// # Arguments
+///
+/// * `n` - The odd number to check, greater than 2.
+/// * `a` - The base, between 2 and $n - 2$.
+///
+/// # Returns
+///
+/// Boolean value indicating whether `n` is a strong probable prime to base `a`.
+fn is_strong_probable_prime_u128(n: u128, a: u128) -> bool {
+    let s: u32 = (n - 1).trailing_zeros();
+    let d: u128 = (n - 1) >> s;
+
+    // x = a^d mod n
+    let mut x: u128 = 1;
+    let mut base: u128 = a;
+    let mut e: u128 = d;
+    while e > 0 {
+        if e & 1 == 1 {
+            x = mod_mul(x, base, n);
+        }
+        base = mod_mul(base, base, n);
+        e >>= 1;
+    }
+
+    if x == 1 || x == n - 1 {
+        return true;
+    }
+    for _ in 1..s {
+        x = mod_mul(x, x, n);
+        if x == n - 1 {
+            return true;
+        }
+    }
+    false
+}
+
+/// Counts the primes below a limit that satisfy a predicate.
+///
+/// The primes are found with a single `Sieve`, so any property (being a palindrome, having no even digits, etc.)
+/// can be counted without sieving again for each one.
+///
+/// # Arguments
+///
+/// * `limit` - The (exclusive) upper bound on the primes.
+/// * `pred` - The property each prime is checked for.
+///
+/// # Returns
+///
+/// The number of primes $p < limit$ for which `pred(p)` is `true`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::digits::is_palindrome;
+/// use bens_number_theory::primes::count_primes_below_where;
+///
+/// // 2, 3, 5, 7, 11, 101, 131, ..., 919, 929
+/// assert_eq!(count_primes_below_where(1000, is_palindrome), 20);
+/// assert_eq!(count_primes_below_where(100, |p| p % 10 == 3), 7); // 3, 13, 23, 43, 53, 73, 83
+/// ```
+pub fn count_primes_below_where<F: Fn(u64) -> bool>(limit: u64, pred: F) -> usize {
+    if limit < 2 {
+        return 0;
+    }
+    Sieve::new(limit - 1).primes().filter(|&p| pred(p)).count()
+}
+
+/// Finds the `n`th prime, counting from `nth_prime(0) = 2`, like `Iterator::nth`.
+///
+/// The primes are sieved up to the bound $p_k < k(\ln k + \ln \ln k)$ (for the $k$th prime with $k \ge 6$),
+/// which is always large enough to contain the answer.
+///
+/// [A000040](https://oeis.org/A000040)
+///
+/// # Arguments
+///
+/// * `n` - The (0-based) index of the prime to find.
+///
+/// # Returns
+///
+/// The `n`th prime, so the 1000th prime is `nth_prime(999)`.
+///
+/// # Panics
+///
+/// Panics if the prime doesn't fit in `T`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::nth_prime;
+/// assert_eq!(nth_prime::<u32>(0), 2);
+/// assert_eq!(nth_prime::<u32>(4), 11);
+/// assert_eq!(nth_prime::<u64>(999), 7919);
+/// ```
+pub fn nth_prime<T>(n: usize) -> T
+where
+    T: num::FromPrimitive,
+{
+    // the 1-based index, since that's what the bound is stated for
+    let k: f64 = (n + 1) as f64;
+    let limit: u64 = if k < 6.0 {
+        11
+    } else {
+        // a little extra so that rounding can't cut the bound short
+        (k * (k.ln() + k.ln().ln())) as u64 + 2
+    };
+    let p: u64 = Sieve::new(limit)
+        .primes()
+        .nth(n)
+        .expect("The sieve bound is always above the nth prime");
+    T::from_u64(p).expect("The nth prime doesn't fit in the requested type")
+}
+
+/// Calculates the *prime-counting function* $\pi(x)$, the number of primes less than or equal to `x`.
+///
+/// [A000720](https://oeis.org/A000720)
+///
+/// # Arguments
+///
+/// * `x` - The (inclusive) upper bound on the primes.
+///
+/// # Returns
+///
+/// The number of primes $p \le x$, which is 0 for anything below 2.
+///
+/// # Panics
+///
+/// Panics if `x` doesn't fit in a `u64`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::prime_pi;
+/// assert_eq!(prime_pi(10), 4); // 2, 3, 5, 7
+/// assert_eq!(prime_pi(11_u64), 5);
+/// assert_eq!(prime_pi(-3), 0);
+/// ```
+pub fn prime_pi<T>(x: T) -> usize
+where
+    T: num::traits::Zero + num::ToPrimitive + std::cmp::PartialOrd,
+{
+    if x < T::zero() {
+        return 0;
+    }
+    let limit: u64 = x.to_u64().expect("Can't sieve past u64::MAX");
+    Sieve::new(limit).primes().count()
+}
+
+/// Determines whether or not a number is *squarefree*, meaning no prime divides it more than once.
+///
+/// [A005117](https://oeis.org/A005117)
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+///
+/// # Returns
+///
+/// Boolean representing whether or not `n` is squarefree. 1 is squarefree, but 0 (which every square divides) is not.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::is_squarefree;
+/// assert_eq!(is_squarefree(30), true); // 2 * 3 * 5
+/// assert_eq!(is_squarefree(12), false); // 2^2 * 3
+/// ```
+pub fn is_squarefree<T>(n: T) -> bool
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + num::FromPrimitive
+        + std::ops::Div<Output = T>
+        + std::ops::Rem<Output = T>
+        + std::ops::AddAssign
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    n != T::zero() && prime_factorization(n).iter().all(|&(_, e)| e == 1)
+}
+
+/// Determines whether or not a number is a *Lucas–Carmichael number*.
+///
+/// These mirror the Carmichael numbers with $p + 1$ in place of $p - 1$: $n$ is a Lucas–Carmichael number
+/// when it is odd, composite, squarefree, and $p + 1$ divides $n + 1$ for every prime $p$ dividing $n$.
+/// The smallest is $399 = 3 \cdot 7 \cdot 19$, since 4, 8 and 20 all divide 400.
+///
+/// [A006972](https://oeis.org/A006972)
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+///
+/// # Returns
+///
+/// Boolean representing whether or not `n` is a Lucas–Carmichael number.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::is_lucas_carmichael;
+/// assert_eq!(is_lucas_carmichael(399), true);
+/// assert_eq!(is_lucas_carmichael(561), false); // a Carmichael number, but 4 doesn't divide 562
+/// ```
+pub fn is_lucas_carmichael(n: u64) -> bool {
+    if n < 3 || n.is_multiple_of(2) || !is_squarefree(n) {
+        return false;
+    }
+    let factors: Vec<(u64, u32)> = prime_factorization(n);
+    // squarefree, so a single factor means n is prime
+    factors.len() > 1 && factors.iter().all(|&(p, _)| (n + 1).is_multiple_of(p + 1))
+}
+
+/// Checks if a number is prime using the *Miller–Rabin* test.
+///
+/// Writing $n - 1 = 2^s d$ with $d$ odd, a prime $n$ must satisfy either $a^d \equiv 1$ or $a^{2^r d} \equiv -1 \pmod{n}$
+/// for some $0 \le r < s$, for every base $a$. A composite fails this for at least $\frac{3}{4}$ of the bases.
+///
+/// The first twelve primes are always used as bases, which is deterministic for $n < 3.18 \times 10^{23}$
+/// (so `rounds` is ignored there). Beyond that, `rounds` more pseudo-random bases are tried. Those bases
+/// are generated from `n` itself, so the answer for a given `n` is always the same, but this also means the
+/// $4^{-rounds}$ false-positive bound only holds for an `n` chosen without knowledge of the generator: a
+/// composite can be searched for that happens to pass all of its bases. Use `is_probable_prime_with`
+/// (behind the `rand` feature) when the input may be adversarial. Unlike `is_prime`, this never needs a
+/// list of primes, and all the arithmetic is done with `mod_mul` so it can't overflow.
+///
+/// # Arguments
+///
+/// * `n` - The number to check. Negative numbers are never prime.
+/// * `rounds` - The number of extra random bases to try when $n \ge 3.18 \times 10^{23}$.
+///
+/// # Returns
+///
+/// Boolean value indicating whether `n` is (probably) prime.
+///
+/// # Panics
+///
+/// Panics if `n` is too large to fit in a `u128`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::is_prime_miller_rabin;
+/// assert_eq!(is_prime_miller_rabin(2147483647, 10), true); // 2^31 - 1
+/// assert_eq!(is_prime_miller_rabin(2047, 10), false); // 23 * 89
+/// assert_eq!(is_prime_miller_rabin(-7, 10), false);
+/// ```
+pub fn is_prime_miller_rabin<T>(n: T, rounds: usize) -> bool
+where
+    T: num::traits::Zero + num::ToPrimitive + std::cmp::PartialOrd,
+{
+    if n < T::zero() {
+        return false;
+    }
+    let n: u128 = n.to_u128().expect("n must fit in a u128");
+    let mut state: u64 = n as u64 ^ (n >> 64) as u64;
+    miller_rabin_u128(n, rounds, || {
+        // a base between 2 and n - 2
+        2 + ((splitmix64(&mut state) as u128) << 64 | splitmix64(&mut state) as u128) % (n - 3)
+    })
+}
+
+/// Steps the *SplitMix64* pseudo-random number generator.
+///
+/// # Arguments
+///
+/// * `state` - The generator's state, which is advanced.
+///
+/// # Returns
+///
+/// The next pseudo-random `u64`.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z: u64 = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+/// The number of values sieved at a time by `for_each_prime_in_range`.
+const SEGMENT_SIZE: u64 = 1 << 15;
+
+/// Calls a function on every prime in a range, in increasing order, using a segmented Sieve of Eratosthenes.
+///
+/// The range is sieved in fixed size segments, so apart from the primes up to $\sqrt{high}$ (which are
+/// needed to sieve every segment) nothing is stored. This makes it the cheapest way to process the primes
+/// in a range whose start is too large to sieve from zero, like summing them.
+///
+/// # Arguments
+///
+/// * `low` - The (inclusive) lower bound of the range.
+/// * `high` - The (inclusive) upper bound of the range.
+/// * `f` - The function called on each prime.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::for_each_prime_in_range;
+///
+/// let mut sum: u64 = 0;
+/// for_each_prime_in_range(10, 30, |p| sum += p);
+/// assert_eq!(sum, 11 + 13 + 17 + 19 + 23 + 29);
+///
+/// let mut count: usize = 0;
+/// for_each_prime_in_range(1_000_000_000_000, 1_000_000_001_000, |_| count += 1);
+/// assert_eq!(count, 37);
+/// ```
+pub fn for_each_prime_in_range<F: FnMut(u64)>(low: u64, high: u64, mut f: F) {
+    segmented_sieve(low, high, &mut f);
+}
+
+/// Runs the segmented sieve behind `for_each_prime_in_range`.
+///
+/// This takes a trait object so that it can call itself to find the primes up to $\sqrt{high}$.
+///
+/// # Arguments
+///
+/// * `low` - The (inclusive) lower bound of the range.
+/// * `high` - The (inclusive) upper bound of the range.
+/// * `f` - The function called on each prime.
+fn segmented_sieve(low: u64, high: u64, f: &mut dyn FnMut(u64)) {
+    let low: u64 = low.max(2);
+    if low > high {
+        return;
+    }
+    // every composite in the range has a prime factor of at most sqrt(high), which always fits in a u32
+    let mut base_primes: Vec<u32> = Vec::new();
+    segmented_sieve(2, isqrt(high), &mut |p| base_primes.push(p as u32));
+
+    let mut segment_low: u64 = low;
+    loop {
+        let segment_high: u64 = segment_low.saturating_add(SEGMENT_SIZE - 1).min(high);
+        let mut composite: Vec<bool> = vec![false; (segment_high - segment_low + 1) as usize];
+        for &p in &base_primes {
+            let p: u64 = p as u64;
+            if p * p > segment_high {
+                break;
+            }
+            // the first multiple of p in the segment, skipping p itself
+            let mut multiple: u64 = match segment_low.div_ceil(p).checked_mul(p) {
+                Some(m) => m.max(p * p),
+                None => continue,
+            };
+            while multiple <= segment_high {
+                composite[(multiple - segment_low) as usize] = true;
+                multiple = match multiple.checked_add(p) {
+                    Some(m) => m,
+                    None => break,
+                };
+            }
+        }
+        for (i, is_composite) in composite.iter().enumerate() {
+            if !is_composite {
+                f(segment_low + i as u64);
+            }
+        }
+        if segment_high == high {
+            return;
+        }
+        segment_low = segment_high + 1;
+    }
+}
+
+/// Lists the primes in a range, using a segmented Sieve of Eratosthenes.
+///
+/// This collects the primes found by `for_each_prime_in_range`.
+///
+/// # Arguments
+///
+/// * `low` - The (inclusive) lower bound of the range.
+/// * `high` - The (inclusive) upper bound of the range.
+///
+/// # Returns
+///
+/// The primes $p$ with $low \le p \le high$, in increasing order.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::primes::primes_in_range;
+/// assert_eq!(primes_in_range(90, 110), vec![97, 101, 103, 107, 109]);
+/// assert_eq!(primes_in_range(0, 10), vec![2, 3, 5, 7]);
+/// assert_eq!(primes_in_range(24, 28), Vec::<u64>::new());
+/// ```
+pub fn primes_in_range(low: u64, high: u64) -> Vec<u64> {
+    let mut primes: Vec<u64> = Vec::new();
+    for_each_prime_in_range(low, high, |p| primes.push(p));
+    primes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        is_strong_lucas_probable_prime, jacobi_symbol, mod_mersenne, passes_first_prime_bases,
+        sieve_of_eratosthenes, Sieve,
+    };
+    use num::BigInt;
+
+    #[test]
+    fn sieve_bits_test() {
+        let mut sieve: Sieve = Sieve::new(1000);
+        for i in [1, 2, 3, 5, 6, 8] {
+            // 3, 5, 7, 11, 13, 17
+            assert!(!sieve.get_bit(i));
+        }
+        for i in [0, 4, 7, 10, 499] {
+            // 1, 9, 15, 21, 999
+            assert!(sieve.get_bit(i));
+        }
+        sieve.set_bit(1);
+        assert!(sieve.get_bit(1));
+        assert!(!sieve.is_prime(3));
+    }
+
+    #[test]
+    fn sieve_matches_bool_sieve_test() {
+        for limit in [0, 1, 2, 3, 63, 64, 127, 128, 129, 1000, 100_000] {
+            let sieve: Sieve = Sieve::new(limit);
+            let reference: Vec<bool> = sieve_of_eratosthenes(limit as usize);
+            for (n, &prime) in reference.iter().enumerate() {
+                assert_eq!(sieve.is_prime(n as u64), prime, "{}", n);
+            }
+        }
+    }
+
+    #[test]
+    fn sieve_memory_test() {
+        let limit: usize = 1_000_000;
+        let sieve: Sieve = Sieve::new(limit as u64);
+        let reference: Vec<bool> = sieve_of_eratosthenes(limit);
+        let packed_bytes: usize = sieve.bits.capacity() * std::mem::size_of::<u64>();
+        let bool_bytes: usize = reference.capacity() * std::mem::size_of::<bool>();
+        assert!(packed_bytes <= limit / 16 + 8);
+        assert!(packed_bytes * 15 < bool_bytes);
+    }
+
+    #[test]
+    fn sieve_of_eratosthenes_test() {
+        assert_eq!(sieve_of_eratosthenes(0), vec![false]);
+        assert_eq!(sieve_of_eratosthenes(1), vec![false, false]);
+        let primes: Vec<usize> = sieve_of_eratosthenes(30)
+            .iter()
+            .enumerate()
+            .filter(|(_, &p)| p)
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(primes, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    }
+
+    #[test]
+    fn passes_first_prime_bases_test() {
+        assert!(passes_first_prime_bases(&BigInt::from(2)));
+        assert!(passes_first_prime_bases(&BigInt::from(37)));
+        assert!(passes_first_prime_bases(&BigInt::from(
+            18446744073709551557_u64
+        )));
+        // strong pseudoprime to bases 2 through 11
+        assert!(!passes_first_prime_bases(&BigInt::from(2152302898747_u64)));
+    }
+
+    #[test]
+    fn mod_mersenne_test() {
+        let m: BigInt = BigInt::from(127);
+        for x in 0..20000 {
+            assert_eq!(mod_mersenne(BigInt::from(x), 7, &m), BigInt::from(x % 127));
+        }
+    }
+
+    #[test]
+    fn is_strong_lucas_probable_prime_test() {
+        for p in [3, 5, 7, 11, 13, 97, 7919] {
+            assert!(is_strong_lucas_probable_prime(&BigInt::from(p)));
+        }
+        for n in [9, 15, 25, 49, 91, 561, 2047] {
+            assert!(!is_strong_lucas_probable_prime(&BigInt::from(n)));
+        }
+        // the smallest strong Lucas pseudoprimes
+        for n in [5459, 5777, 10877, 16109, 18971] {
+            assert!(is_strong_lucas_probable_prime(&BigInt::from(n)));
+        }
+    }
+
+    #[test]
+    fn jacobi_symbol_test() {
+        assert_eq!(jacobi_symbol(&BigInt::from(1), &BigInt::from(1)), 1);
+        assert_eq!(jacobi_symbol(&BigInt::from(2), &BigInt::from(7)), 1);
+        assert_eq!(jacobi_symbol(&BigInt::from(3), &BigInt::from(7)), -1);
+        assert_eq!(jacobi_symbol(&BigInt::from(5), &BigInt::from(3)), -1);
+        assert_eq!(jacobi_symbol(&BigInt::from(-7), &BigInt::from(5)), -1);
+        assert_eq!(jacobi_symbol(&BigInt::from(1001), &BigInt::from(9907)), -1);
+        assert_eq!(jacobi_symbol(&BigInt::from(19), &BigInt::from(45)), 1);
+        assert_eq!(jacobi_symbol(&BigInt::from(6), &BigInt::from(15)), 0);
+    }
+}
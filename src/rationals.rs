@@ -0,0 +1,483 @@
+use crate::gcd::gcd;
+use crate::modular::multiplicative_order;
+use num::{BigInt, BigRational, One, Signed, Zero};
+
+/// Calculates the path from the root of the *Stern–Brocot tree* to a given rational.
+///
+/// The Stern–Brocot tree contains every positive rational exactly once, in lowest terms.
+/// Starting from the root $\frac{1}{1}$, each step either moves to the left child (`'L'`),
+/// which is smaller, or the right child (`'R'`), which is larger.
+/// The resulting string of `L`s and `R`s is a binary encoding of the rational.
+///
+/// The lengths of the runs in the path are the coefficients of the continued fraction of `r`
+/// (with the last coefficient decreased by one), so the path is built one run at a time
+/// using division rather than one step at a time.
+///
+/// # Arguments
+///
+/// * `r` - The positive rational to find the path to.
+///
+/// # Returns
+///
+/// A vector of `'L'` and `'R'` characters. The root $\frac{1}{1}$ has an empty path.
+///
+/// # Panics
+///
+/// Panics if `r` is not positive.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::rationals::stern_brocot_path;
+/// use num::{BigInt, BigRational};
+///
+/// let r = BigRational::new(BigInt::from(3), BigInt::from(2));
+/// assert_eq!(stern_brocot_path(&r), vec!['R', 'L']);
+/// ```
+pub fn stern_brocot_path(r: &BigRational) -> Vec<char> {
+    if !r.is_positive() {
+        panic!("Only positive rationals appear in the Stern-Brocot tree");
+    }
+    let mut a: BigInt = r.numer().abs();
+    let mut b: BigInt = r.denom().abs();
+    let mut path: Vec<char> = vec![];
+    while a != b {
+        if a > b {
+            // keep at least one copy of `b` in `a`, so we never step past the target
+            let q: BigInt = (&a - BigInt::one()) / &b;
+            push_run(&mut path, 'R', &q);
+            a -= q * &b;
+        } else {
+            let q: BigInt = (&b - BigInt::one()) / &a;
+            push_run(&mut path, 'L', &q);
+            b -= q * &a;
+        }
+    }
+    path
+}
+
+/// Calculates the rational found by following a path down the *Stern–Brocot tree*.
+///
+/// This is the inverse of `stern_brocot_path`.
+/// Each node is the mediant $\frac{a + c}{b + d}$ of its closest ancestors $\frac{a}{b}$ (to the left)
+/// and $\frac{c}{d}$ (to the right), starting with the bounds $\frac{0}{1}$ and $\frac{1}{0}$.
+///
+/// # Arguments
+///
+/// * `path` - A slice of `'L'` and `'R'` characters.
+///
+/// # Returns
+///
+/// The rational at the end of the path.
+///
+/// # Panics
+///
+/// Panics if the path contains anything other than `'L'` or `'R'`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::rationals::stern_brocot_from_path;
+/// use num::{BigInt, BigRational};
+///
+/// let r = stern_brocot_from_path(&['R', 'L']);
+/// assert_eq!(r, BigRational::new(BigInt::from(3), BigInt::from(2)));
+/// ```
+pub fn stern_brocot_from_path(path: &[char]) -> BigRational {
+    // left bound = a/b, right bound = c/d
+    let (mut a, mut b): (BigInt, BigInt) = (BigInt::zero(), BigInt::one());
+    let (mut c, mut d): (BigInt, BigInt) = (BigInt::one(), BigInt::zero());
+    for step in path {
+        let m_numer: BigInt = &a + &c;
+        let m_denom: BigInt = &b + &d;
+        match step {
+            'L' => (c, d) = (m_numer, m_denom),
+            'R' => (a, b) = (m_numer, m_denom),
+            _ => panic!("Stern-Brocot paths may only contain 'L' and 'R'"),
+        }
+    }
+    BigRational::new(a + c, b + d)
+}
+
+/// Pushes `step` onto `path` `count` times.
+///
+/// # Arguments
+///
+/// * `path` - The path to extend.
+/// * `step` - The step to repeat.
+/// * `count` - The number of times to repeat `step`.
+fn push_run(path: &mut Vec<char>, step: char, count: &BigInt) {
+    let mut i: BigInt = BigInt::zero();
+    while &i < count {
+        path.push(step);
+        i += 1;
+    }
+}
+
+/// Rounds a rational to the nearest integer.
+///
+/// Halfway cases are rounded away from zero, so $\frac{7}{2}$ rounds to $4$ and $-\frac{7}{2}$ rounds to $-4$.
+///
+/// # Arguments
+///
+/// * `r` - The rational to round.
+///
+/// # Returns
+///
+/// The integer closest to `r`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::rationals::round_rational;
+/// use num::{BigInt, BigRational};
+///
+/// let r = BigRational::new(BigInt::from(7), BigInt::from(2));
+/// assert_eq!(round_rational(&r), BigInt::from(4));
+/// assert_eq!(round_rational(&-r), BigInt::from(-4));
+/// ```
+pub fn round_rational(r: &BigRational) -> BigInt {
+    r.round().to_integer()
+}
+
+/// Calculates the floor of a rational, $\lfloor r \rfloor$.
+///
+/// # Arguments
+///
+/// * `r` - The rational to round down.
+///
+/// # Returns
+///
+/// The largest integer less than or equal to `r`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::rationals::floor_rational;
+/// use num::{BigInt, BigRational};
+///
+/// let r = BigRational::new(BigInt::from(-7), BigInt::from(2));
+/// assert_eq!(floor_rational(&r), BigInt::from(-4));
+/// ```
+pub fn floor_rational(r: &BigRational) -> BigInt {
+    r.floor().to_integer()
+}
+
+/// Calculates the ceiling of a rational, $\lceil r \rceil$.
+///
+/// # Arguments
+///
+/// * `r` - The rational to round up.
+///
+/// # Returns
+///
+/// The smallest integer greater than or equal to `r`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::rationals::ceil_rational;
+/// use num::{BigInt, BigRational};
+///
+/// let r = BigRational::new(BigInt::from(7), BigInt::from(2));
+/// assert_eq!(ceil_rational(&r), BigInt::from(4));
+/// ```
+pub fn ceil_rational(r: &BigRational) -> BigInt {
+    r.ceil().to_integer()
+}
+
+/// Reduces a fraction to lowest terms, with a positive denominator.
+///
+/// This is a lightweight, integer-only alternative to building a `BigRational`,
+/// dividing both parts by $\gcd(\text{numer}, \text{denom})$.
+///
+/// # Arguments
+///
+/// * `numer` - The numerator.
+/// * `denom` - The denominator.
+///
+/// # Returns
+///
+/// A tuple `(numer, denom)` in lowest terms with `denom > 0`. Zero is always `(0, 1)`.
+///
+/// # Panics
+///
+/// Panics if `denom` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::rationals::simplify_fraction;
+/// assert_eq!(simplify_fraction(4, -8), (-1, 2));
+/// assert_eq!(simplify_fraction(0, 5), (0, 1));
+/// ```
+pub fn simplify_fraction(numer: i128, denom: i128) -> (i128, i128) {
+    if denom == 0 {
+        panic!("The denominator of a fraction can't be 0");
+    }
+    let g: i128 = gcd(numer, denom);
+    let (numer, denom): (i128, i128) = (numer / g, denom / g);
+    if denom < 0 {
+        return (-numer, -denom);
+    }
+    (numer, denom)
+}
+
+/// Calculates the *convergents* of a continued fraction.
+///
+/// The convergents $\frac{h_n}{k_n}$ of $[a_0; a_1, a_2, \ldots]$ are the fractions found by cutting it off after each coefficient.
+/// They follow the recurrence
+/// $$h_n = a_n h_{n-1} + h_{n-2}, \quad k_n = a_n k_{n-1} + k_{n-2}$$
+/// starting from $h_{-1} = 1, h_{-2} = 0$ and $k_{-1} = 0, k_{-2} = 1$.
+///
+/// # Arguments
+///
+/// * `coeffs` - The coefficients $a_0, a_1, \ldots$ of the continued fraction.
+///
+/// # Returns
+///
+/// Vector of the convergents, one for each coefficient.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::rationals::convergents;
+/// use num::{BigInt, BigRational};
+///
+/// // sqrt(2) = [1; 2, 2, 2, ...]
+/// let coeffs: Vec<BigInt> = vec![1, 2, 2, 2].into_iter().map(BigInt::from).collect();
+/// let expected: Vec<BigRational> = vec![(1, 1), (3, 2), (7, 5), (17, 12)]
+///     .into_iter()
+///     .map(|(h, k)| BigRational::new(BigInt::from(h), BigInt::from(k)))
+///     .collect();
+/// assert_eq!(convergents(&coeffs), expected);
+/// ```
+pub fn convergents(coeffs: &[BigInt]) -> Vec<BigRational> {
+    let (mut h, mut h_previous): (BigInt, BigInt) = (BigInt::one(), BigInt::zero());
+    let (mut k, mut k_previous): (BigInt, BigInt) = (BigInt::zero(), BigInt::one());
+    let mut result: Vec<BigRational> = vec![];
+    for a in coeffs {
+        let h_next: BigInt = a * &h + &h_previous;
+        let k_next: BigInt = a * &k + &k_previous;
+        h_previous = std::mem::replace(&mut h, h_next);
+        k_previous = std::mem::replace(&mut k, k_next);
+        result.push(BigRational::new(h.clone(), k.clone()));
+    }
+    result
+}
+
+/// Splits the decimal expansion of a fraction into its non-repeating and repeating parts.
+///
+/// After reducing the fraction to $\frac{a}{d}$, write $d = 2^i 5^j d'$ with $d'$ coprime to 10.
+/// The non-repeating part is $\max(i, j)$ digits long, and the repeating block is as long as the
+/// multiplicative order of 10 modulo $d'$ (or empty when $d' = 1$).
+/// Knowing both lengths up front, the digits are found by long division without remembering any remainders,
+/// so the memory used is just the digits returned.
+/// The repeating block can still be almost as long as `denom` itself, so large prime denominators give huge outputs.
+///
+/// # Arguments
+///
+/// * `numer` - The numerator. Only the fractional part of `numer / denom` is expanded.
+/// * `denom` - The denominator.
+///
+/// # Returns
+///
+/// A tuple of the digits after the decimal point that come before the repeating block,
+/// and the digits of the repeating block (which is empty if the expansion terminates).
+///
+/// # Panics
+///
+/// Panics if `denom` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::rationals::decimal_period;
+/// assert_eq!(decimal_period(1, 7), (vec![], vec![1, 4, 2, 8, 5, 7]));
+/// assert_eq!(decimal_period(1, 6), (vec![1], vec![6])); // 0.1666...
+/// assert_eq!(decimal_period(5, 4), (vec![2, 5], vec![])); // 1.25
+/// ```
+pub fn decimal_period(numer: u64, denom: u64) -> (Vec<u8>, Vec<u8>) {
+    if denom == 0 {
+        panic!("Denominator cannot be zero");
+    }
+    let numer: u64 = numer % denom;
+    if numer == 0 {
+        return (vec![], vec![]);
+    }
+    let mut reduced: u64 = denom / gcd(numer, denom);
+    let (mut twos, mut fives): (usize, usize) = (0, 0);
+    while reduced.is_multiple_of(2) {
+        reduced /= 2;
+        twos += 1;
+    }
+    while reduced.is_multiple_of(5) {
+        reduced /= 5;
+        fives += 1;
+    }
+    let prefix_length: usize = twos.max(fives);
+    let period: usize = if reduced == 1 {
+        0
+    } else {
+        multiplicative_order(10, reduced).expect("10 is coprime to what's left of the denominator")
+            as usize
+    };
+
+    // u128 so that remainder * 10 can't overflow
+    let denom: u128 = denom as u128;
+    let mut remainder: u128 = numer as u128;
+    let mut digits: Vec<u8> = Vec::with_capacity(prefix_length + period);
+    for _ in 0..prefix_length + period {
+        remainder *= 10;
+        digits.push((remainder / denom) as u8);
+        remainder %= denom;
+    }
+    let repeating: Vec<u8> = digits.split_off(prefix_length);
+    (digits, repeating)
+}
+
+/// Finds the denominator $d < limit$ for which $\frac{1}{d}$ has the longest repeating decimal block.
+///
+/// This is [Project Euler #26](https://projecteuler.net/problem=26).
+///
+/// # Arguments
+///
+/// * `limit` - The (exclusive) upper bound on the denominator.
+///
+/// # Returns
+///
+/// `Some` denominator with the longest period (the smallest one if there's a tie), or `None` if `limit` is at most 1.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::rationals::longest_recurring_cycle_below;
+/// assert_eq!(longest_recurring_cycle_below(10), Some(7)); // 0.(142857)
+/// assert_eq!(longest_recurring_cycle_below(1000), Some(983));
+/// ```
+pub fn longest_recurring_cycle_below(limit: u64) -> Option<u64> {
+    let mut best: Option<(u64, usize)> = None;
+    for d in 1..limit {
+        let period: usize = decimal_period(1, d).1.len();
+        if best.is_none_or(|(_, longest)| period > longest) {
+            best = Some((d, period));
+        }
+    }
+    best.map(|(d, _)| d)
+}
+
+/// Evaluates a finite simple continued fraction.
+///
+/// $$[a_0; a_1, \ldots, a_n] = a_0 + \cfrac{1}{a_1 + \cfrac{1}{\ddots + \cfrac{1}{a_n}}}$$
+///
+/// This works from the innermost coefficient outwards, so only one rational is kept at a time.
+///
+/// # Arguments
+///
+/// * `coeffs` - The coefficients $a_0, a_1, \ldots, a_n$ of the continued fraction.
+///
+/// # Returns
+///
+/// The value of the continued fraction, in lowest terms.
+///
+/// # Panics
+///
+/// Panics if `coeffs` is empty, or if the continued fraction divides by zero (like $[1; 0]$).
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::rationals::from_continued_fraction;
+/// use num::{BigInt, BigRational};
+///
+/// // 355/113 = [3; 7, 16]
+/// let coeffs: Vec<BigInt> = vec![3, 7, 16].into_iter().map(BigInt::from).collect();
+/// assert_eq!(
+///     from_continued_fraction(&coeffs),
+///     BigRational::new(BigInt::from(355), BigInt::from(113))
+/// );
+/// ```
+pub fn from_continued_fraction(coeffs: &[BigInt]) -> BigRational {
+    let (last, rest): (&BigInt, &[BigInt]) = coeffs
+        .split_last()
+        .expect("A continued fraction needs at least one coefficient");
+    let mut value: BigRational = BigRational::from_integer(last.clone());
+    for a in rest.iter().rev() {
+        if value.is_zero() {
+            panic!("The continued fraction divides by zero");
+        }
+        value = value.recip() + a;
+    }
+    value
+}
+
+/// Writes a rational as a decimal string with a fixed number of decimal places.
+///
+/// The value is truncated towards zero, so every digit shown is a correct digit of the exact value.
+///
+/// # Arguments
+///
+/// * `r` - The rational to write.
+/// * `digits` - The number of digits after the decimal point.
+///
+/// # Returns
+///
+/// The decimal string, with no decimal point when `digits` is 0.
+/// A minus sign is only included when the digits shown aren't all zero.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::rationals::ratio_to_str_prec;
+/// use num::{BigInt, BigRational};
+///
+/// let r: BigRational = BigRational::new(BigInt::from(-22), BigInt::from(7));
+/// assert_eq!(ratio_to_str_prec(&r, 4), "-3.1428");
+/// assert_eq!(ratio_to_str_prec(&r, 0), "-3");
+/// ```
+pub fn ratio_to_str_prec(r: &BigRational, digits: usize) -> String {
+    let scale: BigInt = BigInt::from(10).pow(digits as u32);
+    // truncating division rounds towards zero
+    let scaled: BigInt = (r.numer() * &scale) / r.denom();
+    let magnitude: String = scaled.abs().to_string();
+    // pad so there is at least one digit before the decimal point
+    let padded: String = format!("{:0>width$}", magnitude, width = digits + 1);
+    let (integer, fraction): (&str, &str) = padded.split_at(padded.len() - digits);
+    let sign: &str = if scaled.is_negative() { "-" } else { "" };
+    if digits == 0 {
+        format!("{}{}", sign, integer)
+    } else {
+        format!("{}{}.{}", sign, integer, fraction)
+    }
+}
+
+/// Evaluates a finite continued fraction and writes it as a decimal string.
+///
+/// This combines `from_continued_fraction` and `ratio_to_str_prec`.
+///
+/// # Arguments
+///
+/// * `coeffs` - The coefficients $a_0, a_1, \ldots, a_n$ of the continued fraction.
+/// * `digits` - The number of digits after the decimal point, which are truncated rather than rounded.
+///
+/// # Returns
+///
+/// The value of the continued fraction as a decimal string.
+///
+/// # Panics
+///
+/// Panics if `coeffs` is empty, or if the continued fraction divides by zero.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::rationals::continued_fraction_decimal;
+/// use num::BigInt;
+///
+/// // the start of the continued fraction of pi
+/// let coeffs: Vec<BigInt> = vec![3, 7, 15, 1, 292].into_iter().map(BigInt::from).collect();
+/// assert_eq!(continued_fraction_decimal(&coeffs, 9), "3.141592653");
+/// ```
+pub fn continued_fraction_decimal(coeffs: &[BigInt], digits: usize) -> String {
+    ratio_to_str_prec(&from_continued_fraction(coeffs), digits)
+}
@@ -0,0 +1,98 @@
+/// Calculates the integer square root of a number.
+///
+/// This uses Newton's method on integers, so no floating point is involved and the answer is exact
+/// for any size of integer, including `BigInt`.
+///
+/// # Arguments
+///
+/// * `n` - The number to find the square root of.
+///
+/// # Returns
+///
+/// The largest `r` such that $r^2 \le n$.
+///
+/// # Panics
+///
+/// Panics if `n` is negative.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::roots::isqrt;
+/// use num::BigInt;
+/// assert_eq!(isqrt(0), 0);
+/// assert_eq!(isqrt(99), 9);
+/// assert_eq!(isqrt(100), 10);
+/// assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+/// assert_eq!(isqrt(BigInt::from(10).pow(40)), BigInt::from(10).pow(20));
+/// ```
+pub fn isqrt<T>(n: T) -> T
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + std::ops::Add<Output = T>
+        + std::ops::Div<Output = T>
+        + std::cmp::Ord
+        + Clone,
+{
+    if n < T::zero() {
+        panic!("Negative numbers don't have a real square root");
+    }
+    let two: T = T::one() + T::one();
+    if n < two {
+        return n;
+    }
+    // n / 2 + 1 is at least the root and keeps x + n / x from overflowing
+    let mut x: T = n.clone() / two.clone() + T::one();
+    let mut y: T = (x.clone() + n.clone() / x.clone()) / two.clone();
+    while y < x {
+        x = y;
+        y = (x.clone() + n.clone() / x.clone()) / two.clone();
+    }
+    x
+}
+
+/// Calculates the integer `k`th root of a number.
+///
+/// This binary searches for the root, using checked powers so that candidates too large for a `u64` are rejected
+/// instead of overflowing. No floating point is involved, so the answer is exact for every `u64`.
+///
+/// # Arguments
+///
+/// * `n` - The number to find the root of.
+/// * `k` - Which root to find.
+///
+/// # Returns
+///
+/// The largest `r` such that $r^k \le n$.
+///
+/// # Panics
+///
+/// Panics if `k` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::roots::integer_nth_root;
+/// assert_eq!(integer_nth_root(1729, 3), 12); // 12^3 = 1728
+/// assert_eq!(integer_nth_root(1024, 10), 2);
+/// assert_eq!(integer_nth_root(u64::MAX, 2), u32::MAX as u64);
+/// ```
+pub fn integer_nth_root(n: u64, k: u32) -> u64 {
+    if k == 0 {
+        panic!("The 0th root is undefined");
+    }
+    if k == 1 || n < 2 {
+        return n;
+    }
+    // 2^(64 / k + 1) is always too big to be the root
+    let (mut low, mut high): (u64, u64) = (1, 1 << (64 / k + 1).min(63));
+    while high - low > 1 {
+        let middle: u64 = low + (high - low) / 2;
+        match middle.checked_pow(k) {
+            Some(power) if power <= n => low = middle,
+            _ => high = middle,
+        }
+    }
+    low
+}
@@ -1,4 +1,7 @@
-use num::BigInt;
+use crate::factorials::factorial;
+use crate::gcd::gcd;
+use crate::modular::binomial_mod_prime;
+use num::{BigInt, BigRational, One, Zero};
 
 /// Calculates a vector of numbers representing the Lucas Sequence.
 ///
@@ -365,3 +368,596 @@ pub fn ben_sequence(n: BigInt) -> Vec<BigInt> {
     }
     nums
 }
+
+/// Calculates the `n`th Catalan number.
+///
+/// $$C_n = \frac{1}{n + 1}\binom{2n}{n}$$
+///
+/// It is built up one term at a time using $C_{k+1} = \frac{2(2k + 1)}{k + 2}C_k$, where every division is exact.
+///
+/// [A000108](https://oeis.org/A000108)
+///
+/// # Arguments
+///
+/// * `n` - The index of the Catalan number, starting from $C_0 = 1$.
+///
+/// # Returns
+///
+/// $C_n$ as a `BigInt`.
+///
+/// # Example
+///
+/// ```
+/// use num::BigInt;
+/// use bens_number_theory::sequences::catalan_nth;
+///
+/// assert_eq!(catalan_nth(0), BigInt::from(1));
+/// assert_eq!(catalan_nth(5), BigInt::from(42));
+/// ```
+pub fn catalan_nth(n: u64) -> BigInt {
+    let mut c: BigInt = BigInt::from(1);
+    for k in 0..n {
+        c = c * (2 * (2 * k + 1)) / (k + 2);
+    }
+    c
+}
+
+/// Calculates the `n`th Catalan number modulo a prime `p`.
+///
+/// Dividing $\binom{2n}{n}$ by $n + 1$ needs a modular inverse, which doesn't exist when $p$ divides $n + 1$,
+/// so this uses the equivalent form
+/// $$C_n = \binom{2n}{n} - \binom{2n}{n + 1}$$
+/// with both binomials found modulo `p` by `binomial_mod_prime`.
+/// This never forms $C_n$ itself, so for small primes it works for indices far too large for `catalan_nth`.
+/// Each base-`p` digit of $2n$ costs up to $O(p)$ work though, so a large `p` with a large `n` is slow.
+///
+/// # Arguments
+///
+/// * `n` - The index of the Catalan number, at most `u64::MAX / 2` so that $2n$ fits in a `u64`.
+/// * `p` - The prime modulus.
+///
+/// # Returns
+///
+/// $C_n \bmod p$.
+///
+/// # Panics
+///
+/// Panics if `p` is less than 2, or if `n` is larger than `u64::MAX / 2`.
+///
+/// # Example
+///
+/// ```
+/// use bens_number_theory::sequences::catalan_mod_prime;
+///
+/// assert_eq!(catalan_mod_prime(5, 7), 0); // 42
+/// assert_eq!(catalan_mod_prime(5, 1000000007), 42);
+/// ```
+pub fn catalan_mod_prime(n: u64, p: u64) -> u64 {
+    if p < 2 {
+        panic!("The modulus must be a prime");
+    }
+    let two_n: u64 = n
+        .checked_mul(2)
+        .expect("2n has to fit in a u64, so n can be at most u64::MAX / 2");
+    let a: u64 = binomial_mod_prime(two_n, n, p);
+    let b: u64 = binomial_mod_prime(two_n, n + 1, p);
+    // a + p - b in u128, since p can be close to u64::MAX
+    ((a as u128 + p as u128 - b as u128) % p as u128) as u64
+}
+
+/// Calculates the `n`th Bernoulli number.
+///
+/// The Bernoulli numbers are defined by the recurrence
+/// $$B_0 = 1, \quad B_m = -\frac{1}{m + 1} \sum_{k=0}^{m-1} \binom{m + 1}{k} B_k$$
+/// which uses the convention $B_1 = -\frac{1}{2}$. Every odd Bernoulli number after $B_1$ is $0$.
+///
+/// [A027641](https://oeis.org/A027641) / [A027642](https://oeis.org/A027642)
+///
+/// # Arguments
+///
+/// * `n` - The index of the Bernoulli number.
+///
+/// # Returns
+///
+/// $B_n$ as a `BigRational`.
+///
+/// # Example
+///
+/// ```
+/// use num::{BigInt, BigRational};
+/// use bens_number_theory::sequences::bernoulli;
+///
+/// assert_eq!(bernoulli(1), BigRational::new(BigInt::from(-1), BigInt::from(2)));
+/// assert_eq!(bernoulli(12), BigRational::new(BigInt::from(-691), BigInt::from(2730)));
+/// ```
+pub fn bernoulli(n: u32) -> BigRational {
+    bernoulli_list(n).pop().unwrap()
+}
+
+/// Calculates $\sum_{k=1}^{n} k^p$ using *Faulhaber's formula*.
+///
+/// $$\sum_{k=1}^{n} k^p = \frac{1}{p + 1} \sum_{j=0}^{p} \binom{p + 1}{j} B^{+}_j n^{p + 1 - j}$$
+///
+/// where $B^{+}_j$ are the Bernoulli numbers with $B^{+}_1 = +\frac{1}{2}$.
+/// This takes $p + 1$ terms no matter how large $n$ is.
+///
+/// # Arguments
+///
+/// * `n` - The last number in the sum.
+/// * `p` - The power to raise each number to.
+///
+/// # Returns
+///
+/// $1^p + 2^p + \cdots + n^p$ as a `BigInt`.
+///
+/// # Example
+///
+/// ```
+/// use num::BigInt;
+/// use bens_number_theory::sequences::sum_of_powers;
+///
+/// assert_eq!(sum_of_powers(10, 1), BigInt::from(55));
+/// assert_eq!(sum_of_powers(5, 2), BigInt::from(55));
+/// ```
+pub fn sum_of_powers(n: u64, p: u32) -> BigInt {
+    let b: Vec<BigRational> = bernoulli_list(p);
+    let n: BigInt = BigInt::from(n);
+    let mut binomial: BigInt = BigInt::one();
+    let mut sum: BigRational = BigRational::zero();
+    for (j, b_j) in b.iter().enumerate() {
+        let b_j: BigRational = if j == 1 { -b_j } else { b_j.clone() };
+        sum += b_j * BigRational::from(&binomial * n.pow(p + 1 - j as u32));
+        // C(p + 1, j + 1) from C(p + 1, j)
+        binomial = binomial * (p as usize + 1 - j) / (j + 1);
+    }
+    (sum / BigRational::from(BigInt::from(p + 1))).to_integer()
+}
+
+/// Calculates the Bernoulli numbers $B_0$ through $B_n$ (with $B_1 = -\frac{1}{2}$).
+///
+/// # Arguments
+///
+/// * `n` - The index of the last Bernoulli number.
+///
+/// # Returns
+///
+/// A vector of the `n + 1` Bernoulli numbers.
+fn bernoulli_list(n: u32) -> Vec<BigRational> {
+    let mut b: Vec<BigRational> = vec![BigRational::one()];
+    for m in 1..=n as usize {
+        let mut binomial: BigInt = BigInt::one();
+        let mut sum: BigRational = BigRational::zero();
+        for (k, b_k) in b.iter().enumerate() {
+            sum += b_k * BigRational::from(binomial.clone());
+            // C(m + 1, k + 1) from C(m + 1, k)
+            binomial = binomial * (m + 1 - k) / (k + 1);
+        }
+        b.push(-sum / BigRational::from(BigInt::from(m + 1)));
+    }
+    b
+}
+
+/// Calculates the `n`th Fibonacci number using *fast doubling*.
+///
+/// Rather than building the whole sequence, this uses the identities
+/// $$F_{2k} = F_k (2F_{k+1} - F_k), \quad F_{2k+1} = F_{k+1}^2 + F_k^2$$
+/// to go from $(F_k, F_{k+1})$ to $(F_{2k}, F_{2k+1})$ (or $(F_{2k+1}, F_{2k+2})$) for each bit of `n`,
+/// which only takes $O(\log n)$ multiplications.
+///
+/// # Arguments
+///
+/// * `n` - The index of the Fibonacci number, starting from $F_0 = 0$.
+///
+/// # Returns
+///
+/// $F_n$ as a `BigInt`.
+///
+/// # Panics
+///
+/// Panics if `n` is negative.
+///
+/// # Example
+///
+/// ```
+/// use num::BigInt;
+/// use bens_number_theory::sequences::fibonacci_nth;
+///
+/// assert_eq!(fibonacci_nth(BigInt::from(10)), BigInt::from(55));
+/// ```
+pub fn fibonacci_nth(n: BigInt) -> BigInt {
+    if n < BigInt::zero() {
+        panic!("Fibonacci numbers are only defined here for non-negative indices");
+    }
+    // (F_k, F_{k+1}), starting from k = 0
+    let mut a: BigInt = BigInt::zero();
+    let mut b: BigInt = BigInt::one();
+    for i in (0..n.bits()).rev() {
+        let c: BigInt = &a * (&b * 2 - &a);
+        let d: BigInt = &a * &a + &b * &b;
+        if n.bit(i) {
+            b = &c + &d;
+            a = d;
+        } else {
+            a = c;
+            b = d;
+        }
+    }
+    a
+}
+
+/// Calculates $\gcd(F_m, F_n)$ using the identity
+/// $$\gcd(F_m, F_n) = F_{\gcd(m, n)}$$
+///
+/// This only needs one (much smaller) Fibonacci number, found with `fibonacci_nth`,
+/// instead of $F_m$ and $F_n$.
+///
+/// # Arguments
+///
+/// * `m` - The index of the first Fibonacci number.
+/// * `n` - The index of the second Fibonacci number.
+///
+/// # Returns
+///
+/// $\gcd(F_m, F_n)$ as a `BigInt`.
+///
+/// # Example
+///
+/// ```
+/// use num::BigInt;
+/// use bens_number_theory::sequences::fibonacci_gcd;
+///
+/// assert_eq!(fibonacci_gcd(12, 18), BigInt::from(8)); // gcd(144, 2584) = F_6
+/// ```
+pub fn fibonacci_gcd(m: u64, n: u64) -> BigInt {
+    fibonacci_nth(BigInt::from(gcd(m, n)))
+}
+
+/// A memoized Fibonacci calculator that keeps every value it has computed.
+///
+/// Unlike `fibonacci_nth`, which starts over on every call, `FibCache` stores $F_0, F_1, \dots$ in a `Vec`
+/// and only extends it when asked for a larger index, so repeated queries for already seen indices cost $O(1)$.
+///
+/// # Example
+///
+/// ```
+/// use num::BigInt;
+/// use bens_number_theory::sequences::FibCache;
+///
+/// let mut cache = FibCache::new();
+/// assert_eq!(cache.get(10), BigInt::from(55));
+/// assert_eq!(cache.get(5), BigInt::from(5)); // already cached
+/// assert_eq!(cache.len(), 11);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FibCache {
+    /// `values[i]` is $F_i$
+    values: Vec<BigInt>,
+}
+
+impl FibCache {
+    /// Creates a cache holding just $F_0 = 0$ and $F_1 = 1$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bens_number_theory::sequences::FibCache;
+    /// assert_eq!(FibCache::new().len(), 2);
+    /// ```
+    pub fn new() -> Self {
+        FibCache {
+            values: vec![BigInt::zero(), BigInt::one()],
+        }
+    }
+
+    /// Gets the `n`th Fibonacci number, computing and storing every value up to it if it isn't cached yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The index of the Fibonacci number, starting from $F_0 = 0$.
+    ///
+    /// # Returns
+    ///
+    /// $F_n$ as a `BigInt`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num::BigInt;
+    /// use bens_number_theory::sequences::FibCache;
+    ///
+    /// let mut cache = FibCache::new();
+    /// assert_eq!(cache.get(12), BigInt::from(144));
+    /// ```
+    pub fn get(&mut self, n: u64) -> BigInt {
+        let n: usize = n as usize;
+        while self.values.len() <= n {
+            let len: usize = self.values.len();
+            let next: BigInt = &self.values[len - 1] + &self.values[len - 2];
+            self.values.push(next);
+        }
+        self.values[n].clone()
+    }
+
+    /// The number of Fibonacci numbers currently stored, so $F_0$ through $F_{len - 1}$ are cached.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bens_number_theory::sequences::FibCache;
+    ///
+    /// let mut cache = FibCache::new();
+    /// cache.get(20);
+    /// assert_eq!(cache.len(), 21);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether the cache is empty. It always holds at least $F_0$ and $F_1$, so this is always `false`.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl Default for FibCache {
+    fn default() -> Self {
+        FibCache::new()
+    }
+}
+
+/// Calculates the *finite differences* of a sequence, $\Delta a_i = a_{i+1} - a_i$.
+///
+/// # Arguments
+///
+/// * `seq` - The sequence to take the differences of.
+///
+/// # Returns
+///
+/// Vector of the differences between consecutive terms, which is one shorter than `seq` (or empty if `seq` is).
+///
+/// # Example
+///
+/// ```
+/// use num::BigInt;
+/// use bens_number_theory::sequences::finite_differences;
+///
+/// let squares: Vec<BigInt> = [1, 4, 9, 16].iter().map(|&x| BigInt::from(x)).collect();
+/// let expected: Vec<BigInt> = [3, 5, 7].iter().map(|&x| BigInt::from(x)).collect();
+/// assert_eq!(finite_differences(&squares), expected);
+/// ```
+pub fn finite_differences(seq: &[BigInt]) -> Vec<BigInt> {
+    seq.windows(2).map(|pair| &pair[1] - &pair[0]).collect()
+}
+
+/// Calculates the `k`th finite differences of a sequence by applying `finite_differences` `k` times.
+///
+/// If the sequence comes from a polynomial of degree $d$, the $d$th differences are constant
+/// (and all later ones are 0), which makes this a quick way to spot polynomial sequences.
+///
+/// # Arguments
+///
+/// * `seq` - The sequence to take the differences of.
+/// * `k` - How many times to take differences. `k = 0` gives the sequence back.
+///
+/// # Returns
+///
+/// Vector of the `k`th differences, which is `k` shorter than `seq` (or empty if `k` is at least its length).
+///
+/// # Example
+///
+/// ```
+/// use num::BigInt;
+/// use bens_number_theory::sequences::nth_finite_difference;
+///
+/// let cubes: Vec<BigInt> = (1..=6).map(|x| BigInt::from(x * x * x)).collect();
+/// assert_eq!(nth_finite_difference(&cubes, 3), vec![BigInt::from(6); 3]);
+/// ```
+pub fn nth_finite_difference(seq: &[BigInt], k: usize) -> Vec<BigInt> {
+    let mut differences: Vec<BigInt> = seq.to_vec();
+    for _ in 0..k {
+        if differences.is_empty() {
+            break;
+        }
+        differences = finite_differences(&differences);
+    }
+    differences
+}
+
+/// Extends a sequence by assuming it comes from a polynomial, using its *forward-difference table*.
+///
+/// Differences are taken until a row is constant. Each new term is then found by adding back up the table:
+/// the constant row stays the same, and every row above it grows by the last value of the row below.
+/// A sequence of $m$ terms is treated as a polynomial of degree at most $m - 1$.
+///
+/// # Arguments
+///
+/// * `seq` - The known terms of the sequence.
+/// * `terms` - How many more terms to find.
+///
+/// # Returns
+///
+/// Vector of the next `terms` values of the sequence (not including `seq` itself).
+///
+/// # Panics
+///
+/// Panics if `seq` is empty.
+///
+/// # Example
+///
+/// ```
+/// use num::BigInt;
+/// use bens_number_theory::sequences::extrapolate_sequence;
+///
+/// let cubes: Vec<BigInt> = [1, 8, 27, 64].iter().map(|&x| BigInt::from(x)).collect();
+/// let next: Vec<BigInt> = [125, 216].iter().map(|&x| BigInt::from(x)).collect();
+/// assert_eq!(extrapolate_sequence(&cubes, 2), next);
+/// ```
+pub fn extrapolate_sequence(seq: &[BigInt], terms: usize) -> Vec<BigInt> {
+    if seq.is_empty() {
+        panic!("Can't extrapolate an empty sequence");
+    }
+    // the last value of each row of the difference table
+    let mut lasts: Vec<BigInt> = vec![];
+    let mut row: Vec<BigInt> = seq.to_vec();
+    loop {
+        lasts.push(row[row.len() - 1].clone());
+        if row.iter().all(|x| *x == row[0]) {
+            break;
+        }
+        row = finite_differences(&row);
+    }
+    let mut next: Vec<BigInt> = Vec::with_capacity(terms);
+    for _ in 0..terms {
+        for i in (0..lasts.len() - 1).rev() {
+            let below: BigInt = lasts[i + 1].clone();
+            lasts[i] += below;
+        }
+        next.push(lasts[0].clone());
+    }
+    next
+}
+
+/// Generates a list of the *left factorials* (the Kurepa sequence).
+///
+/// $$!n = \sum_{k=0}^{n-1} k!$$
+///
+/// Despite sharing the $!n$ notation, these are not the *subfactorials* (the number of derangements of $n$ items,
+/// which is also written $!n$). For example the left factorial $!4 = 0! + 1! + 2! + 3! = 10$,
+/// while there are only 9 derangements of 4 items.
+///
+/// [A003422](https://oeis.org/A003422)
+///
+/// # Arguments
+///
+/// `n` - The size of the list to return
+///
+/// # Example
+///
+/// ```
+/// use bens_number_theory::sequences::left_factorial_sequence;
+/// use num::BigInt;
+///
+/// let left = left_factorial_sequence(BigInt::from(7));
+/// assert_eq!(left, [0, 1, 2, 4, 10, 34, 154].map(BigInt::from));
+/// ```
+pub fn left_factorial_sequence(n: BigInt) -> Vec<BigInt> {
+    let mut nums: Vec<BigInt> = vec![];
+    let mut sum: BigInt = BigInt::zero();
+    let mut k: BigInt = BigInt::zero();
+    while k < n {
+        nums.push(sum.clone());
+        sum += factorial(k.clone());
+        k += 1;
+    }
+    nums
+}
+
+/// Generates the `n`th *Fibonacci word*.
+///
+/// Like the Fibonacci numbers, each word is built from the two before it, but by concatenation instead of addition:
+/// $$S_0 = 0, \quad S_1 = 01, \quad S_n = S_{n-1} S_{n-2}$$
+/// giving `"0"`, `"01"`, `"010"`, `"01001"`, ... The length of $S_n$ is the Fibonacci number $F_{n+2}$.
+///
+/// [A003849](https://oeis.org/A003849)
+///
+/// # Arguments
+///
+/// * `n` - The index of the word, starting from 0.
+///
+/// # Example
+///
+/// ```
+/// use bens_number_theory::sequences::fibonacci_word;
+///
+/// assert_eq!(fibonacci_word(4), "01001010");
+/// ```
+pub fn fibonacci_word(n: u32) -> String {
+    let mut previous: String = String::from("0");
+    let mut current: String = String::from("01");
+    if n == 0 {
+        return previous;
+    }
+    for _ in 1..n {
+        let next: String = current.clone() + &previous;
+        previous = std::mem::replace(&mut current, next);
+    }
+    current
+}
+
+/// Generates the *look-and-say sequence* starting from a seed.
+///
+/// Each term describes the one before it, by reading off each run of equal digits as a count followed by the digit:
+/// `"1"` is "one 1" (`"11"`), which is "two 1s" (`"21"`), then `"1211"`, `"111221"`, ...
+/// Conway showed that, for any seed other than `"22"`, the length grows by a factor approaching
+/// *Conway's constant* $\lambda \approx 1.303577$ with each step.
+///
+/// [A005150](https://oeis.org/A005150)
+///
+/// # Arguments
+///
+/// * `seed` - The first term, usually `"1"`.
+/// * `iterations` - The size of the list to return.
+///
+/// # Panics
+///
+/// Panics if `seed` is empty or contains anything other than the digits 0 to 9.
+///
+/// # Example
+///
+/// ```
+/// use bens_number_theory::sequences::look_and_say;
+///
+/// assert_eq!(look_and_say("1", 5), ["1", "11", "21", "1211", "111221"]);
+/// ```
+pub fn look_and_say(seed: &str, iterations: usize) -> Vec<String> {
+    if seed.is_empty() || !seed.bytes().all(|b| b.is_ascii_digit()) {
+        panic!("Seed must be a non-empty string of digits, got {:?}", seed);
+    }
+    let mut terms: Vec<String> = vec![];
+    let mut current: String = seed.to_string();
+    for _ in 0..iterations {
+        let bytes: &[u8] = current.as_bytes();
+        let mut next: String = String::with_capacity(2 * bytes.len());
+        for run in bytes.chunk_by(|a, b| a == b) {
+            next.push_str(&run.len().to_string());
+            next.push(run[0] as char);
+        }
+        terms.push(std::mem::replace(&mut current, next));
+    }
+    terms
+}
+
+/// Generates a list of *Stern's diatomic sequence* (also called fusc).
+///
+/// $$a(0) = 0, \quad a(1) = 1, \quad a(2n) = a(n), \quad a(2n + 1) = a(n) + a(n + 1)$$
+///
+/// Consecutive terms are always coprime, and the fractions $\frac{a(n)}{a(n+1)}$ for $n \ge 1$
+/// list every positive rational exactly once, in the order of a breadth-first walk of the Calkin–Wilf tree
+/// (the same rationals as the Stern–Brocot tree, in a different arrangement).
+///
+/// [A002487](https://oeis.org/A002487)
+///
+/// # Arguments
+///
+/// `n` - The size of the list to return
+///
+/// # Example
+///
+/// ```
+/// use bens_number_theory::sequences::stern_diatomic;
+///
+/// assert_eq!(stern_diatomic(10), [0, 1, 1, 2, 1, 3, 2, 3, 1, 4]);
+/// ```
+pub fn stern_diatomic(n: u64) -> Vec<u64> {
+    let n: usize = n as usize;
+    let mut a: Vec<u64> = Vec::with_capacity(n);
+    for i in 0..n {
+        let value: u64 = match i {
+            0 | 1 => i as u64,
+            _ if i.is_multiple_of(2) => a[i / 2],
+            _ => a[i / 2] + a[i / 2 + 1],
+        };
+        a.push(value);
+    }
+    a
+}
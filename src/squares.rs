@@ -0,0 +1,222 @@
+use crate::roots::{integer_nth_root, isqrt};
+use std::collections::HashMap;
+
+/// Determines whether or not a given number is a perfect square.
+///
+/// # Arguments
+///
+/// * `n` - The number to check.
+///
+/// # Returns
+///
+/// Boolean representing whether or not $n = k^2$ for some integer $k$.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::squares::is_perfect_square;
+/// assert_eq!(is_perfect_square(0), true);
+/// assert_eq!(is_perfect_square(49), true);
+/// assert_eq!(is_perfect_square(50), false);
+/// ```
+pub fn is_perfect_square(n: u64) -> bool {
+    let root: u64 = isqrt(n);
+    root * root == n
+}
+
+/// Writes a number as the sum of two squares, if possible.
+///
+/// $$n = a^2 + b^2$$
+///
+/// By Fermat's theorem on sums of two squares, this is possible exactly when every prime
+/// of the form $4k + 3$ appears in the factorization of `n` an even number of times.
+///
+/// # Arguments
+///
+/// * `n` - The number to decompose.
+///
+/// # Returns
+///
+/// `Some((a, b))` with $a \le b$ and the smallest possible $a$, or `None` if no such pair exists.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::squares::sum_of_two_squares;
+/// assert_eq!(sum_of_two_squares(25), Some((0, 5)));
+/// assert_eq!(sum_of_two_squares(65), Some((1, 8)));
+/// assert_eq!(sum_of_two_squares(21), None);
+/// ```
+pub fn sum_of_two_squares(n: u64) -> Option<(u64, u64)> {
+    // a <= b means a^2 <= n / 2
+    for a in 0..=isqrt(n / 2) {
+        let remainder: u64 = n - a * a;
+        if is_perfect_square(remainder) {
+            return Some((a, isqrt(remainder)));
+        }
+    }
+    None
+}
+
+/// Writes a number as the sum of three squares, if possible.
+///
+/// $$n = a^2 + b^2 + c^2$$
+///
+/// By *Legendre's three-square theorem*, this is possible exactly when `n` is **not** of the form $4^a(8b + 7)$.
+///
+/// # Arguments
+///
+/// * `n` - The number to decompose.
+///
+/// # Returns
+///
+/// `Some((a, b, c))` with $a \le b \le c$, or `None` when `n` has the form $4^a(8b + 7)$.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::squares::three_squares;
+/// assert_eq!(three_squares(6), Some((1, 1, 2)));
+/// assert_eq!(three_squares(7), None);
+/// assert_eq!(three_squares(28), None); // 4 * 7
+/// ```
+pub fn three_squares(n: u64) -> Option<(u64, u64, u64)> {
+    let mut m: u64 = n;
+    while m != 0 && m.is_multiple_of(4) {
+        m /= 4;
+    }
+    if m % 8 == 7 {
+        return None;
+    }
+    // trying the largest square first means `c` can never be smaller than `b`
+    let mut c: u64 = isqrt(n);
+    loop {
+        if let Some((a, b)) = sum_of_two_squares(n - c * c) {
+            return Some((a, b, c));
+        }
+        if c == 0 {
+            return None;
+        }
+        c -= 1;
+    }
+}
+
+/// Writes a number as the sum of four squares.
+///
+/// $$n = a^2 + b^2 + c^2 + d^2$$
+///
+/// By *Lagrange's four-square theorem*, this is possible for every natural number.
+///
+/// # Arguments
+///
+/// * `n` - The number to decompose.
+///
+/// # Returns
+///
+/// `(a, b, c, d)` with $a \le b \le c \le d$.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::squares::four_squares;
+/// assert_eq!(four_squares(7), (1, 1, 1, 2));
+/// assert_eq!(four_squares(16), (0, 0, 0, 4));
+/// ```
+pub fn four_squares(n: u64) -> (u64, u64, u64, u64) {
+    let mut d: u64 = isqrt(n);
+    loop {
+        if let Some((a, b, c)) = three_squares(n - d * d) {
+            return (a, b, c, d);
+        }
+        if d == 0 {
+            unreachable!("Lagrange's four-square theorem guarantees a decomposition");
+        }
+        d -= 1;
+    }
+}
+
+/// Finds every way to write a number as a sum of two positive cubes.
+///
+/// $$n = a^3 + b^3, \quad 1 \le a \le b$$
+///
+/// Since $a \le b$, $a^3$ is at most half of $n$, so only $a \le \sqrt\[3\]{n / 2}$ needs to be checked,
+/// and for each one $b$ is found with an integer cube root.
+///
+/// # Arguments
+///
+/// * `n` - The number to write as a sum of cubes.
+///
+/// # Returns
+///
+/// Vector of the pairs `(a, b)`, in increasing order of `a`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::squares::taxicab_representations;
+/// assert_eq!(taxicab_representations(1729), vec![(1, 12), (9, 10)]);
+/// assert_eq!(taxicab_representations(1730), vec![]);
+/// ```
+pub fn taxicab_representations(n: u64) -> Vec<(u64, u64)> {
+    let mut representations: Vec<(u64, u64)> = vec![];
+    for a in 1..=integer_nth_root(n / 2, 3) {
+        let remainder: u64 = n - a * a * a;
+        let b: u64 = integer_nth_root(remainder, 3);
+        if b * b * b == remainder {
+            representations.push((a, b));
+        }
+    }
+    representations
+}
+
+/// Finds the smallest *taxicab number* with a given number of representations as a sum of two positive cubes.
+///
+/// The search finds every sum $a^3 + b^3$ up to a limit, and doubles the limit until some sum has come up often enough.
+/// The first few are 2, 1729 (Hardy and Ramanujan's taxicab), and 87539319.
+///
+/// [A011541](https://oeis.org/A011541)
+///
+/// # Arguments
+///
+/// * `ways` - The number of representations needed.
+///
+/// # Returns
+///
+/// The smallest number that can be written as $a^3 + b^3$ in at least `ways` ways.
+///
+/// # Panics
+///
+/// Panics if `ways` is 0, or if the answer doesn't fit in a `u64`.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::squares::smallest_taxicab;
+/// assert_eq!(smallest_taxicab(2), 1729);
+/// ```
+pub fn smallest_taxicab(ways: usize) -> u64 {
+    if ways == 0 {
+        panic!("ways must be at least 1");
+    }
+    let mut limit: u64 = 1024;
+    loop {
+        let mut counts: HashMap<u64, usize> = HashMap::new();
+        for a in 1..=integer_nth_root(limit / 2, 3) {
+            let cube: u64 = a * a * a;
+            for b in a..=integer_nth_root(limit - cube, 3) {
+                *counts.entry(cube + b * b * b).or_insert(0) += 1;
+            }
+        }
+        if let Some(n) = counts
+            .into_iter()
+            .filter(|&(_, count)| count >= ways)
+            .map(|(n, _)| n)
+            .min()
+        {
+            return n;
+        }
+        limit = limit
+            .checked_mul(2)
+            .expect("No taxicab number with that many representations fits in a u64");
+    }
+}
@@ -0,0 +1,277 @@
+use crate::gcd::gcd;
+use crate::primes::prime_factors;
+use std::collections::HashMap;
+
+/// Calculates Euler's totient function $\varphi(n)$, the number of integers from 1 to `n` that are coprime to `n`.
+///
+/// Rather than counting coprimes one by one, `n` is factored and the product formula is applied
+/// over its distinct prime factors:
+/// $$\varphi(n) = n\prod_{p \mid n}\left(1 - \frac{1}{p}\right)$$
+///
+/// [A000010](https://oeis.org/A000010)
+///
+/// # Arguments
+///
+/// * `n` - The number to calculate the totient of.
+///
+/// # Returns
+///
+/// $\varphi(n)$, with $\varphi(1) = 1$ and $\varphi(p) = p - 1$ for primes. Numbers below 1 give 0.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::totient::euler_totient;
+/// assert_eq!(euler_totient(1), 1);
+/// assert_eq!(euler_totient(12), 4);
+/// assert_eq!(euler_totient(13_u64), 12);
+/// ```
+pub fn euler_totient<T>(n: T) -> T
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + num::FromPrimitive
+        + std::ops::Sub<Output = T>
+        + std::ops::Mul<Output = T>
+        + std::ops::Div<Output = T>
+        + std::ops::Rem<Output = T>
+        + std::ops::AddAssign
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    if n < T::one() {
+        return T::zero();
+    }
+    let mut factors: Vec<T> = prime_factors(n);
+    factors.dedup();
+    let mut phi: T = n;
+    for p in factors {
+        // divide first so we never overflow
+        phi = phi / p * (p - T::one());
+    }
+    phi
+}
+
+/// Generates the *totatives* of `n`, the numbers from 1 to `n` that are coprime to `n`.
+///
+/// The totatives of `n` form the multiplicative group of integers modulo `n`,
+/// and there are exactly $\varphi(n)$ of them.
+///
+/// # Arguments
+///
+/// * `n` - The number to find the totatives of.
+///
+/// # Returns
+///
+/// Vector of every `k` with $1 \le k \le n$ and $\gcd(k, n) = 1$, in ascending order.
+/// Since $\gcd(1, 1) = 1$, the only totative of 1 is 1 itself.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::totient::totatives;
+/// assert_eq!(totatives(10), vec![1, 3, 7, 9]);
+/// assert_eq!(totatives(1), vec![1]);
+/// ```
+pub fn totatives<T>(n: T) -> Vec<T>
+where
+    T: num::traits::Zero
+        + num::traits::One
+        + std::ops::Sub<Output = T>
+        + std::ops::Rem<Output = T>
+        + std::ops::AddAssign
+        + std::cmp::PartialOrd
+        + Copy,
+{
+    let mut t: Vec<T> = vec![];
+    let mut k: T = T::one();
+    while k <= n {
+        if gcd(k, n) == T::one() {
+            t.push(k);
+        }
+        k += T::one();
+    }
+    t
+}
+
+/// Calculates Euler's totient $\varphi(k)$ for every `k` up to `limit` using a sieve.
+///
+/// Every entry starts as $\varphi(k) = k$, and then for each prime $p$,
+/// every multiple of $p$ is multiplied by $\left(1 - \frac{1}{p}\right)$ as in the product formula
+/// $$\varphi(n) = n\prod_{p \mid n}\left(1 - \frac{1}{p}\right)$$
+///
+/// # Arguments
+///
+/// * `limit` - The largest number to calculate the totient of.
+///
+/// # Returns
+///
+/// Vector of length `limit + 1` where index `k` holds $\varphi(k)$ (with $\varphi(0) = 0$).
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::totient::totient_sieve;
+/// assert_eq!(totient_sieve(10), vec![0, 1, 1, 2, 2, 4, 2, 6, 4, 6, 4]);
+/// ```
+pub fn totient_sieve(limit: u64) -> Vec<u64> {
+    let mut phi: Vec<u64> = (0..=limit).collect();
+    for p in 2..=limit as usize {
+        // untouched entries are prime
+        if phi[p] == p as u64 {
+            for multiple in (p..=limit as usize).step_by(p) {
+                phi[multiple] -= phi[multiple] / p as u64;
+            }
+        }
+    }
+    phi
+}
+
+/// Lazily yields Euler's totient $\varphi(n)$ for $n = 1, 2, 3, \dots$
+///
+/// Rather than factoring each `n`, the iterator keeps a `totient_sieve` and doubles its size whenever it runs out,
+/// so the total work stays proportional to sieving up to the largest `n` reached.
+///
+/// [A000010](https://oeis.org/A000010)
+///
+/// # Returns
+///
+/// An endless iterator over $\varphi(1), \varphi(2), \varphi(3), \dots$
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::totient::totient_iter;
+/// let phi: Vec<u64> = totient_iter().take(6).collect();
+/// assert_eq!(phi, vec![1, 1, 2, 2, 4, 2]);
+/// ```
+pub fn totient_iter() -> impl Iterator<Item = u64> {
+    let mut phi: Vec<u64> = vec![];
+    (1_u64..).map(move |n| {
+        if n as usize >= phi.len() {
+            phi = totient_sieve((2 * n).max(64));
+        }
+        phi[n as usize]
+    })
+}
+
+/// Counts the pairs of coprime numbers up to `n`.
+///
+/// A pair $(a, b)$ with $1 \le a < b \le n$ is counted when $\gcd(a, b) = 1$.
+/// For each `b`, there are exactly $\varphi(b)$ such `a`, so
+/// $$\\#\\{(a, b)\\} = \sum_{k=2}^{n}\varphi(k)$$
+///
+/// As `n` grows, the proportion of all pairs that are coprime approaches $\frac{6}{\pi^2}$.
+///
+/// # Arguments
+///
+/// * `n` - The largest number allowed in a pair.
+///
+/// # Returns
+///
+/// The number of coprime pairs.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::totient::coprime_pairs_below;
+/// assert_eq!(coprime_pairs_below(5), 9);
+/// ```
+pub fn coprime_pairs_below(n: u64) -> u64 {
+    totient_sieve(n).iter().skip(2).sum()
+}
+
+/// Calculates the *totient summatory function* $\Phi(n) = \sum_{k=1}^{n}\varphi(k)$ using `totient_sieve`.
+///
+/// [A002088](https://oeis.org/A002088)
+///
+/// # Arguments
+///
+/// * `n` - The last number in the sum.
+///
+/// # Returns
+///
+/// $\Phi(n)$, which is 0 when `n` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::totient::totient_sum;
+/// assert_eq!(totient_sum(10), 32);
+/// ```
+pub fn totient_sum(n: u64) -> u64 {
+    totient_sieve(n).iter().skip(1).sum()
+}
+
+/// Calculates the *totient summatory function* $\Phi(n) = \sum_{k=1}^{n}\varphi(k)$ in sublinear time.
+///
+/// Every pair $1 \le a \le b \le n$ has some $\gcd(a, b) = d$, and the pairs with a given $d$
+/// are exactly the coprime pairs up to $\left\lfloor \frac{n}{d} \right\rfloor$ scaled by $d$, so
+/// $$\Phi(n) = \frac{n(n + 1)}{2} - \sum_{d=2}^{n} \Phi\left(\left\lfloor \frac{n}{d} \right\rfloor\right)$$
+///
+/// There are only about $2\sqrt{n}$ different values of $\left\lfloor \frac{n}{d} \right\rfloor$,
+/// so the sum is done in blocks of $d$ with the same quotient, and each $\Phi$ is memoized.
+/// The values below $n^{2/3}$ come from `totient_sieve` instead, which makes the whole thing $O(n^{2/3})$.
+///
+/// # Arguments
+///
+/// * `n` - The last number in the sum.
+///
+/// # Returns
+///
+/// $\Phi(n)$, which is 0 when `n` is 0.
+///
+/// # Panics
+///
+/// Panics if $\Phi(n)$ doesn't fit in a `u64`, which happens for `n` above about $7.7 \times 10^9$.
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::totient::totient_sum_fast;
+/// assert_eq!(totient_sum_fast(10), 32);
+/// assert_eq!(totient_sum_fast(1_000_000_000), 303963551173008414);
+/// ```
+pub fn totient_sum_fast(n: u64) -> u64 {
+    let sieve_limit: u64 = ((n as f64).powf(2.0 / 3.0) as u64).max(1);
+    // small[k] is the sum of phi up to and including k
+    let mut small: Vec<u128> = vec![0];
+    for phi in totient_sieve(sieve_limit).into_iter().skip(1) {
+        small.push(small[small.len() - 1] + phi as u128);
+    }
+    let mut memo: HashMap<u64, u128> = HashMap::new();
+    totient_sum_recursive(n, &small, &mut memo)
+        .try_into()
+        .expect("The totient sum is too large for a u64")
+}
+
+/// Calculates $\Phi(n)$ for `totient_sum_fast`.
+///
+/// # Arguments
+///
+/// * `n` - The last number in the sum.
+/// * `small` - $\Phi(k)$ for every `k` up to the sieve limit.
+/// * `memo` - The values of $\Phi$ already found above the sieve limit.
+///
+/// # Returns
+///
+/// $\Phi(n)$.
+fn totient_sum_recursive(n: u64, small: &[u128], memo: &mut HashMap<u64, u128>) -> u128 {
+    if (n as usize) < small.len() {
+        return small[n as usize];
+    }
+    if let Some(&known) = memo.get(&n) {
+        return known;
+    }
+    let mut total: u128 = n as u128 * (n as u128 + 1) / 2;
+    let mut d: u64 = 2;
+    while d <= n {
+        let q: u64 = n / d;
+        // every d up to n / q gives the same quotient q
+        let last: u64 = n / q;
+        total -= (last - d + 1) as u128 * totient_sum_recursive(q, small, memo);
+        d = last + 1;
+    }
+    memo.insert(n, total);
+    total
+}
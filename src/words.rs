@@ -0,0 +1,174 @@
+/// The names of the numbers below twenty.
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+/// The names of the multiples of ten, indexed by the tens digit.
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// The names of each group of three digits, starting from the ones.
+const SCALES: [&str; 7] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+];
+
+/// Spells a number in (American) English.
+///
+/// Numbers are split into groups of three digits, each named with its scale (thousand, million, ...),
+/// and the tens and ones are joined with a hyphen, as in "forty-two".
+///
+/// American English doesn't use "and" inside numbers, so 342 is "three hundred forty-two".
+/// See `number_to_words_british` for the British convention, "three hundred and forty-two".
+///
+/// # Arguments
+///
+/// * `n` - The number to spell.
+///
+/// # Returns
+///
+/// The number written in words, using the short scale (a billion is $10^9$).
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::words::number_to_words;
+/// assert_eq!(number_to_words(342), "three hundred forty-two");
+/// assert_eq!(number_to_words(1_000_021), "one million twenty-one");
+/// ```
+pub fn number_to_words(n: u64) -> String {
+    spell(n, false)
+}
+
+/// Spells a number in British English.
+///
+/// This is the same as `number_to_words`, except that "and" comes before the tens and ones
+/// whenever anything larger comes before them, as in "three hundred and forty-two" or "one thousand and five".
+///
+/// # Arguments
+///
+/// * `n` - The number to spell.
+///
+/// # Returns
+///
+/// The number written in words, using the short scale (a billion is $10^9$).
+///
+/// # Examples
+///
+/// ```
+/// use bens_number_theory::words::number_to_words_british;
+/// assert_eq!(number_to_words_british(342), "three hundred and forty-two");
+/// assert_eq!(number_to_words_british(1005), "one thousand and five");
+/// ```
+pub fn number_to_words_british(n: u64) -> String {
+    spell(n, true)
+}
+
+/// Spells a number in English.
+///
+/// # Arguments
+///
+/// * `n` - The number to spell.
+/// * `british` - Whether to put "and" before the tens and ones.
+///
+/// # Returns
+///
+/// The number written in words.
+fn spell(n: u64, british: bool) -> String {
+    if n == 0 {
+        return ONES[0].to_string();
+    }
+    let mut groups: Vec<u64> = vec![];
+    let mut rest: u64 = n;
+    while rest > 0 {
+        groups.push(rest % 1000);
+        rest /= 1000;
+    }
+    let mut words: Vec<String> = vec![];
+    for (scale, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        // the final group joins the rest with "and" when it has no hundreds
+        if british && scale == 0 && group < 100 && n >= 1000 {
+            words.push("and".to_string());
+        }
+        words.push(spell_below_thousand(group, british));
+        if scale > 0 {
+            words.push(SCALES[scale].to_string());
+        }
+    }
+    words.join(" ")
+}
+
+/// Spells a number between 1 and 999 in English.
+///
+/// # Arguments
+///
+/// * `n` - The number to spell.
+/// * `british` - Whether to put "and" between the hundreds and the rest.
+///
+/// # Returns
+///
+/// The number written in words.
+fn spell_below_thousand(n: u64, british: bool) -> String {
+    let hundreds: usize = (n / 100) as usize;
+    let rest: usize = (n % 100) as usize;
+    let mut words: Vec<&str> = vec![];
+    if hundreds > 0 {
+        words.extend([ONES[hundreds], "hundred"]);
+        if british && rest > 0 {
+            words.push("and");
+        }
+    }
+    let tens: String = match rest {
+        0 => String::new(),
+        1..=19 => ONES[rest].to_string(),
+        _ if rest.is_multiple_of(10) => TENS[rest / 10].to_string(),
+        _ => format!("{}-{}", TENS[rest / 10], ONES[rest % 10]),
+    };
+    if !tens.is_empty() {
+        words.push(&tens);
+    }
+    words.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::spell_below_thousand;
+
+    #[test]
+    fn spell_below_thousand_test() {
+        assert_eq!(spell_below_thousand(7, false), "seven");
+        assert_eq!(spell_below_thousand(40, false), "forty");
+        assert_eq!(spell_below_thousand(99, false), "ninety-nine");
+        assert_eq!(spell_below_thousand(500, true), "five hundred");
+        assert_eq!(spell_below_thousand(512, true), "five hundred and twelve");
+        assert_eq!(spell_below_thousand(512, false), "five hundred twelve");
+    }
+}
@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod is_power_of_two_tests {
+    use bens_number_theory::bits::is_power_of_two;
+
+    #[test]
+    fn is_power_of_two_test() {
+        assert!(is_power_of_two(64));
+        assert!(!is_power_of_two(48));
+        assert!(!is_power_of_two(0));
+        assert!(!is_power_of_two(-4));
+    }
+
+    #[test]
+    fn powers_and_neighbours_test() {
+        for k in 0..128 {
+            let n: u128 = 1 << k;
+            assert!(is_power_of_two(n));
+            if n > 2 {
+                assert!(!is_power_of_two(n - 1));
+                assert!(!is_power_of_two(n + 1));
+            }
+        }
+        assert!(is_power_of_two(128_u8));
+        assert!(!is_power_of_two(u64::MAX));
+    }
+}
+
+#[cfg(test)]
+mod log2_floor_tests {
+    use bens_number_theory::bits::log2_floor;
+
+    #[test]
+    fn log2_floor_test() {
+        assert_eq!(log2_floor(1), 0);
+        assert_eq!(log2_floor(2), 1);
+        assert_eq!(log2_floor(3), 1);
+        assert_eq!(log2_floor(1000), 9);
+        assert_eq!(log2_floor(u64::MAX), 63);
+    }
+
+    #[test]
+    fn powers_and_neighbours_test() {
+        for k in 1..128 {
+            let n: u128 = 1 << k;
+            assert_eq!(log2_floor(n), k);
+            assert_eq!(log2_floor(n - 1), k - 1);
+            assert_eq!(log2_floor(n + 1), k);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn log2_floor_zero_test() {
+        log2_floor(0);
+    }
+}
@@ -0,0 +1,294 @@
+#[cfg(test)]
+mod eval_power_series_tests {
+    use bens_number_theory::constants::eval_power_series;
+    use bens_number_theory::factorials::factorial;
+    use num::{BigInt, BigRational};
+
+    fn int(n: i64) -> BigRational {
+        BigRational::from(BigInt::from(n))
+    }
+
+    #[test]
+    fn eval_polynomial_test() {
+        let coeffs: Vec<BigRational> = vec![int(1), int(2), int(3)];
+        assert_eq!(eval_power_series(&coeffs, &int(2)), int(17));
+        assert_eq!(eval_power_series(&coeffs, &int(0)), int(1));
+        assert_eq!(eval_power_series(&coeffs, &int(-1)), int(2));
+    }
+
+    #[test]
+    fn eval_rational_point_test() {
+        // 1 + x + x^2 at x = 1/2 is 7/4
+        let coeffs: Vec<BigRational> = vec![int(1), int(1), int(1)];
+        let half: BigRational = BigRational::new(BigInt::from(1), BigInt::from(2));
+        assert_eq!(
+            eval_power_series(&coeffs, &half),
+            BigRational::new(BigInt::from(7), BigInt::from(4))
+        );
+    }
+
+    #[test]
+    fn eval_empty_series_test() {
+        assert_eq!(eval_power_series(&[], &int(5)), int(0));
+    }
+
+    #[test]
+    fn eval_exp_series_test() {
+        // sum of 1/k! for k < 20 approximates e
+        let coeffs: Vec<BigRational> = (0..20)
+            .map(|k| BigRational::from(factorial(BigInt::from(k))).recip())
+            .collect();
+        let e: BigRational = eval_power_series(&coeffs, &int(1));
+        let scaled: BigInt = (e * int(10_i64.pow(15))).to_integer();
+        assert_eq!(scaled, BigInt::from(2718281828459045_i64));
+    }
+}
+
+#[cfg(test)]
+mod estimate_ln_ratio_tests {
+    use bens_number_theory::constants::estimate_ln_ratio;
+    use num::{BigInt, BigRational};
+
+    fn first_digits(r: BigRational, digits: u32) -> BigInt {
+        (r * BigRational::from(BigInt::from(10).pow(digits))).to_integer()
+    }
+
+    #[test]
+    fn ln_two_test() {
+        let ln_two: BigRational = estimate_ln_ratio(&BigRational::from(BigInt::from(2)), 30);
+        assert_eq!(first_digits(ln_two, 10), BigInt::from(6931471805_u64));
+    }
+
+    #[test]
+    fn ln_ten_test() {
+        let ln_ten: BigRational = estimate_ln_ratio(&BigRational::from(BigInt::from(10)), 60);
+        assert_eq!(first_digits(ln_ten, 10), BigInt::from(23025850929_u64));
+    }
+
+    #[test]
+    fn ln_one_test() {
+        let ln_one: BigRational = estimate_ln_ratio(&BigRational::from(BigInt::from(1)), 10);
+        assert_eq!(ln_one, BigRational::from(BigInt::from(0)));
+    }
+
+    #[test]
+    fn ln_reciprocal_test() {
+        // ln(1/2) = -ln(2), and the series is odd in y, so this holds exactly
+        let half: BigRational = BigRational::new(BigInt::from(1), BigInt::from(2));
+        let two: BigRational = BigRational::from(BigInt::from(2));
+        assert_eq!(estimate_ln_ratio(&half, 20), -estimate_ln_ratio(&two, 20));
+    }
+
+    #[test]
+    #[should_panic]
+    fn ln_non_positive_test() {
+        estimate_ln_ratio(&BigRational::from(BigInt::from(0)), 10);
+    }
+}
+
+#[cfg(test)]
+mod estimate_pi_ratio_tests {
+    use bens_number_theory::constants::estimate_pi_ratio;
+    use num::{BigInt, BigRational};
+    use std::str::FromStr;
+
+    #[test]
+    fn estimate_pi_ratio_precision_test() {
+        // each term of the series adds about 8 digits
+        let pi: BigRational = estimate_pi_ratio(5);
+        let digits: BigInt = (pi * BigRational::from(BigInt::from(10).pow(38))).to_integer();
+        assert_eq!(
+            digits,
+            BigInt::from_str("314159265358979323846264338327950288419").unwrap()
+        );
+    }
+
+    #[test]
+    fn estimate_pi_ratio_third_term_test() {
+        // the third term is the first one where (n!)^4 isn't 1
+        let pi: BigRational = estimate_pi_ratio(3);
+        let digits: BigInt = (pi * BigRational::from(BigInt::from(10).pow(23))).to_integer();
+        assert_eq!(
+            digits,
+            BigInt::from_str("314159265358979323846264").unwrap()
+        );
+    }
+}
+
+#[cfg(test)]
+mod cached_constant_tests {
+    use bens_number_theory::constants::{e_cached, golden_ratio_cached, pi_cached};
+    use num::{BigInt, BigRational, Zero};
+    use std::str::FromStr;
+
+    fn digits(r: &BigRational, d: u32) -> String {
+        (r * BigRational::from(BigInt::from(10).pow(d)))
+            .to_integer()
+            .to_string()
+    }
+
+    #[test]
+    fn pi_cached_test() {
+        assert_eq!(
+            digits(&pi_cached(50), 50),
+            "314159265358979323846264338327950288419716939937510"
+        );
+        assert_eq!(pi_cached(2), BigRational::from_str("314/100").unwrap());
+    }
+
+    /// Calculates $\lfloor \pi \cdot 10^{d} \rfloor$ independently, with *Machin's formula*
+    /// $\pi = 16\arctan\frac{1}{5} - 4\arctan\frac{1}{239}$ in fixed point.
+    fn machin_pi_digits(d: u32) -> BigInt {
+        let scale: BigInt = BigInt::from(10).pow(d + 10);
+        let arctan_inv = |x: u32| -> BigInt {
+            let mut power: BigInt = &scale / x;
+            let mut sum: BigInt = BigInt::zero();
+            let mut k: u32 = 0;
+            while !power.is_zero() {
+                let term: BigInt = &power / (2 * k + 1);
+                if k.is_multiple_of(2) {
+                    sum += term;
+                } else {
+                    sum -= term;
+                }
+                power /= x * x;
+                k += 1;
+            }
+            sum
+        };
+        (16 * arctan_inv(5) - 4 * arctan_inv(239)) / BigInt::from(10).pow(10)
+    }
+
+    #[test]
+    fn pi_cached_many_digits_test() {
+        // past about 7000 digits, assuming 8 digits per term of the series falls behind
+        assert_eq!(
+            digits(&pi_cached(8000), 8000),
+            machin_pi_digits(8000).to_string()
+        );
+    }
+
+    #[test]
+    fn e_cached_test() {
+        assert_eq!(
+            digits(&e_cached(50), 50),
+            "271828182845904523536028747135266249775724709369995"
+        );
+        assert_eq!(e_cached(0), BigRational::from(BigInt::from(2)));
+    }
+
+    #[test]
+    fn golden_ratio_cached_test() {
+        assert_eq!(
+            digits(&golden_ratio_cached(50), 50),
+            "161803398874989484820458683436563811772030917980576"
+        );
+    }
+
+    #[test]
+    fn cached_values_identical_test() {
+        let first: BigRational = pi_cached(400);
+        let second: BigRational = pi_cached(400);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn cached_fewer_digits_test() {
+        let many: BigRational = e_cached(300);
+        let few: BigRational = e_cached(20);
+        assert_eq!(digits(&few, 20), digits(&many, 20));
+        assert_eq!(&digits(&many, 300)[..21], digits(&few, 20));
+    }
+}
+
+#[cfg(test)]
+mod golden_ratio_tests {
+    use bens_number_theory::constants::golden_ratio;
+    use bens_number_theory::sequences::lucas_sequence;
+    use num::{rational::Ratio, BigInt, Integer, One, Zero};
+
+    #[test]
+    fn golden_ratio_lucas_test() {
+        let lucas: Vec<BigInt> = lucas_sequence(BigInt::from(20));
+        let expected: Ratio<BigInt> = Ratio::new(lucas[19].clone(), lucas[18].clone());
+        assert_eq!(golden_ratio(BigInt::from(20)), expected);
+        assert_eq!(
+            golden_ratio(BigInt::from(20)),
+            Ratio::new(BigInt::from(9349), BigInt::from(5778))
+        );
+    }
+
+    #[test]
+    fn golden_ratio_lowest_terms_test() {
+        for n in 2..200 {
+            let phi: Ratio<BigInt> = golden_ratio(BigInt::from(n));
+            assert!(phi.numer().gcd(phi.denom()).is_one());
+        }
+    }
+
+    #[test]
+    fn golden_ratio_small_test() {
+        assert!(golden_ratio(BigInt::from(0)).is_zero());
+        assert!(golden_ratio(BigInt::from(1)).is_zero());
+        assert_eq!(
+            golden_ratio(BigInt::from(2)),
+            Ratio::new(BigInt::from(1), BigInt::from(2))
+        );
+        assert_eq!(golden_ratio(BigInt::from(3)), Ratio::from(BigInt::from(3)));
+    }
+}
+
+#[cfg(test)]
+mod e_continued_fraction_tests {
+    use bens_number_theory::constants::{e_cached, e_continued_fraction};
+    use bens_number_theory::rational_abs;
+    use bens_number_theory::rationals::convergents;
+    use num::{BigInt, BigRational};
+
+    #[test]
+    fn e_continued_fraction_test() {
+        assert_eq!(e_continued_fraction(0), vec![]);
+        assert_eq!(e_continued_fraction(1), vec![2]);
+        assert_eq!(e_continued_fraction(10), vec![2, 1, 2, 1, 1, 4, 1, 1, 6, 1]);
+        let coeffs: Vec<u64> = e_continued_fraction(301);
+        for k in 1..=100 {
+            assert_eq!(coeffs[3 * k - 1], 2 * k as u64);
+            assert_eq!(coeffs[3 * k - 2], 1);
+            assert_eq!(coeffs[3 * k], 1);
+        }
+    }
+
+    #[test]
+    fn e_convergents_test() {
+        let coeffs: Vec<BigInt> = e_continued_fraction(30)
+            .into_iter()
+            .map(BigInt::from)
+            .collect();
+        let approximations: Vec<BigRational> = convergents(&coeffs);
+        assert_eq!(
+            approximations[..6],
+            [(2, 1), (3, 1), (8, 3), (11, 4), (19, 7), (87, 32)]
+                .map(|(h, k)| BigRational::new(BigInt::from(h), BigInt::from(k)))
+        );
+        let e: BigRational = e_cached(60);
+        let errors: Vec<BigRational> = approximations
+            .iter()
+            .map(|c| rational_abs(&(c - &e)))
+            .collect();
+        for pair in errors.windows(2) {
+            assert!(pair[1] < pair[0]);
+        }
+        // Project Euler 65: the numerator of the 100th convergent
+        let coeffs: Vec<BigInt> = e_continued_fraction(100)
+            .into_iter()
+            .map(BigInt::from)
+            .collect();
+        let numerator: BigInt = convergents(&coeffs)[99].numer().clone();
+        let digit_sum: u32 = numerator
+            .to_string()
+            .chars()
+            .map(|c| c.to_digit(10).unwrap())
+            .sum();
+        assert_eq!(digit_sum, 272);
+    }
+}
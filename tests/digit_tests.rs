@@ -0,0 +1,538 @@
+#[cfg(test)]
+mod digit_sum_tests {
+    use bens_number_theory::digits::digit_sum;
+
+    #[test]
+    fn digit_sum_test() {
+        assert_eq!(digit_sum(0), 0);
+        assert_eq!(digit_sum(7), 7);
+        assert_eq!(digit_sum(1234), 10);
+        assert_eq!(digit_sum(9_999_999_999_u64), 90);
+    }
+}
+
+#[cfg(test)]
+mod smith_tests {
+    use bens_number_theory::digits::is_smith;
+
+    #[test]
+    fn smith_numbers_test() {
+        assert!(is_smith(4));
+        assert!(is_smith(22));
+        assert!(is_smith(27));
+        assert!(is_smith(58));
+        assert!(is_smith(85));
+        assert!(is_smith(4937775));
+    }
+
+    #[test]
+    fn non_smith_numbers_test() {
+        assert!(!is_smith(6));
+        assert!(!is_smith(10));
+        assert!(!is_smith(100));
+    }
+
+    #[test]
+    fn smith_primes_excluded_test() {
+        // primes trivially have matching digit sums, but are not Smith numbers
+        assert!(!is_smith(0));
+        assert!(!is_smith(1));
+        assert!(!is_smith(2));
+        assert!(!is_smith(11));
+        assert!(!is_smith(97));
+    }
+}
+
+#[cfg(test)]
+mod repunit_tests {
+    use bens_number_theory::digits::{is_repdigit, is_repunit, is_repunit_prime, repunit};
+    use num::BigInt;
+    use std::str::FromStr;
+
+    #[test]
+    fn repunit_test() {
+        assert_eq!(repunit(1), BigInt::from(1));
+        assert_eq!(repunit(3), BigInt::from(111));
+        assert_eq!(repunit(5), BigInt::from(11111));
+        assert_eq!(
+            repunit(30),
+            BigInt::from_str("111111111111111111111111111111").unwrap()
+        );
+    }
+
+    #[test]
+    fn is_repunit_test() {
+        assert!(is_repunit(&repunit(1)));
+        assert!(is_repunit(&repunit(40)));
+        assert!(!is_repunit(&BigInt::from(0)));
+        assert!(!is_repunit(&BigInt::from(121)));
+        assert!(!is_repunit(&BigInt::from(-11)));
+    }
+
+    #[test]
+    fn is_repdigit_test() {
+        assert!(is_repdigit(&BigInt::from(0), 10));
+        assert!(is_repdigit(&BigInt::from(5555), 10));
+        assert!(is_repdigit(&BigInt::from(7), 2)); // 111
+        assert!(is_repdigit(&BigInt::from(4095), 16)); // fff
+        assert!(!is_repdigit(&BigInt::from(8), 2)); // 1000
+        assert!(!is_repdigit(&BigInt::from(5556), 10));
+    }
+
+    #[test]
+    fn large_repdigit_test() {
+        let nines: BigInt = BigInt::from(10).pow(500) - 1;
+        assert!(is_repdigit(&nines, 10));
+        assert!(!is_repdigit(&(&nines + 1), 10));
+        assert!(is_repdigit(&(BigInt::from(36).pow(300) - 1), 36)); // zzz...z
+        assert!(is_repunit(&repunit(1000)));
+        assert!(!is_repunit(&(repunit(1000) + 1)));
+    }
+
+    #[test]
+    fn is_repunit_prime_test() {
+        assert!(!is_repunit_prime(1));
+        assert!(is_repunit_prime(2));
+        assert!(!is_repunit_prime(4));
+        // the repunit primes below 100 ones are R_2, R_19 and R_23
+        let k: Vec<u32> = (1..100).filter(|&k| is_repunit_prime(k)).collect();
+        assert_eq!(k, vec![2, 19, 23]);
+    }
+}
+
+#[cfg(test)]
+mod factorial_chain_tests {
+    use bens_number_theory::digits::{factorial_chain, factorial_chain_length};
+
+    #[test]
+    fn factorial_chain_test() {
+        assert_eq!(factorial_chain(169), vec![169, 363601, 1454, 169]);
+        assert_eq!(
+            factorial_chain(69),
+            vec![69, 363600, 1454, 169, 363601, 1454]
+        );
+        assert_eq!(factorial_chain(1), vec![1, 1]);
+        assert_eq!(factorial_chain(0), vec![0, 1, 1]);
+    }
+
+    #[test]
+    fn factorial_chain_length_test() {
+        assert_eq!(factorial_chain_length(169), 3);
+        assert_eq!(factorial_chain_length(69), 5);
+        assert_eq!(factorial_chain_length(78), 4);
+        assert_eq!(factorial_chain_length(540), 2);
+    }
+
+    #[test]
+    fn factorions_test() {
+        // factorions are exactly the numbers whose chain is a 1-cycle
+        let factorions: Vec<u64> = (1..100000)
+            .filter(|&n| factorial_chain_length(n) == 1)
+            .collect();
+        assert_eq!(factorions, vec![1, 2, 145, 40585]);
+    }
+}
+
+#[cfg(test)]
+mod digit_base_tests {
+    use bens_number_theory::digits::{digit_count, digit_sum, digit_sum_base};
+
+    #[test]
+    fn digit_count_test() {
+        assert_eq!(digit_count(0, 2), 1);
+        assert_eq!(digit_count(1, 2), 1);
+        assert_eq!(digit_count(8, 2), 4);
+        assert_eq!(digit_count(255, 16), 2);
+        assert_eq!(digit_count(256, 16), 3);
+        assert_eq!(digit_count(0, 10), 1);
+        assert_eq!(digit_count(999, 10), 3);
+        assert_eq!(digit_count(1000, 10), 4);
+        assert_eq!(digit_count(u128::MAX, 10), 39);
+        assert_eq!(digit_count(u128::MAX, 2), 128);
+    }
+
+    #[test]
+    fn digit_sum_base_test() {
+        assert_eq!(digit_sum_base(0, 2), 0);
+        assert_eq!(digit_sum_base(255, 2), 8);
+        assert_eq!(digit_sum_base(0, 16), 0);
+        assert_eq!(digit_sum_base(255, 16), 30);
+        assert_eq!(digit_sum_base(0, 10), 0);
+        assert_eq!(digit_sum_base(35, 36), 35);
+    }
+
+    #[test]
+    fn digit_sum_base_matches_digit_sum_test() {
+        for n in 0..1000_u128 {
+            assert_eq!(digit_sum_base(n, 10), digit_sum(n));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn digit_count_bad_radix_test() {
+        digit_count(10, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn digit_sum_base_bad_radix_test() {
+        digit_sum_base(10, 37);
+    }
+}
+
+#[cfg(test)]
+mod champernowne_digit_tests {
+    use bens_number_theory::digits::champernowne_digit;
+
+    #[test]
+    fn champernowne_digit_test() {
+        let expected: String = (1..1000).map(|n| n.to_string()).collect();
+        for (i, c) in expected.chars().enumerate() {
+            assert_eq!(
+                champernowne_digit(i as u64 + 1),
+                c.to_digit(10).unwrap() as u8
+            );
+        }
+    }
+
+    #[test]
+    fn champernowne_digit_boundaries_test() {
+        // 9 -> 10
+        assert_eq!(champernowne_digit(9), 9);
+        assert_eq!(champernowne_digit(10), 1);
+        assert_eq!(champernowne_digit(11), 0);
+        // 99 -> 100 (99 ends at position 9 + 90 * 2 = 189)
+        assert_eq!(champernowne_digit(188), 9);
+        assert_eq!(champernowne_digit(189), 9);
+        assert_eq!(champernowne_digit(190), 1);
+        assert_eq!(champernowne_digit(191), 0);
+        assert_eq!(champernowne_digit(192), 0);
+    }
+
+    #[test]
+    fn champernowne_product_test() {
+        // Project Euler 40
+        let product: u64 = (0..7)
+            .map(|k| champernowne_digit(10_u64.pow(k)) as u64)
+            .product();
+        assert_eq!(product, 210);
+        assert!(champernowne_digit(u64::MAX) < 10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn champernowne_digit_zero_test() {
+        champernowne_digit(0);
+    }
+}
+
+#[cfg(test)]
+mod keith_tests {
+    use bens_number_theory::digits::is_keith;
+
+    #[test]
+    fn is_keith_test() {
+        for n in [14, 19, 28, 47, 61, 75, 197, 742, 1104, 1537, 7385] {
+            assert!(is_keith(n), "{}", n);
+        }
+    }
+
+    #[test]
+    fn not_keith_test() {
+        for n in [0, 1, 9, 10, 15, 100, 196, 743] {
+            assert!(!is_keith(n), "{}", n);
+        }
+    }
+
+    #[test]
+    fn keith_numbers_below_1000_test() {
+        let keith: Vec<u64> = (0..1000).filter(|&n| is_keith(n)).collect();
+        assert_eq!(keith, vec![14, 19, 28, 47, 61, 75, 197, 742]);
+        assert!(!is_keith(u64::MAX));
+    }
+}
+
+#[cfg(test)]
+mod vampire_tests {
+    use bens_number_theory::digits::is_vampire;
+
+    #[test]
+    fn is_vampire_test() {
+        assert_eq!(is_vampire(1260), Some(vec![(21, 60)]));
+        assert_eq!(is_vampire(1395), Some(vec![(15, 93)]));
+        assert_eq!(is_vampire(1435), Some(vec![(35, 41)]));
+        assert_eq!(is_vampire(1530), Some(vec![(30, 51)]));
+        assert_eq!(is_vampire(125460), Some(vec![(204, 615), (246, 510)]));
+    }
+
+    #[test]
+    fn not_vampire_test() {
+        assert_eq!(is_vampire(0), None);
+        assert_eq!(is_vampire(1000), None);
+        assert_eq!(is_vampire(1234), None);
+        // odd number of digits
+        assert_eq!(is_vampire(12600), None);
+        // 126000 = 210 * 600, but both fangs end in 0
+        assert_eq!(is_vampire(126000), None);
+    }
+
+    #[test]
+    fn four_digit_vampires_test() {
+        let vampires: Vec<u64> = (1000..10000).filter(|&n| is_vampire(n).is_some()).collect();
+        assert_eq!(vampires, vec![1260, 1395, 1435, 1530, 1827, 2187, 6880]);
+    }
+}
+
+#[cfg(test)]
+mod sqrt_digits_tests {
+    use bens_number_theory::digits::sqrt_digits;
+
+    #[test]
+    fn sqrt_two_test() {
+        assert_eq!(sqrt_digits(2, 10), vec![1, 4, 1, 4, 2, 1, 3, 5, 6, 2]);
+        let expected: &str = "14142135623730950488016887242096980785696718753769";
+        let digits: String = sqrt_digits(2, 50).iter().map(|d| d.to_string()).collect();
+        assert_eq!(digits, expected);
+    }
+
+    #[test]
+    fn perfect_square_test() {
+        assert_eq!(sqrt_digits(144, 6), vec![1, 2, 0, 0, 0, 0]);
+        assert_eq!(sqrt_digits(1, 3), vec![1, 0, 0]);
+        assert_eq!(sqrt_digits(0, 3), vec![0, 0, 0]);
+        assert_eq!(sqrt_digits(10000, 4), vec![1, 0, 0, 0]);
+    }
+
+    #[test]
+    fn sqrt_digits_test() {
+        assert_eq!(sqrt_digits(10, 6), vec![3, 1, 6, 2, 2, 7]);
+        assert_eq!(sqrt_digits(99, 5), vec![9, 9, 4, 9, 8]);
+        assert_eq!(sqrt_digits(2, 0), vec![]);
+        let u64_max_root: Vec<u8> = sqrt_digits(u64::MAX, 10);
+        assert_eq!(u64_max_root, vec![4, 2, 9, 4, 9, 6, 7, 2, 9, 5]);
+    }
+
+    #[test]
+    fn irrational_digit_sum_test() {
+        // Project Euler 80
+        let total: u64 = (1..=100_u64)
+            .filter(|&n| n.isqrt() * n.isqrt() != n)
+            .map(|n| sqrt_digits(n, 100).iter().map(|&d| d as u64).sum::<u64>())
+            .sum();
+        assert_eq!(total, 40886);
+    }
+}
+
+#[cfg(test)]
+mod automorphic_tests {
+    use bens_number_theory::digits::{is_automorphic, is_trimorphic};
+
+    #[test]
+    fn is_automorphic_test() {
+        let automorphic: Vec<u128> = (0..10000).filter(|&n| is_automorphic(n)).collect();
+        assert_eq!(automorphic, vec![0, 1, 5, 6, 25, 76, 376, 625, 9376]);
+    }
+
+    #[test]
+    fn is_trimorphic_test() {
+        for n in [1, 4, 5, 6, 9, 24, 25, 49, 51, 75, 76, 99] {
+            assert!(is_trimorphic(n), "{}", n);
+        }
+        let trimorphic: Vec<u128> = (1..100).filter(|&n| is_trimorphic(n)).collect();
+        assert_eq!(trimorphic, vec![1, 4, 5, 6, 9, 24, 25, 49, 51, 75, 76, 99]);
+    }
+
+    #[test]
+    fn automorphic_numbers_are_trimorphic_test() {
+        for n in 0..100000 {
+            if is_automorphic(n) {
+                assert!(is_trimorphic(n));
+            }
+        }
+    }
+
+    #[test]
+    fn large_trimorphic_test() {
+        // n^3 would overflow a u128 for all of these
+        assert!(is_automorphic(8212890625));
+        assert!(is_trimorphic(8212890625));
+        assert!(is_automorphic(81787109376));
+        assert!(is_trimorphic(10_u128.pow(38) - 1)); // 99...9 = -1 mod 10^38
+        assert!(!is_trimorphic(10_u128.pow(38) - 2));
+        assert!(!is_trimorphic(u128::MAX));
+    }
+}
+
+#[cfg(test)]
+mod factorial_digit_sum_tests {
+    use bens_number_theory::digits::factorial_digit_sum;
+
+    #[test]
+    fn factorial_digit_sum_test() {
+        assert_eq!(factorial_digit_sum(0), 1);
+        assert_eq!(factorial_digit_sum(5), 3); // 120
+        assert_eq!(factorial_digit_sum(10), 27);
+        assert_eq!(factorial_digit_sum(100), 648);
+        assert_eq!(factorial_digit_sum(1000), 10539);
+    }
+}
+
+#[cfg(test)]
+mod base_number_tests {
+    use bens_number_theory::digits::BaseNumber;
+
+    #[test]
+    fn base_7_add_test() {
+        // 66 + 1 = 100 in base 7
+        let a: BaseNumber = BaseNumber::from_u128(48, 7);
+        let b: BaseNumber = BaseNumber::from_u128(1, 7);
+        let sum: BaseNumber = a + b;
+        assert_eq!(sum.to_string(), "100");
+        assert_eq!(sum.to_u128(), 49);
+
+        let a: BaseNumber = BaseNumber::from_u128(2400, 7);
+        let b: BaseNumber = BaseNumber::from_u128(3456, 7);
+        assert_eq!(a.to_string(), "6666");
+        assert_eq!((a + b).to_u128(), 5856);
+    }
+
+    #[test]
+    fn base_16_round_trip_test() {
+        let n: BaseNumber = BaseNumber::from_u128(0xdeadbeef, 16);
+        assert_eq!(n.to_string(), "deadbeef");
+        assert_eq!(n.to_u128(), 3735928559);
+        assert_eq!(n.radix(), 16);
+        assert_eq!(BaseNumber::from_u128(u128::MAX, 16).to_u128(), u128::MAX);
+    }
+
+    #[test]
+    fn zero_test() {
+        let zero: BaseNumber = BaseNumber::from_u128(0, 10);
+        assert_eq!(zero.to_string(), "0");
+        assert_eq!(zero.digits(), &[0]);
+        assert_eq!(zero.clone() + zero, BaseNumber::from_u128(0, 10));
+    }
+
+    #[test]
+    fn add_matches_u128_test() {
+        for radix in 2..=36 {
+            for (a, b) in [(0, 5), (1, 1), (35, 1), (1000, 999), (123456, 654321)] {
+                let sum: BaseNumber =
+                    BaseNumber::from_u128(a, radix) + BaseNumber::from_u128(b, radix);
+                assert_eq!(sum, BaseNumber::from_u128(a + b, radix));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched_radix_test() {
+        let _ = BaseNumber::from_u128(10, 7) + BaseNumber::from_u128(10, 8);
+    }
+
+    #[test]
+    #[should_panic]
+    fn to_u128_overflow_test() {
+        let max: BaseNumber = BaseNumber::from_u128(u128::MAX, 2);
+        (max.clone() + max).to_u128();
+    }
+}
+
+#[cfg(test)]
+mod cyclic_number_tests {
+    use bens_number_theory::digits::{is_cyclic_number, rotations};
+
+    #[test]
+    fn rotations_test() {
+        assert_eq!(rotations(123), vec![123, 231, 312]);
+        assert_eq!(rotations(1193), vec![1193, 1931, 9311, 3119]);
+        assert_eq!(rotations(102), vec![102, 21, 210]);
+        assert_eq!(rotations(11), vec![11, 11]);
+        assert_eq!(rotations(0), vec![0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rotations_overflow_test() {
+        rotations(u64::MAX);
+    }
+
+    #[test]
+    fn is_cyclic_number_test() {
+        assert!(is_cyclic_number(142857));
+        for k in 1..=6 {
+            assert!(rotations(142857).contains(&(142857 * k)));
+        }
+    }
+
+    #[test]
+    fn not_cyclic_number_test() {
+        assert!(!is_cyclic_number(0));
+        assert!(!is_cyclic_number(1));
+        assert!(!is_cyclic_number(111));
+        assert!(!is_cyclic_number(285714)); // 285714 * 4 = 1142856
+        assert!(!is_cyclic_number(u64::MAX));
+        assert!((10..200_000).filter(|&n| is_cyclic_number(n)).eq([142857]));
+    }
+}
+
+#[cfg(test)]
+mod economical_tests {
+    use bens_number_theory::digits::{is_economical, is_equidigital, is_extravagant};
+
+    #[test]
+    fn is_economical_test() {
+        assert!(is_economical(125)); // 53
+        assert!(is_economical(128)); // 27
+        let economical: Vec<u64> = (1..1000).filter(|&n| is_economical(n)).collect();
+        assert_eq!(economical, vec![125, 128, 243, 256, 343, 512, 625, 729]);
+    }
+
+    #[test]
+    fn is_extravagant_test() {
+        assert!(is_extravagant(4)); // 22
+        assert!(is_extravagant(126)); // 2327
+        let extravagant: Vec<u64> = (1..40).filter(|&n| is_extravagant(n)).collect();
+        assert_eq!(
+            extravagant,
+            vec![4, 6, 8, 9, 12, 18, 20, 22, 24, 26, 28, 30, 33, 34, 36, 38, 39]
+        );
+    }
+
+    #[test]
+    fn is_equidigital_test() {
+        let equidigital: Vec<u64> = (1..30).filter(|&n| is_equidigital(n)).collect();
+        assert_eq!(
+            equidigital,
+            vec![1, 2, 3, 5, 7, 10, 11, 13, 14, 15, 16, 17, 19, 21, 23, 25, 27, 29]
+        );
+    }
+
+    #[test]
+    fn exactly_one_classification_test() {
+        for n in 1..5000 {
+            let count: usize = [is_economical(n), is_equidigital(n), is_extravagant(n)]
+                .iter()
+                .filter(|&&b| b)
+                .count();
+            assert_eq!(count, 1, "{}", n);
+        }
+    }
+}
+
+#[cfg(test)]
+mod palindrome_tests {
+    use bens_number_theory::digits::is_palindrome;
+
+    #[test]
+    fn is_palindrome_test() {
+        for n in [0, 7, 11, 101, 1221, 12321, 9009] {
+            assert!(is_palindrome(n), "{}", n);
+        }
+        for n in [10, 12, 100, 1231, 9008] {
+            assert!(!is_palindrome(n), "{}", n);
+        }
+        assert_eq!((0..200).filter(|&n| is_palindrome(n)).count(), 10 + 9 + 10);
+    }
+}
@@ -29,3 +29,209 @@ mod factorial_list_tests {
         )
     }
 }
+
+#[cfg(test)]
+mod factorial_trailing_zeros_tests {
+    use bens_number_theory::factorials::{factorial, factorial_trailing_zeros};
+    use num::BigInt;
+
+    #[test]
+    fn factorial_trailing_zeros_test() {
+        assert_eq!(factorial_trailing_zeros(0), 0);
+        assert_eq!(factorial_trailing_zeros(4), 0);
+        assert_eq!(factorial_trailing_zeros(5), 1);
+        assert_eq!(factorial_trailing_zeros(10), 2);
+        assert_eq!(factorial_trailing_zeros(25), 6);
+        assert_eq!(factorial_trailing_zeros(100), 24);
+        assert_eq!(factorial_trailing_zeros(1000000000), 249999998);
+    }
+
+    #[test]
+    fn factorial_trailing_zeros_matches_factorial_test() {
+        for n in 0..150_u64 {
+            let digits: String = factorial(BigInt::from(n)).to_string();
+            let zeros: usize = digits.len() - digits.trim_end_matches('0').len();
+            assert_eq!(factorial_trailing_zeros(n), zeros as u64);
+        }
+    }
+}
+
+#[cfg(test)]
+mod factorial_prime_exponent_tests {
+    use bens_number_theory::factorials::{factorial, factorial_prime_exponent};
+    use num::BigInt;
+
+    #[test]
+    fn factorial_prime_exponent_test() {
+        assert_eq!(factorial_prime_exponent(10, 2), 8);
+        assert_eq!(factorial_prime_exponent(10, 3), 4);
+        assert_eq!(factorial_prime_exponent(10, 5), 2);
+        assert_eq!(factorial_prime_exponent(10, 7), 1);
+        assert_eq!(factorial_prime_exponent(10, 11), 0);
+        assert_eq!(factorial_prime_exponent(100, 2), 97);
+    }
+
+    #[test]
+    fn factorial_prime_exponent_matches_factorial_test() {
+        for p in [2_u64, 3, 5, 7, 13] {
+            for n in 0..60_u64 {
+                let mut f: BigInt = factorial(BigInt::from(n));
+                let mut exponent: u64 = 0;
+                while &f % p == BigInt::from(0) {
+                    f /= p;
+                    exponent += 1;
+                }
+                assert_eq!(factorial_prime_exponent(n, p), exponent);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn factorial_prime_exponent_one_test() {
+        factorial_prime_exponent(10, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn factorial_prime_exponent_zero_test() {
+        factorial_prime_exponent(10, 0);
+    }
+}
+
+#[cfg(test)]
+mod factoradic_tests {
+    use bens_number_theory::factorials::{from_factoradic, to_factoradic};
+
+    #[test]
+    fn to_factoradic_test() {
+        assert_eq!(to_factoradic(0), vec![0]);
+        assert_eq!(to_factoradic(1), vec![1, 0]);
+        assert_eq!(to_factoradic(5), vec![2, 1, 0]);
+        assert_eq!(to_factoradic(6), vec![1, 0, 0, 0]);
+        assert_eq!(to_factoradic(463), vec![3, 4, 1, 0, 1, 0]);
+    }
+
+    #[test]
+    fn from_factoradic_test() {
+        assert_eq!(from_factoradic(&[]), 0);
+        assert_eq!(from_factoradic(&[0]), 0);
+        assert_eq!(from_factoradic(&[3, 4, 1, 0, 1, 0]), 463);
+        assert_eq!(from_factoradic(&[0, 0, 3, 4, 1, 0, 1, 0]), 463);
+    }
+
+    #[test]
+    fn factoradic_round_trip_test() {
+        for n in 0..10000 {
+            let digits: Vec<u64> = to_factoradic(n);
+            assert!(digits.iter().rev().enumerate().all(|(i, &d)| d <= i as u64));
+            assert_eq!(from_factoradic(&digits), n);
+        }
+        assert_eq!(from_factoradic(&to_factoradic(u64::MAX)), u64::MAX);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_factoradic_invalid_digit_test() {
+        from_factoradic(&[3, 1]);
+    }
+}
+
+#[cfg(test)]
+mod nth_permutation_tests {
+    use bens_number_theory::factorials::nth_permutation;
+
+    #[test]
+    fn nth_permutation_test() {
+        assert_eq!(nth_permutation(&[0, 1, 2], 0), vec![0, 1, 2]);
+        let all: Vec<Vec<i32>> = (0..6).map(|k| nth_permutation(&[0, 1, 2], k)).collect();
+        assert_eq!(
+            all,
+            vec![
+                vec![0, 1, 2],
+                vec![0, 2, 1],
+                vec![1, 0, 2],
+                vec![1, 2, 0],
+                vec![2, 0, 1],
+                vec![2, 1, 0]
+            ]
+        );
+    }
+
+    #[test]
+    fn nth_permutation_edge_test() {
+        assert_eq!(nth_permutation::<i32>(&[], 0), vec![]);
+        assert_eq!(nth_permutation(&["x"], 0), vec!["x"]);
+        // Project Euler 24
+        let digits: Vec<u32> = nth_permutation(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9], 999_999);
+        assert_eq!(digits, vec![2, 7, 8, 3, 9, 1, 5, 4, 6, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nth_permutation_too_large_test() {
+        nth_permutation(&[0, 1, 2], 6);
+    }
+}
+
+#[cfg(test)]
+mod permutation_rank_tests {
+    use bens_number_theory::factorials::{permutation_rank, permutation_unrank};
+    use std::collections::HashSet;
+
+    #[test]
+    fn permutation_rank_test() {
+        assert_eq!(permutation_rank(&[0, 1, 2]), 0);
+        assert_eq!(permutation_rank(&[0, 2, 1]), 1);
+        assert_eq!(permutation_rank(&[2, 1, 0]), 5);
+        assert_eq!(permutation_rank(&[]), 0);
+        assert_eq!(permutation_rank(&[0]), 0);
+        assert_eq!(permutation_rank(&[9, 8, 7, 6, 5, 4, 3, 2, 1, 0]), 3628799);
+    }
+
+    #[test]
+    fn permutation_unrank_test() {
+        assert_eq!(permutation_unrank(3, 0), vec![0, 1, 2]);
+        assert_eq!(permutation_unrank(3, 5), vec![2, 1, 0]);
+        assert_eq!(permutation_unrank(0, 0), vec![]);
+        // Project Euler 24
+        assert_eq!(
+            permutation_unrank(10, 999999),
+            vec![2, 7, 8, 3, 9, 1, 5, 4, 6, 0]
+        );
+    }
+
+    #[test]
+    fn rank_unrank_round_trip_test() {
+        let mut seen: HashSet<Vec<usize>> = HashSet::new();
+        let mut previous: Option<Vec<usize>> = None;
+        for rank in 0..24 {
+            let perm: Vec<usize> = permutation_unrank(4, rank);
+            assert_eq!(permutation_rank(&perm), rank);
+            if let Some(previous) = previous {
+                assert!(previous < perm);
+            }
+            previous = Some(perm.clone());
+            seen.insert(perm);
+        }
+        assert_eq!(seen.len(), 24);
+    }
+
+    #[test]
+    #[should_panic]
+    fn permutation_rank_repeated_test() {
+        permutation_rank(&[0, 1, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn permutation_rank_out_of_range_test() {
+        permutation_rank(&[0, 3, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn permutation_unrank_too_large_test() {
+        permutation_unrank(4, 24);
+    }
+}
@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod triangular_root_tests {
+    use bens_number_theory::figurate::triangular_root;
+
+    #[test]
+    fn triangular_root_test() {
+        assert_eq!(triangular_root(0), Some(0));
+        assert_eq!(triangular_root(1), Some(1));
+        assert_eq!(triangular_root(10), Some(4));
+        assert_eq!(triangular_root(11), None);
+        assert_eq!(triangular_root(2), None);
+    }
+
+    #[test]
+    fn triangular_root_inverse_test() {
+        for k in 0..2000_u64 {
+            let t: u64 = k * (k + 1) / 2;
+            assert_eq!(triangular_root(t), Some(k));
+            if k > 1 {
+                assert_eq!(triangular_root(t + 1), None);
+            }
+        }
+        let k: u64 = 4_000_000_000;
+        assert_eq!(triangular_root(k * (k + 1) / 2), Some(k));
+    }
+}
+
+#[cfg(test)]
+mod pentagonal_root_tests {
+    use bens_number_theory::figurate::pentagonal_root;
+
+    #[test]
+    fn pentagonal_root_test() {
+        assert_eq!(pentagonal_root(0), Some(0));
+        assert_eq!(pentagonal_root(1), Some(1));
+        assert_eq!(pentagonal_root(5), Some(2));
+        assert_eq!(pentagonal_root(22), Some(4));
+        assert_eq!(pentagonal_root(23), None);
+        // 24 * 2 + 1 = 49 is a square, but 7 isn't 5 mod 6
+        assert_eq!(pentagonal_root(2), None);
+    }
+
+    #[test]
+    fn pentagonal_root_inverse_test() {
+        let pentagonal: Vec<u64> = (0..200).map(|k| (3 * k * k - k) / 2).collect();
+        for n in 0..pentagonal[199] {
+            let expected: Option<u64> = pentagonal.iter().position(|&p| p == n).map(|k| k as u64);
+            assert_eq!(pentagonal_root(n), expected);
+        }
+    }
+}
+
+#[cfg(test)]
+mod hexagonal_root_tests {
+    use bens_number_theory::figurate::{hexagonal_root, pentagonal_root, triangular_root};
+
+    #[test]
+    fn hexagonal_root_test() {
+        assert_eq!(hexagonal_root(0), Some(0));
+        assert_eq!(hexagonal_root(1), Some(1));
+        assert_eq!(hexagonal_root(6), Some(2));
+        assert_eq!(hexagonal_root(28), Some(4));
+        assert_eq!(hexagonal_root(21), None);
+    }
+
+    #[test]
+    fn hexagonal_root_inverse_test() {
+        let hexagonal: Vec<u64> = (0..200).map(|k| 2 * k * k - k).collect();
+        for n in 0..hexagonal[199] {
+            let expected: Option<u64> = hexagonal.iter().position(|&h| h == n).map(|k| k as u64);
+            assert_eq!(hexagonal_root(n), expected);
+        }
+    }
+
+    #[test]
+    fn triangular_pentagonal_hexagonal_test() {
+        // Project Euler 45: T_285 = P_165 = H_143 = 40755, and the next one
+        let all: Vec<u64> = (1..100_000_u64)
+            .map(|k| k * (2 * k - 1))
+            .filter(|&h| pentagonal_root(h).is_some())
+            .collect();
+        assert_eq!(all, vec![1, 40755, 1533776805]);
+        assert_eq!(triangular_root(40755), Some(285));
+        assert_eq!(pentagonal_root(40755), Some(165));
+        assert_eq!(hexagonal_root(40755), Some(143));
+    }
+}
+
+#[cfg(test)]
+mod spiral_diagonal_sum_tests {
+    use bens_number_theory::figurate::spiral_diagonal_sum;
+
+    #[test]
+    fn spiral_diagonal_sum_test() {
+        assert_eq!(spiral_diagonal_sum(1), 1);
+        assert_eq!(spiral_diagonal_sum(3), 25);
+        assert_eq!(spiral_diagonal_sum(5), 101);
+        assert_eq!(spiral_diagonal_sum(7), 261);
+        // Project Euler 28
+        assert_eq!(spiral_diagonal_sum(1001), 669171001);
+    }
+
+    #[test]
+    fn spiral_diagonal_sum_corners_test() {
+        let mut sum: u64 = 1;
+        for n in (3..200_u64).step_by(2) {
+            sum += 4 * n * n - 6 * n + 6;
+            assert_eq!(spiral_diagonal_sum(n), sum);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn spiral_diagonal_sum_even_test() {
+        spiral_diagonal_sum(4);
+    }
+
+    #[test]
+    fn spiral_diagonal_sum_largest_test() {
+        assert_eq!(spiral_diagonal_sum(3024615), 18446719906291854181);
+    }
+
+    #[test]
+    #[should_panic]
+    fn spiral_diagonal_sum_overflow_test() {
+        spiral_diagonal_sum(3024617);
+    }
+
+    #[test]
+    #[should_panic]
+    fn spiral_diagonal_sum_max_test() {
+        spiral_diagonal_sum(u64::MAX);
+    }
+}
@@ -0,0 +1,227 @@
+#[cfg(test)]
+mod gcd_tests {
+    use bens_number_theory::gcd::gcd;
+
+    #[test]
+    fn gcd_test() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(18, 12), 6);
+        assert_eq!(gcd(17, 5), 1);
+        assert_eq!(gcd(1071_u64, 462_u64), 21);
+        assert_eq!(gcd(2_i128.pow(100), 2_i128.pow(60) * 3), 2_i128.pow(60));
+    }
+
+    #[test]
+    fn gcd_zero_test() {
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(0, 9), 9);
+        assert_eq!(gcd(9, 0), 9);
+    }
+
+    #[test]
+    fn gcd_negative_test() {
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(12, -18), 6);
+        assert_eq!(gcd(-12, -18), 6);
+        assert_eq!(gcd(-5, 0), 5);
+    }
+}
+
+#[cfg(test)]
+mod lcm_tests {
+    use bens_number_theory::gcd::{lcm, lcm_all, smallest_multiple};
+    use num::BigInt;
+    use std::str::FromStr;
+
+    #[test]
+    fn lcm_test() {
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(6, 4), 12);
+        assert_eq!(lcm(7_u64, 5_u64), 35);
+        assert_eq!(lcm(-4, -6), 12);
+        assert_eq!(lcm(0, 0), 0);
+        assert_eq!(lcm(9, 0), 0);
+    }
+
+    #[test]
+    fn lcm_all_test() {
+        assert_eq!(lcm_all(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]), 2520);
+        assert_eq!(lcm_all(&[12]), 12);
+        assert_eq!(lcm_all::<i32>(&[]), 1);
+    }
+
+    #[test]
+    fn smallest_multiple_test() {
+        assert_eq!(smallest_multiple(0), BigInt::from(1));
+        assert_eq!(smallest_multiple(1), BigInt::from(1));
+        assert_eq!(smallest_multiple(10), BigInt::from(2520));
+        assert_eq!(smallest_multiple(20), BigInt::from(232792560));
+        assert_eq!(
+            smallest_multiple(50),
+            BigInt::from_str("3099044504245996706400").unwrap()
+        );
+    }
+}
+
+#[cfg(test)]
+mod gcd_extended_tests {
+    use bens_number_theory::gcd::{gcd, gcd_extended};
+
+    #[test]
+    fn gcd_extended_test() {
+        assert_eq!(gcd_extended(240, 46), (2, -9, 47));
+        assert_eq!(gcd_extended(0, 0), (0, 1, 0));
+        assert_eq!(gcd_extended(0, 5), (5, 0, 1));
+        assert_eq!(gcd_extended(7, 0), (7, 1, 0));
+    }
+
+    #[test]
+    fn gcd_extended_bezout_test() {
+        for a in -30_i64..30 {
+            for b in -30_i64..30 {
+                let (g, x, y) = gcd_extended(a, b);
+                assert_eq!(g, gcd(a, b));
+                assert_eq!(a * x + b * y, g);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod binary_gcd_tests {
+    use bens_number_theory::gcd::{binary_gcd, gcd};
+
+    #[test]
+    fn binary_gcd_test() {
+        assert_eq!(binary_gcd(12, 18), 6);
+        assert_eq!(binary_gcd(1071, 462), 21);
+        assert_eq!(binary_gcd(17, 5), 1);
+        assert_eq!(binary_gcd(1 << 40, 3 << 20), 1 << 20);
+        assert_eq!(binary_gcd(u64::MAX, u64::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn binary_gcd_zero_test() {
+        assert_eq!(binary_gcd(0, 0), 0);
+        assert_eq!(binary_gcd(0, 9), 9);
+        assert_eq!(binary_gcd(9, 0), 9);
+    }
+
+    #[test]
+    fn binary_gcd_matches_gcd_test() {
+        for a in 0..200_u64 {
+            for b in 0..200_u64 {
+                assert_eq!(binary_gcd(a, b), gcd(a, b));
+            }
+        }
+        // a simple linear congruential generator, so the pairs are pseudo-random but reproducible
+        let mut state: u64 = 0x2545F4914F6CDD1D;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            state
+        };
+        for _ in 0..10000 {
+            let (a, b): (u64, u64) = (next(), next());
+            // also try pairs with a large common factor
+            let c: u64 = next() >> 40;
+            assert_eq!(binary_gcd(a, b), gcd(a, b));
+            assert_eq!(
+                binary_gcd((a >> 30) * c, (b >> 30) * c),
+                gcd((a >> 30) * c, (b >> 30) * c)
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod gcd_big_tests {
+    use bens_number_theory::gcd::{gcd, gcd_big, lcm, lcm_big};
+    use bens_number_theory::sequences::fibonacci_sequence;
+    use num::BigInt;
+
+    #[test]
+    fn gcd_big_test() {
+        assert_eq!(
+            gcd_big(&BigInt::from(12), &BigInt::from(18)),
+            BigInt::from(6)
+        );
+        assert_eq!(
+            gcd_big(&BigInt::from(-12), &BigInt::from(-18)),
+            BigInt::from(6)
+        );
+        assert_eq!(gcd_big(&BigInt::from(0), &BigInt::from(0)), BigInt::from(0));
+        assert_eq!(
+            gcd_big(&BigInt::from(0), &BigInt::from(-9)),
+            BigInt::from(9)
+        );
+        for a in -20_i64..20 {
+            for b in -20_i64..20 {
+                assert_eq!(
+                    gcd_big(&BigInt::from(a), &BigInt::from(b)),
+                    BigInt::from(gcd(a, b))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn lcm_big_test() {
+        assert_eq!(
+            lcm_big(&BigInt::from(4), &BigInt::from(6)),
+            BigInt::from(12)
+        );
+        assert_eq!(lcm_big(&BigInt::from(0), &BigInt::from(6)), BigInt::from(0));
+        for a in -20_i64..20 {
+            for b in -20_i64..20 {
+                assert_eq!(
+                    lcm_big(&BigInt::from(a), &BigInt::from(b)),
+                    BigInt::from(lcm(a, b))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn gcd_big_fibonacci_test() {
+        // gcd(F(m), F(n)) = F(gcd(m, n))
+        let fib: Vec<BigInt> = fibonacci_sequence(BigInt::from(1001));
+        for (m, n) in [(1000, 750), (999, 666), (997, 991), (960, 840), (512, 768)] {
+            assert_eq!(gcd_big(&fib[m], &fib[n]), fib[gcd(m, n)]);
+        }
+        // lcm(F(m), F(n)) * gcd(F(m), F(n)) = F(m) * F(n)
+        assert_eq!(
+            lcm_big(&fib[1000], &fib[750]) * gcd_big(&fib[1000], &fib[750]),
+            &fib[1000] * &fib[750]
+        );
+    }
+}
+
+#[cfg(test)]
+mod longest_coprime_run_tests {
+    use bens_number_theory::gcd::longest_coprime_run;
+
+    #[test]
+    fn all_coprime_test() {
+        assert_eq!(longest_coprime_run(&[2, 3, 5, 7, 11]), 5);
+        assert_eq!(longest_coprime_run(&[1, 1, 1]), 3);
+        // adjacent, not pairwise: 2 and 4 are never neighbours
+        assert_eq!(longest_coprime_run(&[2, 3, 4, 5]), 4);
+    }
+
+    #[test]
+    fn broken_run_test() {
+        assert_eq!(longest_coprime_run(&[6, 35, 11, 13, 26]), 4);
+        assert_eq!(longest_coprime_run(&[4, 6, 9, 10, 11, 13, 15]), 5);
+        assert_eq!(longest_coprime_run(&[2, 4, 6, 8]), 1);
+        assert_eq!(longest_coprime_run(&[5, 5]), 1);
+    }
+
+    #[test]
+    fn single_element_test() {
+        assert_eq!(longest_coprime_run(&[]), 0);
+        assert_eq!(longest_coprime_run(&[12]), 1);
+        assert_eq!(longest_coprime_run(&[0]), 1);
+    }
+}
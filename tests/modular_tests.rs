@@ -0,0 +1,318 @@
+#[cfg(test)]
+mod mod_pow_tests {
+    use bens_number_theory::modular::mod_pow;
+
+    #[test]
+    fn mod_pow_test() {
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(4, 13, 497), 445);
+        assert_eq!(mod_pow(7, 0, 13), 1);
+        assert_eq!(mod_pow(0, 5, 13), 0);
+        assert_eq!(mod_pow(10, 10, 1), 0);
+    }
+
+    #[test]
+    fn mod_pow_large_modulus_test() {
+        // Fermat's little theorem with the largest u64 prime
+        let p: u64 = 18446744073709551557;
+        assert_eq!(mod_pow(2, p - 1, p), 1);
+        assert_eq!(mod_pow(u64::MAX, 2, p), 58 * 58);
+    }
+}
+
+#[cfg(test)]
+mod multiplicative_order_tests {
+    use bens_number_theory::modular::{mod_pow, multiplicative_order};
+
+    #[test]
+    fn multiplicative_order_test() {
+        assert_eq!(multiplicative_order(2, 7), Some(3));
+        assert_eq!(multiplicative_order(3, 7), Some(6));
+        assert_eq!(multiplicative_order(10, 7), Some(6));
+        assert_eq!(multiplicative_order(1, 10), Some(1));
+        assert_eq!(multiplicative_order(3, 10), Some(4));
+        assert_eq!(multiplicative_order(2, 1), Some(1));
+    }
+
+    #[test]
+    fn multiplicative_order_not_coprime_test() {
+        assert_eq!(multiplicative_order(2, 6), None);
+        assert_eq!(multiplicative_order(0, 5), None);
+        assert_eq!(multiplicative_order(5, 0), None);
+    }
+
+    #[test]
+    fn multiplicative_order_brute_force_test() {
+        for n in 2..60_u64 {
+            for a in 1..n {
+                let expected: Option<u64> = (1..=n).find(|&k| mod_pow(a, k, n) == 1);
+                assert_eq!(multiplicative_order(a, n), expected, "{} mod {}", a, n);
+            }
+        }
+    }
+
+    #[test]
+    fn multiplicative_order_large_test() {
+        assert_eq!(multiplicative_order(2, 1_000_000_007), Some(500_000_003));
+        assert_eq!(multiplicative_order(10, 1_000_000_007), Some(1_000_000_006));
+        // phi(n) is above 2^32
+        assert_eq!(multiplicative_order(3, 4_294_967_311), Some(4_294_967_310));
+        assert_eq!(multiplicative_order(2, 4_294_967_311), Some(2_147_483_655));
+    }
+}
+
+#[cfg(test)]
+mod binomial_mod_prime_tests {
+    use bens_number_theory::factorials::factorial;
+    use bens_number_theory::modular::binomial_mod_prime;
+    use num::BigInt;
+
+    fn binomial(n: u64, k: u64) -> BigInt {
+        factorial(BigInt::from(n)) / (factorial(BigInt::from(k)) * factorial(BigInt::from(n - k)))
+    }
+
+    #[test]
+    fn binomial_mod_prime_test() {
+        assert_eq!(binomial_mod_prime(10, 3, 7), 1);
+        assert_eq!(binomial_mod_prime(10, 11, 7), 0);
+        assert_eq!(binomial_mod_prime(0, 0, 7), 1);
+        assert_eq!(binomial_mod_prime(7, 3, 7), 0);
+    }
+
+    #[test]
+    fn binomial_mod_prime_matches_big_test() {
+        for p in [2, 3, 5, 7, 11, 1000000007_u64] {
+            for n in 0..60 {
+                for k in 0..=n {
+                    let expected: BigInt = binomial(n, k) % p;
+                    assert_eq!(BigInt::from(binomial_mod_prime(n, k, p)), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn binomial_mod_prime_modulus_one_test() {
+        binomial_mod_prime(5, 2, 1);
+    }
+}
+
+#[cfg(test)]
+mod linear_congruence_tests {
+    use bens_number_theory::modular::solve_linear_congruence;
+
+    #[test]
+    fn no_solution_test() {
+        assert_eq!(solve_linear_congruence(2, 3, 4), Vec::<i64>::new());
+        assert_eq!(solve_linear_congruence(6, 5, 9), Vec::<i64>::new());
+        assert_eq!(solve_linear_congruence(0, 1, 5), Vec::<i64>::new());
+    }
+
+    #[test]
+    fn unique_solution_test() {
+        assert_eq!(solve_linear_congruence(3, 4, 7), vec![6]);
+        assert_eq!(solve_linear_congruence(-3, 4, 7), vec![1]);
+        assert_eq!(solve_linear_congruence(5, -1, 12), vec![7]);
+        assert_eq!(solve_linear_congruence(4, 9, 1), vec![0]);
+    }
+
+    #[test]
+    fn multiple_solutions_test() {
+        assert_eq!(solve_linear_congruence(14, 30, 100), vec![45, 95]);
+        assert_eq!(solve_linear_congruence(6, 9, 15), vec![4, 9, 14]);
+        assert_eq!(solve_linear_congruence(0, 0, 3), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn matches_brute_force_test() {
+        for n in 1..40_i64 {
+            for a in -n..n {
+                for b in 0..n {
+                    let expected: Vec<i64> = (0..n).filter(|x| (a * x - b) % n == 0).collect();
+                    assert_eq!(solve_linear_congruence(a, b, n as u64), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn large_modulus_test() {
+        let n: u64 = i64::MAX as u64;
+        let x: Vec<i64> = solve_linear_congruence(2, 1, n);
+        assert_eq!(x, vec![i64::MAX / 2 + 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_modulus_test() {
+        solve_linear_congruence(1, 1, 0);
+    }
+}
+
+#[cfg(test)]
+mod mod_mul_tests {
+    use bens_number_theory::modular::mod_mul;
+    use num::BigInt;
+
+    fn big_mod_mul(a: u128, b: u128, m: u128) -> u128 {
+        let product: BigInt = BigInt::from(a) * BigInt::from(b) % BigInt::from(m);
+        u128::try_from(product).unwrap()
+    }
+
+    #[test]
+    fn mod_mul_small_test() {
+        assert_eq!(mod_mul(6, 7, 10), 2);
+        assert_eq!(mod_mul(0, 123, 7), 0);
+        assert_eq!(mod_mul(123, 456, 1), 0);
+        assert_eq!(
+            mod_mul(u64::MAX as u128, u64::MAX as u128, 1_000_000_007),
+            big_mod_mul(u64::MAX as u128, u64::MAX as u128, 1_000_000_007)
+        );
+    }
+
+    #[test]
+    fn mod_mul_near_max_test() {
+        let values: [u128; 6] = [
+            u128::MAX,
+            u128::MAX - 1,
+            u128::MAX / 2,
+            u128::MAX / 3 + 12345,
+            1 << 127,
+            (1 << 64) + 1,
+        ];
+        let moduli: [u128; 5] = [
+            u128::MAX,
+            u128::MAX - 158, // 2^128 - 159 is prime
+            1 << 100,
+            (1 << 127) - 1,
+            1_000_000_007,
+        ];
+        for &a in values.iter() {
+            for &b in values.iter() {
+                for &m in moduli.iter() {
+                    assert_eq!(
+                        mod_mul(a, b, m),
+                        big_mod_mul(a, b, m),
+                        "{} * {} mod {}",
+                        a,
+                        b,
+                        m
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn mod_mul_zero_modulus_test() {
+        mod_mul(2, 3, 0);
+    }
+}
+
+#[cfg(test)]
+mod big_crt_tests {
+    use bens_number_theory::modular::{crt_big, mod_inverse_big};
+    use num::{BigInt, Integer, One};
+    use std::str::FromStr;
+
+    fn big(s: &str) -> BigInt {
+        BigInt::from_str(s).unwrap()
+    }
+
+    // 2^127 - 1 and 2^521 - 1 are prime, and 2^256 + 1 is coprime to both
+    fn large_moduli() -> Vec<BigInt> {
+        vec![
+            (BigInt::one() << 127) - 1,
+            (BigInt::one() << 521) - 1,
+            (BigInt::one() << 256) + 1,
+        ]
+    }
+
+    #[test]
+    fn mod_inverse_big_test() {
+        assert_eq!(
+            mod_inverse_big(&BigInt::from(3), &BigInt::from(11)),
+            Some(BigInt::from(4))
+        );
+        assert_eq!(
+            mod_inverse_big(&BigInt::from(-3), &BigInt::from(11)),
+            Some(BigInt::from(7))
+        );
+        assert_eq!(
+            mod_inverse_big(&BigInt::from(14), &BigInt::from(11)),
+            Some(BigInt::from(4))
+        );
+        assert_eq!(mod_inverse_big(&BigInt::from(6), &BigInt::from(9)), None);
+        assert_eq!(
+            mod_inverse_big(&BigInt::from(5), &BigInt::from(1)),
+            Some(BigInt::from(0))
+        );
+    }
+
+    #[test]
+    fn mod_inverse_big_large_test() {
+        let a: BigInt = big("123456789012345678901234567890123456789");
+        for m in large_moduli() {
+            let inverse: BigInt = mod_inverse_big(&a, &m).unwrap();
+            assert!((&a * &inverse).mod_floor(&m).is_one());
+            assert!(inverse < m);
+        }
+        // RSA-style: the private exponent inverts e = 65537 modulo phi
+        let p: BigInt = (BigInt::one() << 127) - 1;
+        let q: BigInt = (BigInt::one() << 89) - 1;
+        let phi: BigInt = (&p - 1) * (&q - 1);
+        let e: BigInt = BigInt::from(65537);
+        let d: BigInt = mod_inverse_big(&e, &phi).unwrap();
+        assert!((&e * &d).mod_floor(&phi).is_one());
+    }
+
+    #[test]
+    #[should_panic]
+    fn mod_inverse_big_zero_modulus_test() {
+        mod_inverse_big(&BigInt::from(3), &BigInt::from(0));
+    }
+
+    #[test]
+    fn crt_big_test() {
+        let residues: Vec<BigInt> = vec![2, 3, 2].into_iter().map(BigInt::from).collect();
+        let moduli: Vec<BigInt> = vec![3, 5, 7].into_iter().map(BigInt::from).collect();
+        assert_eq!(crt_big(&residues, &moduli), Some(BigInt::from(23)));
+        assert_eq!(crt_big(&[], &[]), Some(BigInt::from(0)));
+    }
+
+    #[test]
+    fn crt_big_non_coprime_test() {
+        // x = 3 (mod 4) and x = 5 (mod 6) agree mod 2, giving x = 11 (mod 12)
+        let moduli: Vec<BigInt> = vec![BigInt::from(4), BigInt::from(6)];
+        assert_eq!(
+            crt_big(&[BigInt::from(3), BigInt::from(5)], &moduli),
+            Some(BigInt::from(11))
+        );
+        // x = 1 (mod 4) and x = 2 (mod 6) disagree mod 2
+        assert_eq!(crt_big(&[BigInt::from(1), BigInt::from(2)], &moduli), None);
+    }
+
+    #[test]
+    fn crt_big_large_test() {
+        let moduli: Vec<BigInt> = large_moduli();
+        let residues: Vec<BigInt> = vec![
+            big("98765432109876543210"),
+            -big("314159265358979323846264338327950288419716939937510"),
+            big("271828182845904523536028747135266249775724709369995"),
+        ];
+        let x: BigInt = crt_big(&residues, &moduli).unwrap();
+        let product: BigInt = moduli.iter().product();
+        assert!(x >= BigInt::from(0) && x < product);
+        for (r, m) in residues.iter().zip(moduli.iter()) {
+            assert_eq!(x.mod_floor(m), r.mod_floor(m));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn crt_big_length_mismatch_test() {
+        crt_big(&[BigInt::from(1)], &[]);
+    }
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod partition_count_tests {
+    use bens_number_theory::partitions::partition_count;
+    use num::BigInt;
+    use std::str::FromStr;
+
+    #[test]
+    fn partition_count_test() {
+        let expected: Vec<u64> = vec![1, 1, 2, 3, 5, 7, 11, 15, 22, 30, 42, 56, 77, 101, 135];
+        for (n, &p) in expected.iter().enumerate() {
+            assert_eq!(partition_count(n as u64), BigInt::from(p));
+        }
+    }
+
+    #[test]
+    fn partition_count_large_test() {
+        assert_eq!(partition_count(100), BigInt::from(190569292));
+        assert_eq!(
+            partition_count(1000),
+            BigInt::from_str("24061467864032622473692149727991").unwrap()
+        );
+    }
+}
+
+#[cfg(test)]
+mod count_ways_to_make_tests {
+    use bens_number_theory::partitions::{count_ways_to_make, partition_count};
+    use num::BigInt;
+
+    #[test]
+    fn count_ways_to_make_test() {
+        assert_eq!(count_ways_to_make(5, &[1, 2]), BigInt::from(3));
+        assert_eq!(count_ways_to_make(0, &[1, 2]), BigInt::from(1));
+        assert_eq!(count_ways_to_make(0, &[]), BigInt::from(1));
+        assert_eq!(count_ways_to_make(4, &[]), BigInt::from(0));
+        assert_eq!(count_ways_to_make(3, &[2]), BigInt::from(0));
+        assert_eq!(count_ways_to_make(10, &[2, 5, 3, 6]), BigInt::from(5));
+        // Project Euler 31
+        assert_eq!(
+            count_ways_to_make(200, &[1, 2, 5, 10, 20, 50, 100, 200]),
+            BigInt::from(73682)
+        );
+    }
+
+    #[test]
+    fn matches_partition_count_test() {
+        for n in 0..120_u64 {
+            let parts: Vec<u64> = (1..=n).collect();
+            assert_eq!(count_ways_to_make(n, &parts), partition_count(n));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_denomination_test() {
+        count_ways_to_make(5, &[0, 1]);
+    }
+}
+
+#[cfg(test)]
+mod distinct_partition_count_tests {
+    use bens_number_theory::partitions::{count_ways_to_make, distinct_partition_count};
+    use num::BigInt;
+
+    #[test]
+    fn distinct_partition_count_test() {
+        let expected: Vec<u64> = vec![1, 1, 1, 2, 2, 3, 4, 5, 6, 8, 10, 12, 15, 18, 22, 27];
+        for (n, &q) in expected.iter().enumerate() {
+            assert_eq!(distinct_partition_count(n as u64), BigInt::from(q));
+        }
+        assert_eq!(distinct_partition_count(100), BigInt::from(444793));
+    }
+
+    #[test]
+    fn euler_partition_theorem_test() {
+        for n in 0..150_u64 {
+            let odd_parts: Vec<u64> = (1..=n).step_by(2).collect();
+            assert_eq!(
+                distinct_partition_count(n),
+                count_ways_to_make(n, &odd_parts)
+            );
+        }
+    }
+}
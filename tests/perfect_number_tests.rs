@@ -8,6 +8,32 @@ mod general_tests {
         assert_eq!(divisors(20), vec![1, 2, 4, 5, 10, 20]);
     }
 
+    #[test]
+    fn divisors_square_test() {
+        assert_eq!(divisors(4), vec![1, 2, 4]);
+        assert_eq!(divisors(9), vec![1, 3, 9]);
+        assert_eq!(divisors(16), vec![1, 2, 4, 8, 16]);
+        assert_eq!(divisors(36), vec![1, 2, 3, 4, 6, 9, 12, 18, 36]);
+        assert_eq!(divisors(1), vec![1]);
+    }
+
+    #[test]
+    fn divisors_small_and_prime_test() {
+        assert_eq!(divisors(2), vec![1, 2]);
+        assert_eq!(divisors(3), vec![1, 3]);
+        assert_eq!(divisors(97), vec![1, 97]);
+        assert_eq!(divisors(0), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn divisors_sorted_and_distinct_test() {
+        for n in 1..500_u32 {
+            let d: Vec<u32> = divisors(n);
+            assert!(d.windows(2).all(|w| w[0] < w[1]), "{}", n);
+            assert_eq!(d.len(), (1..=n).filter(|i| n % i == 0).count(), "{}", n);
+        }
+    }
+
     #[test]
     fn perfect_number_test() {
         assert!(is_perfect_number(6));
@@ -18,3 +44,573 @@ mod general_tests {
         assert!(!is_perfect_number(8130));
     }
 }
+
+#[cfg(test)]
+mod nth_perfect_number_tests {
+    use bens_number_theory::perfect_numbers::nth_perfect_number;
+    use num::BigInt;
+    use std::str::FromStr;
+
+    #[test]
+    fn nth_perfect_number_test() {
+        assert_eq!(nth_perfect_number(1), BigInt::from(6));
+        assert_eq!(nth_perfect_number(2), BigInt::from(28));
+        assert_eq!(nth_perfect_number(3), BigInt::from(496));
+        assert_eq!(nth_perfect_number(4), BigInt::from(8128));
+        assert_eq!(nth_perfect_number(5), BigInt::from(33550336));
+    }
+
+    #[test]
+    fn nth_perfect_number_large_test() {
+        assert_eq!(nth_perfect_number(6), BigInt::from(8589869056_u64));
+        assert_eq!(
+            nth_perfect_number(8),
+            BigInt::from_str("2305843008139952128").unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn nth_perfect_number_zero_test() {
+        nth_perfect_number(0);
+    }
+}
+
+#[cfg(test)]
+mod count_divisors_tests {
+    use bens_number_theory::perfect_numbers::count_divisors;
+
+    #[test]
+    fn count_divisors_test() {
+        assert_eq!(count_divisors(1), 1);
+        assert_eq!(count_divisors(2), 2);
+        assert_eq!(count_divisors(12), 6);
+        assert_eq!(count_divisors(360), 24);
+        assert_eq!(count_divisors(1024_u64), 11);
+        assert_eq!(count_divisors(0), 0);
+    }
+
+    #[test]
+    fn count_divisors_brute_force_test() {
+        for n in 1..500_u32 {
+            let expected: usize = (1..=n).filter(|d| n % d == 0).count();
+            assert_eq!(count_divisors(n) as usize, expected, "{}", n);
+        }
+    }
+}
+
+#[cfg(test)]
+mod highly_composite_tests {
+    use bens_number_theory::perfect_numbers::{count_divisors, highly_composite_below};
+
+    #[test]
+    fn highly_composite_test() {
+        assert_eq!(
+            highly_composite_below(100),
+            vec![1, 2, 4, 6, 12, 24, 36, 48, 60]
+        );
+        assert_eq!(highly_composite_below(1), vec![]);
+        assert_eq!(highly_composite_below(1000).last(), Some(&840));
+    }
+
+    #[test]
+    fn highly_composite_increasing_test() {
+        let nums: Vec<u64> = highly_composite_below(10000);
+        for pair in nums.windows(2) {
+            assert!(count_divisors(pair[0]) < count_divisors(pair[1]));
+        }
+    }
+}
+
+#[cfg(test)]
+mod divisors_from_spf_tests {
+    use bens_number_theory::perfect_numbers::{divisors, divisors_from_spf};
+    use bens_number_theory::primes::smallest_prime_factors;
+
+    #[test]
+    fn divisors_from_spf_test() {
+        let spf: Vec<usize> = smallest_prime_factors(1000);
+        assert_eq!(divisors_from_spf(0, &spf), vec![]);
+        assert_eq!(divisors_from_spf(1, &spf), vec![1]);
+        assert_eq!(divisors_from_spf(10, &spf), vec![1, 2, 5, 10]);
+        assert_eq!(divisors_from_spf(16, &spf), vec![1, 2, 4, 8, 16]);
+        assert_eq!(divisors_from_spf(360, &spf).len(), 24);
+    }
+
+    #[test]
+    fn divisors_from_spf_brute_force_test() {
+        let limit: usize = 2000;
+        let spf: Vec<usize> = smallest_prime_factors(limit);
+        for n in 1..=limit {
+            let expected: Vec<usize> = (1..=n).filter(|d| n % d == 0).collect();
+            assert_eq!(divisors_from_spf(n, &spf), expected);
+        }
+    }
+
+    #[test]
+    fn divisors_from_spf_matches_divisors_test() {
+        let limit: usize = 2000;
+        let spf: Vec<usize> = smallest_prime_factors(limit);
+        for n in 1..=limit {
+            assert_eq!(divisors_from_spf(n, &spf), divisors(n));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn divisors_from_spf_out_of_range_test() {
+        let spf: Vec<usize> = smallest_prime_factors(10);
+        divisors_from_spf(11, &spf);
+    }
+}
+
+#[cfg(test)]
+mod is_even_perfect_fast_tests {
+    use bens_number_theory::perfect_numbers::{is_even_perfect_fast, is_perfect_number};
+
+    #[test]
+    fn is_even_perfect_fast_matches_slow_test() {
+        for n in [6, 28, 496, 8128, 33550336_u64] {
+            assert!(is_perfect_number(n));
+            assert!(is_even_perfect_fast(n as u128));
+        }
+        assert!(!is_perfect_number(8130));
+        assert!(!is_even_perfect_fast(8130));
+    }
+
+    #[test]
+    fn is_even_perfect_fast_range_test() {
+        for n in 1..10000_u64 {
+            assert_eq!(
+                is_even_perfect_fast(n as u128),
+                is_perfect_number(n),
+                "{}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn is_even_perfect_fast_large_test() {
+        assert!(is_even_perfect_fast(8589869056));
+        assert!(is_even_perfect_fast(137438691328));
+        assert!(is_even_perfect_fast(2305843008139952128));
+        // 2^10 * (2^11 - 1), but 2^11 - 1 = 2047 is not prime
+        assert!(!is_even_perfect_fast(1024 * 2047));
+    }
+
+    #[test]
+    fn is_even_perfect_fast_odd_test() {
+        assert!(!is_even_perfect_fast(0));
+        assert!(!is_even_perfect_fast(1));
+        assert!(!is_even_perfect_fast(945));
+        assert!(!is_even_perfect_fast(u128::MAX));
+    }
+}
+
+#[cfg(test)]
+mod sigma_tests {
+    use bens_number_theory::perfect_numbers::{
+        count_divisors, is_perfect_number, sigma, sigma_proper,
+    };
+
+    #[test]
+    fn sigma_test() {
+        assert_eq!(sigma(1, 1), 1);
+        assert_eq!(sigma(6, 1), 12);
+        assert_eq!(sigma(28, 1), 56);
+        assert_eq!(sigma(13, 1), 14);
+        assert_eq!(sigma(12, 2), 210);
+        assert_eq!(sigma(0, 1), 0);
+    }
+
+    #[test]
+    fn sigma_brute_force_test() {
+        for k in 0..4 {
+            for n in 1..200_u64 {
+                let expected: u64 = (1..=n).filter(|d| n % d == 0).map(|d| d.pow(k)).sum();
+                assert_eq!(sigma(n, k), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn sigma_zero_counts_divisors_test() {
+        for n in 1..200 {
+            assert_eq!(sigma(n, 0), count_divisors(n));
+        }
+    }
+
+    #[test]
+    fn sigma_prime_test() {
+        for p in [2_u64, 3, 5, 97, 7919, 1_000_000_007] {
+            assert_eq!(sigma(p, 1), p + 1);
+            assert_eq!(sigma_proper(p), 1);
+        }
+    }
+
+    #[test]
+    fn sigma_proper_narrow_type_test() {
+        // sigma(120) = 360 doesn't fit in a u8, but s(120) = 240 does
+        assert_eq!(sigma_proper(120_u8), 240);
+        assert!(!is_perfect_number(120_u8));
+        // sigma(127) = 128 doesn't fit in an i8
+        assert_eq!(sigma_proper(127_i8), 1);
+        assert!(!is_perfect_number(127_i8));
+        for n in 1..=255_u8 {
+            let wide: u32 = n as u32;
+            let expected: u32 = (1..wide).filter(|d| wide.is_multiple_of(*d)).sum();
+            if expected <= 255 {
+                assert_eq!(sigma_proper(n) as u32, expected, "{}", n);
+            }
+            assert_eq!(is_perfect_number(n), expected == wide, "{}", n);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn sigma_proper_overflow_test() {
+        // s(240) = 504
+        sigma_proper(240_u8);
+    }
+
+    #[test]
+    fn sigma_proper_test() {
+        assert_eq!(sigma_proper(6), 6);
+        assert_eq!(sigma_proper(28), 28);
+        assert_eq!(sigma_proper(12), 16); // abundant
+        assert_eq!(sigma_proper(8), 7); // deficient
+        assert_eq!(sigma_proper(1), 0);
+        assert_eq!(sigma_proper(0), 0);
+        assert_eq!(sigma_proper(-6), 0);
+    }
+
+    #[test]
+    fn sigma_proper_brute_force_test() {
+        for n in 1..=100_u32 {
+            let expected: u32 = (1..n).filter(|d| n % d == 0).sum();
+            assert_eq!(sigma_proper(n), expected, "{}", n);
+            assert_eq!(is_perfect_number(n), expected == n, "{}", n);
+        }
+    }
+}
+
+#[cfg(test)]
+mod aliquot_sum_sieve_tests {
+    use bens_number_theory::perfect_numbers::{aliquot_sum_sieve, sigma};
+
+    #[test]
+    fn aliquot_sum_sieve_test() {
+        let s: Vec<u64> = aliquot_sum_sieve(300);
+        assert_eq!(s[220], 284);
+        assert_eq!(s[284], 220);
+        assert_eq!(s[6], 6);
+        assert_eq!(s[0], 0);
+        assert_eq!(s[1], 0);
+        assert_eq!(s[13], 1);
+        assert_eq!(aliquot_sum_sieve(0), vec![0]);
+    }
+
+    #[test]
+    fn aliquot_sum_sieve_matches_sigma_test() {
+        let s: Vec<u64> = aliquot_sum_sieve(5000);
+        for (i, &value) in s.iter().enumerate().skip(1) {
+            assert_eq!(value, sigma(i as u64, 1) - i as u64);
+        }
+    }
+}
+
+#[cfg(test)]
+mod arithmetic_number_tests {
+    use bens_number_theory::perfect_numbers::is_arithmetic_number;
+
+    #[test]
+    fn is_arithmetic_number_test() {
+        let arithmetic: Vec<u64> = (0..50).filter(|&n| is_arithmetic_number(n)).collect();
+        assert_eq!(
+            arithmetic,
+            vec![
+                1, 3, 5, 6, 7, 11, 13, 14, 15, 17, 19, 20, 21, 22, 23, 27, 29, 30, 31, 33, 35, 37,
+                38, 39, 41, 42, 43, 44, 45, 46, 47, 49
+            ]
+        );
+    }
+
+    #[test]
+    fn non_arithmetic_number_test() {
+        assert!(!is_arithmetic_number(0));
+        assert!(!is_arithmetic_number(2));
+        assert!(!is_arithmetic_number(4)); // (1 + 2 + 4) / 3
+        assert!(!is_arithmetic_number(12)); // 28 / 6
+    }
+
+    #[test]
+    fn odd_primes_are_arithmetic_test() {
+        for p in [3_u64, 101, 7919, 1_000_003] {
+            assert!(is_arithmetic_number(p));
+        }
+    }
+}
+
+#[cfg(test)]
+mod untouchable_tests {
+    use bens_number_theory::perfect_numbers::is_untouchable;
+
+    #[test]
+    fn is_untouchable_test() {
+        assert!(is_untouchable(2, 10));
+        assert!(is_untouchable(5, 16));
+        assert!(is_untouchable(52, 51 * 51));
+        assert!(is_untouchable(88, 87 * 87));
+    }
+
+    #[test]
+    fn touchable_test() {
+        assert!(!is_untouchable(0, 1)); // s(1) = 0
+        assert!(!is_untouchable(1, 2)); // s(2) = 1
+        assert!(!is_untouchable(3, 4)); // s(4) = 1 + 2
+        assert!(!is_untouchable(6, 6)); // s(6) = 6
+        assert!(!is_untouchable(284, 220)); // s(220) = 284
+    }
+
+    #[test]
+    fn limit_too_small_test() {
+        // s(9) = 4, but 9 is past the limit
+        assert!(is_untouchable(4, 8));
+        assert!(!is_untouchable(4, 9));
+    }
+
+    #[test]
+    fn first_untouchable_numbers_test() {
+        let untouchable: Vec<u64> = (2..150).filter(|&n| is_untouchable(n, n * n)).collect();
+        assert_eq!(untouchable, vec![2, 5, 52, 88, 96, 120, 124, 146]);
+    }
+}
+
+#[cfg(test)]
+mod sigma_iter_tests {
+    use bens_number_theory::perfect_numbers::{aliquot_sum_sieve, sigma, sigma_iter};
+
+    #[test]
+    fn sigma_iter_test() {
+        let s: Vec<u64> = sigma_iter(1).take(6).collect();
+        assert_eq!(s, vec![1, 3, 4, 7, 6, 12]);
+        let d: Vec<u64> = sigma_iter(0).take(6).collect();
+        assert_eq!(d, vec![1, 2, 2, 3, 2, 4]);
+    }
+
+    #[test]
+    fn sigma_iter_matches_sigma_test() {
+        for k in [0, 1] {
+            for (n, s) in (1..=20_u64).zip(sigma_iter(k)) {
+                assert_eq!(s, sigma(n, k), "sigma_{}({})", k, n);
+            }
+        }
+        for (n, s) in (1..=20_u64).zip(sigma_iter(3)) {
+            assert_eq!(s, sigma(n, 3));
+        }
+    }
+
+    #[test]
+    fn sigma_iter_perfect_numbers_test() {
+        // runs past several resizes of the sieve
+        let perfect: Vec<u64> = (1..)
+            .zip(sigma_iter(1))
+            .take(10000)
+            .filter(|&(n, s)| s == 2 * n)
+            .map(|(n, _)| n)
+            .collect();
+        assert_eq!(perfect, vec![6, 28, 496, 8128]);
+
+        let s: Vec<u64> = aliquot_sum_sieve(5000);
+        for (n, sigma_n) in (1..=5000).zip(sigma_iter(1)) {
+            assert_eq!(sigma_n, s[n] + n as u64);
+        }
+    }
+}
+
+#[cfg(test)]
+mod largest_divisor_tests {
+    use bens_number_theory::perfect_numbers::{
+        divisors_from_spf, k_largest_divisors, largest_proper_divisor,
+    };
+    use bens_number_theory::primes::smallest_prime_factors;
+
+    #[test]
+    fn largest_proper_divisor_prime_test() {
+        for p in [2_u64, 3, 13, 7919, 4294967291] {
+            assert_eq!(largest_proper_divisor(p), Some(1));
+        }
+    }
+
+    #[test]
+    fn largest_proper_divisor_prime_power_test() {
+        assert_eq!(largest_proper_divisor(1024), Some(512));
+        assert_eq!(largest_proper_divisor(243), Some(81));
+        assert_eq!(largest_proper_divisor(49_u8), Some(7));
+    }
+
+    #[test]
+    fn largest_proper_divisor_composite_test() {
+        assert_eq!(largest_proper_divisor(12), Some(6));
+        assert_eq!(largest_proper_divisor(35), Some(7));
+        assert_eq!(largest_proper_divisor(1001), Some(143));
+        assert_eq!(largest_proper_divisor(0), None);
+        assert_eq!(largest_proper_divisor(1), None);
+    }
+
+    #[test]
+    fn k_largest_divisors_test() {
+        assert_eq!(k_largest_divisors(12, 3), vec![12, 6, 4]);
+        assert_eq!(k_largest_divisors(12, 100), vec![12, 6, 4, 3, 2, 1]);
+        assert_eq!(k_largest_divisors(13, 5), vec![13, 1]);
+        assert_eq!(k_largest_divisors(64, 4), vec![64, 32, 16, 8]);
+        assert_eq!(k_largest_divisors(1, 3), vec![1]);
+        assert_eq!(k_largest_divisors(0, 3), vec![]);
+        assert_eq!(k_largest_divisors(12, 0), vec![]);
+    }
+
+    #[test]
+    fn k_largest_divisors_brute_force_test() {
+        let spf: Vec<usize> = smallest_prime_factors(1000);
+        for n in 1..=1000 {
+            let mut expected: Vec<usize> = divisors_from_spf(n, &spf);
+            expected.reverse();
+            for k in [1, 2, 5, expected.len()] {
+                assert_eq!(k_largest_divisors(n, k), expected[..k.min(expected.len())]);
+            }
+            assert_eq!(largest_proper_divisor(n), expected.get(1).copied());
+        }
+    }
+}
+
+#[cfg(test)]
+mod no_odd_perfect_tests {
+    use bens_number_theory::perfect_numbers::{is_perfect_number, no_odd_perfect_below};
+
+    #[test]
+    fn no_odd_perfect_below_test() {
+        assert!(no_odd_perfect_below(100000));
+        assert!(no_odd_perfect_below(0));
+        assert!(no_odd_perfect_below(1));
+        assert!(no_odd_perfect_below(2));
+    }
+
+    #[test]
+    fn matches_is_perfect_number_test() {
+        assert!(no_odd_perfect_below(2000));
+        assert!((1..2000_u64).step_by(2).all(|n| !is_perfect_number(n)));
+    }
+}
+
+#[cfg(test)]
+mod amicable_tests {
+    use bens_number_theory::perfect_numbers::{amicable_pairs_up_to, are_amicable};
+
+    #[test]
+    fn are_amicable_test() {
+        assert!(are_amicable(220, 284));
+        assert!(are_amicable(284, 220));
+        assert!(are_amicable(1184, 1210));
+        assert!(are_amicable(2620_u64, 2924));
+        assert!(!are_amicable(220, 285));
+        assert!(!are_amicable(0, 0));
+        assert!(!are_amicable(1, 0));
+    }
+
+    #[test]
+    fn perfect_not_amicable_test() {
+        for n in [6, 28, 496, 8128] {
+            assert!(!are_amicable(n, n));
+        }
+        assert!(amicable_pairs_up_to(10_000)
+            .iter()
+            .all(|&(a, b)| a < b && ![6, 28, 496, 8128].contains(&a)));
+    }
+
+    #[test]
+    fn amicable_pairs_up_to_test() {
+        // A063990
+        assert_eq!(
+            amicable_pairs_up_to(10_000_u64),
+            vec![
+                (220, 284),
+                (1184, 1210),
+                (2620, 2924),
+                (5020, 5564),
+                (6232, 6368)
+            ]
+        );
+        assert_eq!(amicable_pairs_up_to(284), vec![(220, 284)]);
+        assert_eq!(amicable_pairs_up_to(283), vec![]);
+        assert_eq!(amicable_pairs_up_to(0), vec![]);
+    }
+
+    #[test]
+    fn amicable_narrow_type_test() {
+        // s(240) = 504 doesn't fit in a u8
+        assert_eq!(amicable_pairs_up_to(250_u8), vec![]);
+        assert_eq!(amicable_pairs_up_to(u8::MAX), vec![]);
+        let narrow: Vec<(u32, u32)> = amicable_pairs_up_to(i16::MAX)
+            .into_iter()
+            .map(|(a, b)| (a as u32, b as u32))
+            .collect();
+        assert_eq!(narrow, amicable_pairs_up_to(i16::MAX as u32));
+        assert_eq!(narrow.len(), 8);
+        assert_eq!(amicable_pairs_up_to(300_i16), vec![(220, 284)]);
+        assert!(!are_amicable(240_u8, 248));
+        assert!(!are_amicable(127_i8, 1));
+    }
+
+    #[test]
+    fn amicable_pairs_up_to_consistent_test() {
+        for (a, b) in amicable_pairs_up_to(20_000_u32) {
+            assert!(are_amicable(a, b));
+        }
+    }
+}
+
+#[cfg(test)]
+mod generate_even_perfect_numbers_tests {
+    use bens_number_theory::perfect_numbers::{
+        generate_even_perfect_numbers, generate_even_perfect_numbers_big, nth_perfect_number,
+    };
+    use num::BigInt;
+
+    #[test]
+    fn generate_even_perfect_numbers_test() {
+        assert_eq!(generate_even_perfect_numbers(10), vec![6, 28, 496, 8128]);
+        assert_eq!(
+            generate_even_perfect_numbers(20_u64),
+            vec![6, 28, 496, 8128, 33550336, 8589869056, 137438691328]
+        );
+    }
+
+    #[test]
+    fn generate_even_perfect_numbers_overflow_test() {
+        // only the perfect numbers that fit are returned
+        assert_eq!(
+            generate_even_perfect_numbers(100_u16),
+            vec![6, 28, 496, 8128]
+        );
+        assert_eq!(generate_even_perfect_numbers(100_i32).len(), 5);
+        assert_eq!(generate_even_perfect_numbers(100_u64).len(), 8);
+        assert_eq!(
+            generate_even_perfect_numbers(100_u64)[7],
+            2305843008139952128
+        );
+        assert_eq!(generate_even_perfect_numbers(100_u128).len(), 9);
+    }
+
+    #[test]
+    fn generate_even_perfect_numbers_big_test() {
+        let perfect: Vec<BigInt> = generate_even_perfect_numbers_big(130);
+        // p = 2, 3, 5, 7, 13, 17, 19, 31, 61, 89, 107, 127
+        assert_eq!(perfect.len(), 12);
+        assert_eq!(perfect[4], BigInt::from(33550336));
+        assert_eq!(perfect[5], BigInt::from(8589869056_u64));
+        for (i, n) in perfect.iter().enumerate() {
+            assert_eq!(*n, nth_perfect_number(i + 1));
+        }
+    }
+}
@@ -325,3 +325,1226 @@ mod is_mersenne_prime_tests {
         assert!(!is_mersenne_prime((2_u128.pow(30)) - 1));
     }
 }
+
+#[cfg(test)]
+mod prime_factors_tests {
+    use bens_number_theory::primes::prime_factors;
+
+    #[test]
+    fn prime_factors_test() {
+        assert_eq!(prime_factors(360), vec![2, 2, 2, 3, 3, 5]);
+        assert_eq!(prime_factors(1024), vec![2; 10]);
+        assert_eq!(prime_factors(97), vec![97]);
+        assert_eq!(prime_factors(2147483647_u64), vec![2147483647]);
+    }
+
+    #[test]
+    fn prime_factors_small_test() {
+        assert_eq!(prime_factors(0), vec![]);
+        assert_eq!(prime_factors(1), vec![]);
+        assert_eq!(prime_factors(-12), vec![]);
+    }
+}
+
+#[cfg(test)]
+mod strong_probable_prime_tests {
+    use bens_number_theory::primes::{generate_primes, is_strong_probable_prime};
+    use num::BigInt;
+
+    #[test]
+    fn strong_probable_prime_matches_primes_test() {
+        let primes: Vec<i32> = generate_primes(1000);
+        for n in 2..1000 {
+            let expected: bool = primes.contains(&n);
+            let actual: bool = [2, 3, 5]
+                .iter()
+                .all(|&a| is_strong_probable_prime(&BigInt::from(n), &BigInt::from(a)));
+            assert_eq!(actual, expected, "{}", n);
+        }
+    }
+
+    #[test]
+    fn strong_pseudoprime_test() {
+        // the smallest strong pseudoprime to base 2
+        assert!(is_strong_probable_prime(
+            &BigInt::from(2047),
+            &BigInt::from(2)
+        ));
+        assert!(!is_strong_probable_prime(
+            &BigInt::from(2047),
+            &BigInt::from(3)
+        ));
+    }
+
+    #[test]
+    fn strong_probable_prime_small_test() {
+        assert!(!is_strong_probable_prime(
+            &BigInt::from(0),
+            &BigInt::from(2)
+        ));
+        assert!(!is_strong_probable_prime(
+            &BigInt::from(1),
+            &BigInt::from(2)
+        ));
+        assert!(is_strong_probable_prime(&BigInt::from(2), &BigInt::from(2)));
+        assert!(is_strong_probable_prime(&BigInt::from(3), &BigInt::from(3)));
+        assert!(!is_strong_probable_prime(
+            &BigInt::from(-7),
+            &BigInt::from(2)
+        ));
+    }
+}
+
+#[cfg(test)]
+mod wilson_prime_tests {
+    use bens_number_theory::primes::is_wilson_prime;
+
+    #[test]
+    fn wilson_primes_test() {
+        assert!(is_wilson_prime(5));
+        assert!(is_wilson_prime(13));
+        assert!(is_wilson_prime(563));
+    }
+
+    #[test]
+    fn non_wilson_primes_test() {
+        assert!(!is_wilson_prime(2));
+        assert!(!is_wilson_prime(3));
+        assert!(!is_wilson_prime(7));
+        assert!(!is_wilson_prime(11));
+        assert!(!is_wilson_prime(10007));
+    }
+
+    #[test]
+    fn non_prime_wilson_test() {
+        assert!(!is_wilson_prime(0));
+        assert!(!is_wilson_prime(1));
+        assert!(!is_wilson_prime(25));
+    }
+
+    #[test]
+    fn wilson_primes_below_1000_test() {
+        let w: Vec<u64> = (0..1000).filter(|&p| is_wilson_prime(p)).collect();
+        assert_eq!(w, vec![5, 13, 563]);
+    }
+}
+
+#[cfg(test)]
+mod are_primes_tests {
+    use bens_number_theory::primes::{are_primes, is_prime_lazy};
+
+    #[test]
+    fn are_primes_matches_lazy_test() {
+        let nums: Vec<u64> = vec![0, 1, 2, 3, 4, 17, 91, 97, 7919, 7921, 10007, 65536, 65537];
+        let expected: Vec<bool> = nums.iter().map(|&n| is_prime_lazy(n)).collect();
+        assert_eq!(are_primes(&nums), expected);
+    }
+
+    #[test]
+    fn are_primes_range_test() {
+        let nums: Vec<u64> = (0..2000).rev().collect();
+        let expected: Vec<bool> = nums.iter().map(|&n| is_prime_lazy(n)).collect();
+        assert_eq!(are_primes(&nums), expected);
+    }
+
+    #[test]
+    fn are_primes_large_test() {
+        // beyond the sieve limit, so these fall back to the strong probable prime test
+        let nums: Vec<u64> = vec![13, 2147483647, 2147483649, 18446744073709551557, u64::MAX];
+        assert_eq!(are_primes(&nums), vec![true, true, false, true, false]);
+    }
+
+    #[test]
+    fn are_primes_empty_test() {
+        assert_eq!(are_primes(&[]), vec![]);
+    }
+}
+
+#[cfg(test)]
+mod lucas_lehmer_tests {
+    use bens_number_theory::primes::lucas_lehmer;
+
+    #[test]
+    fn lucas_lehmer_test() {
+        let exponents: Vec<u32> = (0..130).filter(|&p| lucas_lehmer(p)).collect();
+        assert_eq!(
+            exponents,
+            vec![2, 3, 5, 7, 13, 17, 19, 31, 61, 89, 107, 127]
+        );
+    }
+}
+
+#[cfg(test)]
+mod sieve_of_atkin_tests {
+    use bens_number_theory::primes::{generate_primes, sieve_of_atkin};
+
+    #[test]
+    fn sieve_of_atkin_test() {
+        assert_eq!(sieve_of_atkin(10), vec![2, 3, 5, 7]);
+        assert_eq!(sieve_of_atkin(11), vec![2, 3, 5, 7]);
+        assert_eq!(sieve_of_atkin(12), vec![2, 3, 5, 7, 11]);
+    }
+
+    #[test]
+    fn sieve_of_atkin_small_limits_test() {
+        assert_eq!(sieve_of_atkin(0), vec![]);
+        assert_eq!(sieve_of_atkin(2), vec![]);
+        assert_eq!(sieve_of_atkin(3), vec![2]);
+        assert_eq!(sieve_of_atkin(4), vec![2, 3]);
+        assert_eq!(sieve_of_atkin(5), vec![2, 3]);
+        assert_eq!(sieve_of_atkin(6), vec![2, 3, 5]);
+    }
+
+    #[test]
+    fn sieve_of_atkin_matches_generate_primes_test() {
+        for limit in 4..1000 {
+            assert_eq!(sieve_of_atkin(limit), generate_primes(limit));
+        }
+        assert_eq!(sieve_of_atkin(10000), generate_primes(10000));
+    }
+
+    #[test]
+    fn sieve_of_atkin_large_test() {
+        assert_eq!(sieve_of_atkin(100000).len(), 9592);
+        assert_eq!(sieve_of_atkin(1000000).len(), 78498);
+    }
+}
+
+#[cfg(test)]
+mod smallest_prime_factors_tests {
+    use bens_number_theory::primes::{prime_factors, smallest_prime_factors};
+
+    #[test]
+    fn smallest_prime_factors_test() {
+        assert_eq!(smallest_prime_factors(0), vec![0]);
+        assert_eq!(smallest_prime_factors(1), vec![0, 1]);
+        assert_eq!(
+            smallest_prime_factors(12),
+            vec![0, 1, 2, 3, 2, 5, 2, 7, 2, 3, 2, 11, 2]
+        );
+    }
+
+    #[test]
+    fn smallest_prime_factors_matches_prime_factors_test() {
+        let spf: Vec<usize> = smallest_prime_factors(10000);
+        for (n, &p) in spf.iter().enumerate().skip(2) {
+            assert_eq!(p, prime_factors(n)[0]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod mersenne_prime_exponent_tests {
+    use bens_number_theory::primes::is_mersenne_prime_exponent;
+
+    #[test]
+    fn mersenne_prime_exponent_test() {
+        assert!(is_mersenne_prime_exponent(61));
+        assert!(is_mersenne_prime_exponent(89));
+        assert!(is_mersenne_prime_exponent(107));
+        assert!(is_mersenne_prime_exponent(521));
+        assert!(is_mersenne_prime_exponent(607));
+    }
+
+    #[test]
+    fn non_mersenne_prime_exponent_test() {
+        assert!(!is_mersenne_prime_exponent(0));
+        assert!(!is_mersenne_prime_exponent(1));
+        assert!(!is_mersenne_prime_exponent(11));
+        assert!(!is_mersenne_prime_exponent(101));
+        assert!(!is_mersenne_prime_exponent(523));
+    }
+}
+
+#[cfg(test)]
+mod primes_iterator_tests {
+    use bens_number_theory::primes::{for_each_prime_in_range, generate_primes, Primes};
+
+    #[test]
+    fn primes_first_25_test() {
+        let primes: Vec<u64> = Primes::new().take(25).collect();
+        assert_eq!(primes, generate_primes(100));
+    }
+
+    #[test]
+    fn primes_matches_generate_primes_test() {
+        let primes: Vec<u64> = Primes::new().take_while(|&p| p < 100000).collect();
+        assert_eq!(primes, generate_primes(100000));
+    }
+
+    #[test]
+    fn primes_starting_from_test() {
+        let primes: Vec<u64> = Primes::starting_from(100).take(5).collect();
+        assert_eq!(primes, vec![101, 103, 107, 109, 113]);
+        assert_eq!(Primes::starting_from(0).next(), Some(2));
+        assert_eq!(Primes::starting_from(3).next(), Some(3));
+        assert_eq!(Primes::starting_from(7920).next(), Some(7927));
+    }
+
+    #[test]
+    fn primes_starting_from_matches_sieve_test() {
+        let primes: Vec<u64> = Primes::starting_from(1_000_000).take(1000).collect();
+        assert_eq!(primes[..], generate_primes(1_100_000)[78498..79498]);
+    }
+
+    #[test]
+    fn primes_starting_from_large_test() {
+        // nothing below the start is generated, so this returns straight away
+        let mut expected: Vec<u64> = Vec::new();
+        for_each_prime_in_range(u64::MAX - 100, u64::MAX, |p| expected.push(p));
+        let primes: Vec<u64> = Primes::starting_from(u64::MAX - 100).collect();
+        assert_eq!(primes, expected);
+        assert_eq!(primes.last(), Some(&18446744073709551557));
+
+        let primes: Vec<u64> = Primes::starting_from(10_000_000_000).take(3).collect();
+        assert_eq!(primes, vec![10000000019, 10000000033, 10000000061]);
+        assert_eq!(
+            Primes::<u8>::typed_starting_from(250).collect::<Vec<u8>>(),
+            vec![251]
+        );
+        assert_eq!(
+            Primes::<i8>::typed_starting_from(120).collect::<Vec<i8>>(),
+            vec![127]
+        );
+    }
+
+    #[test]
+    fn primes_nth_test() {
+        assert_eq!(Primes::new().next(), Some(2));
+        assert_eq!(Primes::new().nth(999), Some(7919));
+        assert_eq!(Primes::<u64>::typed().next(), Some(2));
+    }
+
+    #[test]
+    fn primes_generic_test() {
+        let primes: Vec<u32> = Primes::<u32>::typed().take(25).collect();
+        assert_eq!(primes, generate_primes(100_u32));
+        let primes: Vec<i64> = Primes::typed().take_while(|&p: &i64| p < 10_000).collect();
+        assert_eq!(primes, generate_primes(10_000_i64));
+        assert_eq!(Primes::<i32>::typed_starting_from(-10).next(), Some(2));
+    }
+
+    #[test]
+    fn primes_overflow_test() {
+        // the iterator stops instead of overflowing
+        let primes: Vec<u8> = Primes::typed().collect();
+        assert_eq!(primes.len(), 54);
+        assert_eq!(primes.last(), Some(&251));
+        // 127 is the largest i8, so nothing can follow it
+        let primes: Vec<i8> = Primes::typed().collect();
+        assert_eq!(primes.len(), 31);
+        assert_eq!(primes.last(), Some(&127));
+        assert_eq!(Primes::<u16>::typed().count(), 6542);
+    }
+
+    #[test]
+    fn primes_unannotated_test() {
+        // no type annotations, so these only compile if `new` and `starting_from` give u64s
+        // calling a method on the result needs its type known, and 61 leading zeros means a u64
+        let p = Primes::new().nth(2).unwrap();
+        assert_eq!(p.leading_zeros(), 61);
+        // too large for the i32 an integer literal would default to
+        let q = Primes::starting_from(3_000_000_000).next().unwrap();
+        assert_eq!(q.leading_zeros(), 32);
+        let q = Primes::starting_from(100).next().unwrap();
+        assert_eq!(q.leading_zeros(), 57);
+        let r: Primes = Primes::default();
+        assert_eq!(r.take(3).sum::<u64>(), 10);
+    }
+
+    #[test]
+    fn primes_stored_in_struct_test() {
+        struct Holder {
+            primes: Primes,
+        }
+        let mut holder = Holder {
+            primes: Primes::default(),
+        };
+        assert_eq!(holder.primes.next(), Some(2));
+        assert_eq!(holder.primes.next(), Some(3));
+    }
+}
+
+#[cfg(test)]
+mod factor_tree_tests {
+    use bens_number_theory::primes::{factor_tree, is_prime_lazy, FactorNode};
+
+    #[test]
+    fn factor_tree_leaves_test() {
+        let tree: FactorNode = factor_tree(360);
+        assert_eq!(tree.value(), 360);
+        let leaves: Vec<u64> = tree.leaves();
+        assert_eq!(leaves.iter().product::<u64>(), 360);
+        assert!(leaves.iter().all(|&p| is_prime_lazy(p)));
+        let mut sorted: Vec<u64> = leaves.clone();
+        sorted.sort();
+        assert_eq!(sorted, vec![2, 2, 2, 3, 3, 5]);
+    }
+
+    #[test]
+    fn factor_tree_prime_test() {
+        assert_eq!(factor_tree(2), FactorNode::Leaf(2));
+        assert_eq!(factor_tree(97), FactorNode::Leaf(97));
+    }
+
+    #[test]
+    fn factor_tree_products_test() {
+        for n in 2..500_u64 {
+            let leaves: Vec<u64> = factor_tree(n).leaves();
+            assert_eq!(leaves.iter().product::<u64>(), n);
+            assert!(leaves.iter().all(|&p| is_prime_lazy(p)));
+        }
+    }
+
+    #[test]
+    fn factor_tree_pretty_print_test() {
+        assert_eq!(
+            factor_tree(12).pretty_print(),
+            "12\n├── 2\n└── 6\n    ├── 2\n    └── 3\n"
+        );
+        assert_eq!(
+            factor_tree(36).to_string(),
+            "36\n├── 2\n└── 18\n    ├── 2\n    └── 9\n        ├── 3\n        └── 3\n"
+        );
+    }
+
+    #[test]
+    fn factor_tree_small_factor_test() {
+        // 2p with p a large prime splits immediately instead of searching down from sqrt(2p)
+        let p: u64 = 4_611_686_018_427_387_847;
+        assert_eq!(
+            factor_tree(2 * p),
+            FactorNode::Branch {
+                value: 2 * p,
+                left: Box::new(FactorNode::Leaf(2)),
+                right: Box::new(FactorNode::Leaf(p)),
+            }
+        );
+        assert_eq!(
+            factor_tree(3 * 5 * 7 * 1_000_000_007).leaves(),
+            vec![3, 5, 7, 1_000_000_007]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn factor_tree_one_test() {
+        factor_tree(1);
+    }
+}
+
+#[cfg(test)]
+mod bpsw_tests {
+    use bens_number_theory::primes::{generate_primes, is_prime_bpsw};
+    use num::{BigInt, One};
+
+    #[test]
+    fn is_prime_bpsw_matches_primes_test() {
+        let primes: Vec<i32> = generate_primes(10000);
+        for n in -5..10000 {
+            assert_eq!(
+                is_prime_bpsw(&BigInt::from(n)),
+                primes.contains(&n),
+                "{}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn is_prime_bpsw_pseudoprime_test() {
+        // strong pseudoprimes to base 2, then strong Lucas pseudoprimes
+        for n in [
+            2047, 3277, 4033, 4681, 8321, 5459, 5777, 10877, 16109, 18971,
+        ] {
+            assert!(!is_prime_bpsw(&BigInt::from(n)), "{}", n);
+        }
+        // Carmichael numbers
+        for n in [561, 1105, 1729, 41041, 825265] {
+            assert!(!is_prime_bpsw(&BigInt::from(n)), "{}", n);
+        }
+    }
+
+    #[test]
+    fn is_prime_bpsw_large_test() {
+        let m127: BigInt = (BigInt::one() << 127) - 1;
+        let m89: BigInt = (BigInt::one() << 89) - 1;
+        assert!(is_prime_bpsw(&m127));
+        assert!(is_prime_bpsw(&BigInt::from(18446744073709551557_u64)));
+        assert!(!is_prime_bpsw(&(&m127 * &m89)));
+        assert!(!is_prime_bpsw(&((BigInt::one() << 128) + 1)));
+        assert!(!is_prime_bpsw(&(&m89 * &m89)));
+    }
+}
+
+#[cfg(all(test, feature = "rand"))]
+mod random_prime_tests {
+    use bens_number_theory::primes::{is_prime_bpsw, random_prime, random_prime_with};
+    use num::BigInt;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn random_prime_with_test() {
+        let mut rng: StdRng = StdRng::seed_from_u64(2024);
+        for bits in [2, 3, 8, 32, 64, 256] {
+            let p: BigInt = random_prime_with(bits, &mut rng);
+            assert_eq!(p.bits(), bits as u64);
+            assert!(is_prime_bpsw(&p));
+        }
+    }
+
+    #[test]
+    fn random_prime_with_reproducible_test() {
+        let p: BigInt = random_prime_with(128, &mut StdRng::seed_from_u64(7));
+        let q: BigInt = random_prime_with(128, &mut StdRng::seed_from_u64(7));
+        assert_eq!(p, q);
+    }
+
+    #[test]
+    fn random_prime_test() {
+        let p: BigInt = random_prime(100);
+        assert_eq!(p.bits(), 100);
+        assert!(is_prime_bpsw(&p));
+    }
+
+    #[test]
+    #[should_panic]
+    fn random_prime_one_bit_test() {
+        random_prime(1);
+    }
+}
+
+#[cfg(test)]
+mod consecutive_prime_sum_tests {
+    use bens_number_theory::primes::longest_consecutive_prime_sum_below;
+
+    #[test]
+    fn small_limits_test() {
+        assert_eq!(longest_consecutive_prime_sum_below(0), (0, 0));
+        assert_eq!(longest_consecutive_prime_sum_below(2), (0, 0));
+        assert_eq!(longest_consecutive_prime_sum_below(3), (2, 1));
+        assert_eq!(longest_consecutive_prime_sum_below(6), (5, 2));
+        assert_eq!(longest_consecutive_prime_sum_below(17), (5, 2));
+        assert_eq!(longest_consecutive_prime_sum_below(18), (17, 4));
+        assert_eq!(longest_consecutive_prime_sum_below(100), (41, 6));
+    }
+
+    #[test]
+    fn known_answers_test() {
+        assert_eq!(longest_consecutive_prime_sum_below(1000), (953, 21));
+        assert_eq!(
+            longest_consecutive_prime_sum_below(1_000_000),
+            (997651, 543)
+        );
+    }
+}
+
+#[cfg(test)]
+mod generate_primes_wheel_tests {
+    use bens_number_theory::primes::{generate_primes, generate_primes_wheel};
+
+    #[test]
+    fn generate_primes_wheel_test() {
+        assert_eq!(generate_primes_wheel(10), vec![2, 3, 5, 7]);
+        assert_eq!(
+            generate_primes_wheel(50),
+            vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47]
+        );
+    }
+
+    #[test]
+    fn generate_primes_wheel_small_limits_test() {
+        assert_eq!(generate_primes_wheel(0), vec![]);
+        assert_eq!(generate_primes_wheel(2), vec![]);
+        assert_eq!(generate_primes_wheel(3), vec![2]);
+        assert_eq!(generate_primes_wheel(6), vec![2, 3, 5]);
+        assert_eq!(generate_primes_wheel(8), vec![2, 3, 5, 7]);
+    }
+
+    #[test]
+    fn generate_primes_wheel_matches_generate_primes_test() {
+        for limit in 4..1000 {
+            assert_eq!(generate_primes_wheel(limit), generate_primes(limit));
+        }
+        assert_eq!(generate_primes_wheel(10000), generate_primes(10000));
+    }
+
+    #[test]
+    fn generate_primes_wheel_large_test() {
+        assert_eq!(generate_primes_wheel(1000000).len(), 78498);
+    }
+}
+
+#[cfg(test)]
+mod prime_signature_tests {
+    use bens_number_theory::perfect_numbers::count_divisors;
+    use bens_number_theory::primes::{numbers_with_signature, prime_signature};
+
+    #[test]
+    fn prime_signature_test() {
+        assert_eq!(prime_signature(12), prime_signature(18));
+        assert_eq!(prime_signature(12), vec![1, 2]);
+        assert_eq!(prime_signature(360), vec![1, 2, 3]);
+        assert_eq!(prime_signature(1024), vec![10]);
+        assert_eq!(prime_signature(1), vec![]);
+        assert_eq!(prime_signature(0), vec![]);
+    }
+
+    #[test]
+    fn prime_signature_of_primes_test() {
+        for p in [2, 3, 5, 97, 7919] {
+            assert_eq!(prime_signature(p), vec![1]);
+        }
+    }
+
+    #[test]
+    fn numbers_with_signature_test() {
+        assert_eq!(
+            numbers_with_signature(&[1], 30),
+            vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+        );
+        assert_eq!(
+            numbers_with_signature(&[1, 1], 40),
+            vec![6, 10, 14, 15, 21, 22, 26, 33, 34, 35, 38, 39]
+        );
+        assert_eq!(numbers_with_signature(&[], 10), vec![1]);
+        assert_eq!(
+            numbers_with_signature(&[1, 2], 50),
+            numbers_with_signature(&[2, 1], 50)
+        );
+    }
+
+    #[test]
+    fn same_signature_same_divisor_count_test() {
+        for n in numbers_with_signature(&[1, 1, 2], 1000) {
+            assert_eq!(count_divisors(n), 12);
+        }
+    }
+}
+
+#[cfg(test)]
+mod prime_factorization_tests {
+    use bens_number_theory::primes::prime_factorization;
+
+    #[test]
+    fn prime_factorization_test() {
+        assert_eq!(prime_factorization(360), vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(prime_factorization(1), vec![]);
+        assert_eq!(prime_factorization(0), vec![]);
+    }
+
+    #[test]
+    fn prime_factorization_power_of_two_test() {
+        for k in 1..64_u32 {
+            assert_eq!(prime_factorization(2_u64.pow(k)), vec![(2, k)]);
+        }
+    }
+
+    #[test]
+    fn prime_factorization_semiprime_test() {
+        assert_eq!(prime_factorization(15), vec![(3, 1), (5, 1)]);
+        assert_eq!(prime_factorization(49), vec![(7, 2)]);
+        assert_eq!(
+            prime_factorization(65_521_u64 * 65_537),
+            vec![(65_521, 1), (65_537, 1)]
+        );
+    }
+
+    #[test]
+    fn prime_factorization_prime_test() {
+        for p in [2_u64, 3, 97, 7919, 1_000_000_007] {
+            assert_eq!(prime_factorization(p), vec![(p, 1)]);
+        }
+    }
+
+    #[test]
+    fn prime_factorization_product_test() {
+        for n in 2..2000_u64 {
+            let factorization: Vec<(u64, u32)> = prime_factorization(n);
+            assert!(factorization.windows(2).all(|w| w[0].0 < w[1].0));
+            let product: u64 = factorization.iter().map(|&(p, e)| p.pow(e)).product();
+            assert_eq!(product, n);
+        }
+    }
+}
+
+#[cfg(test)]
+mod omega_tests {
+    use bens_number_theory::primes::{big_omega, omega};
+
+    #[test]
+    fn omega_test() {
+        assert_eq!(omega(360), 3);
+        assert_eq!(big_omega(360), 6);
+        assert_eq!(omega(1), 0);
+        assert_eq!(big_omega(1), 0);
+    }
+
+    #[test]
+    fn prime_test() {
+        for p in [2_u64, 3, 101, 1_000_003] {
+            assert_eq!(omega(p), 1);
+            assert_eq!(big_omega(p), 1);
+        }
+    }
+
+    #[test]
+    fn prime_power_test() {
+        assert_eq!(omega(1024), 1);
+        assert_eq!(big_omega(1024), 10);
+        assert_eq!(omega(3_u64.pow(20)), 1);
+        assert_eq!(big_omega(3_u64.pow(20)), 20);
+    }
+
+    #[test]
+    fn squarefree_test() {
+        for n in [6, 30, 210, 2310, 4199] {
+            assert_eq!(omega(n), big_omega(n));
+        }
+        assert_eq!(omega(2310), 5);
+    }
+}
+
+#[cfg(test)]
+mod liouville_tests {
+    use bens_number_theory::primes::{liouville, liouville_summatory};
+
+    #[test]
+    fn liouville_test() {
+        assert_eq!(liouville(1), 1);
+        assert_eq!(liouville(2), -1);
+        assert_eq!(liouville(6), 1);
+        assert_eq!(liouville(12), -1);
+        let expected: Vec<i8> = vec![1, -1, -1, 1, -1, 1, -1, -1, 1, 1, -1, -1];
+        let actual: Vec<i8> = (1..=12).map(liouville).collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn liouville_summatory_test() {
+        assert_eq!(liouville_summatory(0), 0);
+        assert_eq!(liouville_summatory(1), 1);
+        assert_eq!(liouville_summatory(8), -2);
+        assert_eq!(liouville_summatory(10), 0);
+        let direct: i64 = (1..=1000).map(|k| liouville(k) as i64).sum();
+        assert_eq!(liouville_summatory(1000), direct);
+    }
+
+    #[test]
+    fn polya_conjecture_test() {
+        // L(n) <= 0 for every 2 <= n < 906150257
+        for n in 2..3000 {
+            assert!(liouville_summatory(n) <= 0, "{}", n);
+        }
+        assert!(liouville_summatory(100000) < 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn liouville_zero_test() {
+        liouville(0);
+    }
+}
+
+#[cfg(test)]
+mod radical_tests {
+    use bens_number_theory::primes::radical;
+
+    #[test]
+    fn radical_test() {
+        assert_eq!(radical(360), 30);
+        assert_eq!(radical(1), 1);
+        assert_eq!(radical(97), 97);
+        assert_eq!(radical(1_000_000_007_u64), 1_000_000_007);
+    }
+
+    #[test]
+    fn prime_power_test() {
+        assert_eq!(radical(8), 2);
+        assert_eq!(radical(3_u64.pow(30)), 3);
+        assert_eq!(radical(2 * 2 * 7 * 7 * 7), 14);
+    }
+
+    #[test]
+    fn squarefree_test() {
+        for n in [2, 6, 30, 210, 2310, 4199] {
+            assert_eq!(radical(n), n);
+        }
+    }
+}
+
+#[cfg(test)]
+mod powerful_tests {
+    use bens_number_theory::primes::{is_powerful, powerful_numbers_below, radical};
+
+    #[test]
+    fn is_powerful_test() {
+        for n in [1, 4, 8, 9, 16, 25, 27, 32, 36, 72, 1000] {
+            assert!(is_powerful(n), "{}", n);
+        }
+        for n in [0, 2, 6, 12, 18, 20, 1001] {
+            assert!(!is_powerful(n), "{}", n);
+        }
+    }
+
+    #[test]
+    fn powerful_numbers_below_test() {
+        assert_eq!(powerful_numbers_below(1), vec![]);
+        assert_eq!(
+            powerful_numbers_below(130),
+            vec![1, 4, 8, 9, 16, 25, 27, 32, 36, 49, 64, 72, 81, 100, 108, 121, 125, 128]
+        );
+    }
+
+    #[test]
+    fn powerful_radical_test() {
+        for n in 1..2000_u64 {
+            let r: u64 = radical(n);
+            assert_eq!(is_powerful(n), n.is_multiple_of(r * r));
+        }
+    }
+}
+
+#[cfg(test)]
+mod achilles_tests {
+    use bens_number_theory::primes::{is_achilles, is_perfect_power};
+
+    #[test]
+    fn is_perfect_power_test() {
+        let powers: Vec<u64> = (0..130).filter(|&n| is_perfect_power(n)).collect();
+        assert_eq!(
+            powers,
+            vec![1, 4, 8, 9, 16, 25, 27, 32, 36, 49, 64, 81, 100, 121, 125, 128]
+        );
+        assert!(is_perfect_power(2_u64.pow(63)));
+        assert!(is_perfect_power(6_u64.pow(10)));
+        assert!(!is_perfect_power(2_u64.pow(5) * 3_u64.pow(3)));
+    }
+
+    #[test]
+    fn is_achilles_test() {
+        for n in [72, 108, 200, 288, 392, 432, 500] {
+            assert!(is_achilles(n), "{}", n);
+        }
+    }
+
+    #[test]
+    fn not_achilles_test() {
+        // perfect powers
+        for n in [1, 4, 8, 36, 216, 1024] {
+            assert!(!is_achilles(n), "{}", n);
+        }
+        // not powerful
+        for n in [0, 2, 12, 18, 70] {
+            assert!(!is_achilles(n), "{}", n);
+        }
+    }
+
+    #[test]
+    fn achilles_numbers_below_1000_test() {
+        let achilles: Vec<u64> = (0..1000).filter(|&n| is_achilles(n)).collect();
+        assert_eq!(
+            achilles,
+            vec![72, 108, 200, 288, 392, 432, 500, 648, 675, 800, 864, 968, 972]
+        );
+    }
+}
+
+#[cfg(test)]
+mod sieve_tests {
+    use bens_number_theory::primes::{generate_primes, is_prime, Sieve};
+
+    #[test]
+    fn sieve_is_prime_test() {
+        let sieve: Sieve = Sieve::new(10000);
+        for n in 0..=10000_u64 {
+            assert_eq!(sieve.is_prime(n), is_prime(n), "{}", n);
+        }
+    }
+
+    #[test]
+    fn sieve_primes_test() {
+        let primes: Vec<u64> = Sieve::new(100_000).primes().collect();
+        assert_eq!(primes, generate_primes(100_000_u64));
+        assert_eq!(Sieve::new(1).primes().count(), 0);
+        assert_eq!(Sieve::new(2).primes().collect::<Vec<u64>>(), vec![2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sieve_past_limit_test() {
+        Sieve::new(100).is_prime(101);
+    }
+}
+
+#[cfg(all(test, feature = "rand"))]
+mod probable_prime_tests {
+    use bens_number_theory::primes::{
+        is_prime, is_prime_miller_rabin, is_probable_prime, is_probable_prime_with,
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+
+    // 2^61 - 1 and the largest prime below 2^64
+    const P: u128 = (1 << 61) - 1;
+    const Q: u128 = 18446744073709551557;
+
+    #[test]
+    fn known_primes_test() {
+        let mut rng: StdRng = StdRng::seed_from_u64(1237);
+        for p in [
+            (1 << 89) - 1,
+            (1 << 107) - 1,
+            (1 << 127) - 1,
+            u128::MAX - 158,
+        ] {
+            for rounds in [1, 5, 20] {
+                assert!(is_probable_prime_with(p, rounds, &mut rng), "{}", p);
+            }
+            assert!(is_probable_prime(p, 10));
+        }
+    }
+
+    #[test]
+    fn known_composites_test() {
+        let mut rng: StdRng = StdRng::seed_from_u64(1237);
+        for n in [P * Q, Q * Q, u128::MAX, 1 << 100, ((1 << 64) + 1) * 3] {
+            for rounds in [5, 20] {
+                assert!(!is_probable_prime_with(n, rounds, &mut rng), "{}", n);
+            }
+            assert!(!is_probable_prime(n, 10));
+        }
+    }
+
+    #[test]
+    fn small_numbers_are_deterministic_test() {
+        // rounds is ignored below 3.18 * 10^23, so even 0 rounds is exact
+        for n in 0..5000_u64 {
+            assert_eq!(is_probable_prime(n as u128, 0), is_prime(n), "{}", n);
+        }
+        // Carmichael numbers and a strong pseudoprime to bases 2 through 23
+        for n in [561, 41041, 825265, 321197185, 3825123056546413051] {
+            assert!(!is_probable_prime(n, 0), "{}", n);
+        }
+        assert!(is_probable_prime(P, 0));
+        assert!(is_probable_prime(Q, 0));
+        // the smallest prime above 2^64
+        assert!(is_probable_prime((1 << 64) + 13, 0));
+        assert!(!is_probable_prime((1 << 64) + 1, 0));
+    }
+
+    #[test]
+    fn matches_is_prime_miller_rabin_test() {
+        let mut rng: StdRng = StdRng::seed_from_u64(1237);
+        for n in (1_u128 << 64) - 1000..(1 << 64) + 1000 {
+            assert_eq!(
+                is_probable_prime_with(n, 0, &mut rng),
+                is_prime_miller_rabin(n, 0),
+                "{}",
+                n
+            );
+        }
+        // the smallest strong pseudoprime to the first twelve primes needs a random base to catch
+        let n: u128 = 318665857834031151167461;
+        assert!(is_probable_prime_with(n, 0, &mut rng));
+        assert!(!is_probable_prime_with(n, 5, &mut rng));
+    }
+
+    #[test]
+    fn seeded_reproducible_test() {
+        let run = |seed: u64| -> Vec<bool> {
+            let mut rng: StdRng = StdRng::seed_from_u64(seed);
+            (0..50_u128)
+                .map(|k| is_probable_prime_with(P * Q + 2 * k, 1, &mut rng))
+                .collect()
+        };
+        assert_eq!(run(99), run(99));
+    }
+}
+
+#[cfg(test)]
+mod count_primes_below_where_tests {
+    use bens_number_theory::digits::is_palindrome;
+    use bens_number_theory::primes::{count_primes_below_where, generate_primes};
+
+    #[test]
+    fn palindromic_primes_test() {
+        assert_eq!(count_primes_below_where(1000, is_palindrome), 20);
+        assert_eq!(count_primes_below_where(100, is_palindrome), 5);
+        // 101 isn't below 101
+        assert_eq!(count_primes_below_where(101, is_palindrome), 5);
+        assert_eq!(count_primes_below_where(102, is_palindrome), 6);
+    }
+
+    #[test]
+    fn no_even_digit_test() {
+        let no_even_digit = |p: u64| p.to_string().chars().all(|c| "13579".contains(c));
+        let expected: usize = generate_primes(10000_u64)
+            .into_iter()
+            .filter(|&p| no_even_digit(p))
+            .count();
+        assert_eq!(count_primes_below_where(10000, no_even_digit), expected);
+    }
+
+    #[test]
+    fn count_all_primes_test() {
+        assert_eq!(count_primes_below_where(0, |_| true), 0);
+        assert_eq!(count_primes_below_where(2, |_| true), 0);
+        assert_eq!(count_primes_below_where(3, |_| true), 1);
+        assert_eq!(count_primes_below_where(1_000_000, |_| true), 78498);
+    }
+}
+
+#[cfg(test)]
+mod lucas_carmichael_tests {
+    use bens_number_theory::primes::{is_lucas_carmichael, is_squarefree};
+
+    #[test]
+    fn is_squarefree_test() {
+        let squarefree: Vec<u64> = (0..20).filter(|&n| is_squarefree(n)).collect();
+        assert_eq!(
+            squarefree,
+            vec![1, 2, 3, 5, 6, 7, 10, 11, 13, 14, 15, 17, 19]
+        );
+        assert!(is_squarefree(2_u128 * 3 * 5 * 7 * 11 * 13));
+        assert!(!is_squarefree(1_000_000_007_u64 * 1_000_000_007));
+    }
+
+    #[test]
+    fn is_lucas_carmichael_test() {
+        for n in [399, 935, 2015, 2915, 4991, 5719] {
+            assert!(is_lucas_carmichael(n), "{}", n);
+        }
+        let below_10000: Vec<u64> = (0..10000).filter(|&n| is_lucas_carmichael(n)).collect();
+        assert_eq!(
+            below_10000,
+            vec![399, 935, 2015, 2915, 4991, 5719, 7055, 8855]
+        );
+    }
+
+    #[test]
+    fn not_lucas_carmichael_test() {
+        // primes
+        for p in [3, 7, 19, 7919] {
+            assert!(!is_lucas_carmichael(p));
+        }
+        // Carmichael numbers
+        for n in [561, 1105, 1729] {
+            assert!(!is_lucas_carmichael(n));
+        }
+        assert!(!is_lucas_carmichael(0));
+        assert!(!is_lucas_carmichael(1));
+        assert!(!is_lucas_carmichael(798)); // 2 * 399
+    }
+}
+
+#[cfg(test)]
+mod miller_rabin_tests {
+    use bens_number_theory::primes::{is_prime, is_prime_miller_rabin};
+
+    // exponents p where 2^p - 1 is prime, up to 127
+    const MERSENNE_EXPONENTS: [u32; 12] = [2, 3, 5, 7, 13, 17, 19, 31, 61, 89, 107, 127];
+
+    #[test]
+    fn mersenne_primes_test() {
+        for p in MERSENNE_EXPONENTS {
+            assert!(is_prime_miller_rabin(p, 10), "{}", p);
+            assert!(is_prime_miller_rabin((1_u128 << p) - 1, 10), "2^{} - 1", p);
+        }
+        // 2^11 - 1 = 2047 is a strong pseudoprime to base 2
+        for p in [
+            11, 23, 29, 37, 41, 43, 47, 53, 59, 67, 71, 101, 103, 109, 113,
+        ] {
+            assert!(!is_prime_miller_rabin((1_u128 << p) - 1, 10), "2^{} - 1", p);
+        }
+    }
+
+    #[test]
+    fn known_large_primes_test() {
+        assert!(is_prime_miller_rabin(2147483647, 5));
+        assert!(is_prime_miller_rabin(1_000_000_007_u64, 5));
+        assert!(is_prime_miller_rabin(18446744073709551557_u64, 5));
+        assert!(is_prime_miller_rabin(u128::MAX - 158, 5)); // 2^128 - 159
+        assert!(!is_prime_miller_rabin(u128::MAX, 5));
+        // 2^64 + 1 = 274177 * 67280421310721
+        assert!(!is_prime_miller_rabin((1_u128 << 64) + 1, 5));
+        // a strong pseudoprime to all twelve fixed bases, so only the random bases catch it
+        assert!(!is_prime_miller_rabin(318665857834031151167461_u128, 5));
+    }
+
+    #[test]
+    fn edge_cases_test() {
+        assert!(!is_prime_miller_rabin(0, 5));
+        assert!(!is_prime_miller_rabin(1, 5));
+        assert!(is_prime_miller_rabin(2, 5));
+        assert!(is_prime_miller_rabin(3, 0));
+        assert!(!is_prime_miller_rabin(4, 5));
+        assert!(!is_prime_miller_rabin(1 << 20, 5));
+        assert!(!is_prime_miller_rabin(-2, 5));
+        assert!(!is_prime_miller_rabin(i128::MIN, 5));
+        assert!(is_prime_miller_rabin(251_u8, 5));
+        assert!(is_prime_miller_rabin(i64::MAX as i128 - 24, 5)); // 2^63 - 25
+    }
+
+    #[test]
+    fn matches_is_prime_test() {
+        for n in 0..10000_u64 {
+            assert_eq!(is_prime_miller_rabin(n, 1), is_prime(n), "{}", n);
+        }
+        // Carmichael numbers
+        for n in [561_u64, 1105, 1729, 2465, 2821, 6601, 8911] {
+            assert!(!is_prime_miller_rabin(n, 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod exact_cutoff_tests {
+    use bens_number_theory::primes::{is_prime, is_prime_list};
+
+    #[test]
+    fn is_prime_past_f32_mantissa_test() {
+        // 2^24 + 43, where f32 can no longer represent every integer
+        assert!(is_prime(16777259_u64));
+        assert!(is_prime(16777259_i64));
+        // 4093^2 and 4093 * 4099, whose smallest factors sit right at sqrt(n)
+        assert!(!is_prime(16752649_u64));
+        assert!(!is_prime(16777207_u64));
+        assert!(!is_prime(16777259_u64 * 3));
+    }
+
+    #[test]
+    fn is_prime_list_exact_cutoff_test() {
+        // n = 134217931^2. In f32, sqrt(n) rounds down to 134217920, which is below the prime 134217929,
+        // so a float cutoff would stop there and call n prime.
+        let n: u64 = 18014453001920761;
+        assert!(!is_prime_list(n, vec![134217929, 134217931]));
+    }
+
+    #[test]
+    fn is_prime_list_no_overflow_test() {
+        // 17 * 17 doesn't fit in a u8, but the cutoff is still reached at 17
+        assert!(is_prime_list(251_u8, vec![2, 3, 5, 7, 11, 13, 17]));
+        assert!(!is_prime_list(253_u8, vec![2, 3, 5, 7, 11, 13, 17]));
+        assert!(is_prime_list(127_i8, vec![2, 3, 5, 7, 11, 13]));
+    }
+
+    #[test]
+    fn is_prime_small_test() {
+        let primes: Vec<u32> = (0..100_u32).filter(|&n| is_prime(n)).collect();
+        assert_eq!(
+            primes,
+            vec![
+                2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79,
+                83, 89, 97
+            ]
+        );
+        assert!(is_prime(251_u8));
+        assert!(!is_prime(255_u8));
+    }
+}
+
+#[cfg(test)]
+mod prime_range_tests {
+    use bens_number_theory::primes::{
+        for_each_prime_in_range, generate_primes, is_prime_miller_rabin, primes_in_range,
+    };
+
+    #[test]
+    fn for_each_prime_in_range_matches_primes_in_range_test() {
+        for (low, high) in [(0, 100), (50, 60), (97, 97), (1000, 5000), (30000, 70000)] {
+            let mut collected: Vec<u64> = Vec::new();
+            for_each_prime_in_range(low, high, |p| collected.push(p));
+            assert_eq!(collected, primes_in_range(low, high));
+        }
+    }
+
+    #[test]
+    fn primes_in_range_test() {
+        let primes: Vec<u64> = generate_primes(100_001);
+        // the last window spans several segments
+        for (low, high) in [
+            (0, 1),
+            (2, 2),
+            (0, 100),
+            (90, 110),
+            (1000, 5000),
+            (0, 100_000),
+        ] {
+            let expected: Vec<u64> = primes
+                .iter()
+                .copied()
+                .filter(|&p| low <= p && p <= high)
+                .collect();
+            assert_eq!(primes_in_range(low, high), expected);
+        }
+        assert!(primes_in_range(10, 5).is_empty());
+        assert!(primes_in_range(24, 28).is_empty());
+    }
+
+    #[test]
+    fn primes_in_range_large_test() {
+        // far too large to sieve from zero
+        let low: u64 = 100_000_000_000_000;
+        let high: u64 = low + 5000;
+        let expected: Vec<u64> = (low..=high)
+            .filter(|&n| is_prime_miller_rabin(n, 0))
+            .collect();
+        assert_eq!(primes_in_range(low, high), expected);
+    }
+}
+
+#[cfg(test)]
+mod prime_counting_tests {
+    use bens_number_theory::primes::{generate_primes, nth_prime, prime_pi, Primes};
+
+    #[test]
+    fn nth_prime_test() {
+        assert_eq!(nth_prime::<u64>(0), 2);
+        assert_eq!(nth_prime::<u64>(1), 3);
+        assert_eq!(nth_prime::<u64>(5), 13);
+        // the 1000th prime
+        assert_eq!(nth_prime::<u64>(999), 7919);
+        assert_eq!(nth_prime::<u32>(9591), 99991);
+    }
+
+    #[test]
+    fn nth_prime_matches_primes_test() {
+        for (n, p) in Primes::new().take(2000).enumerate() {
+            assert_eq!(nth_prime::<u64>(n), p, "{}", n);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn nth_prime_overflow_test() {
+        // the 55th prime is 257
+        nth_prime::<u8>(54);
+    }
+
+    #[test]
+    fn prime_pi_test() {
+        assert_eq!(prime_pi(10), 4);
+        assert_eq!(prime_pi(100000), 9592);
+        assert_eq!(prime_pi(0), 0);
+        assert_eq!(prime_pi(1), 0);
+        assert_eq!(prime_pi(2), 1);
+        assert_eq!(prime_pi(-10), 0);
+        assert_eq!(prime_pi(1_000_000_u64), 78498);
+    }
+
+    #[test]
+    fn prime_pi_matches_generate_primes_test() {
+        let primes: Vec<u64> = generate_primes(2000);
+        for x in 0..2000_u64 {
+            let expected: usize = primes.iter().filter(|&&p| p <= x).count();
+            assert_eq!(prime_pi(x), expected, "{}", x);
+        }
+    }
+
+    #[test]
+    fn prime_pi_inverts_nth_prime_test() {
+        for n in 0..500 {
+            assert_eq!(prime_pi(nth_prime::<u64>(n)), n + 1);
+        }
+    }
+}
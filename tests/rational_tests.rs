@@ -0,0 +1,344 @@
+#[cfg(test)]
+mod stern_brocot_tests {
+    use bens_number_theory::rationals::{stern_brocot_from_path, stern_brocot_path};
+    use num::{BigInt, BigRational};
+
+    fn ratio(n: i64, d: i64) -> BigRational {
+        BigRational::new(BigInt::from(n), BigInt::from(d))
+    }
+
+    #[test]
+    fn stern_brocot_path_test() {
+        assert_eq!(stern_brocot_path(&ratio(1, 1)), Vec::<char>::new());
+        assert_eq!(stern_brocot_path(&ratio(3, 2)), vec!['R', 'L']);
+        assert_eq!(stern_brocot_path(&ratio(1, 3)), vec!['L', 'L']);
+        assert_eq!(stern_brocot_path(&ratio(5, 1)), vec!['R', 'R', 'R', 'R']);
+    }
+
+    #[test]
+    fn stern_brocot_from_path_test() {
+        assert_eq!(stern_brocot_from_path(&[]), ratio(1, 1));
+        assert_eq!(stern_brocot_from_path(&['R', 'L']), ratio(3, 2));
+        assert_eq!(stern_brocot_from_path(&['L', 'R', 'R']), ratio(3, 4));
+    }
+
+    #[test]
+    fn stern_brocot_round_trip_test() {
+        let fractions: Vec<BigRational> = vec![
+            ratio(1, 1),
+            ratio(3, 2),
+            ratio(2, 3),
+            ratio(22, 7),
+            ratio(355, 113),
+            ratio(1, 100),
+            ratio(144, 89),
+        ];
+        for r in fractions {
+            assert_eq!(stern_brocot_from_path(&stern_brocot_path(&r)), r);
+        }
+    }
+
+    #[test]
+    fn stern_brocot_run_lengths_test() {
+        // 355/113 = [3; 7, 16], so the path is R^3 L^7 R^15
+        let path: Vec<char> = stern_brocot_path(&ratio(355, 113));
+        assert_eq!(path.len(), 3 + 7 + 15);
+        assert!(path[..3].iter().all(|&c| c == 'R'));
+        assert!(path[3..10].iter().all(|&c| c == 'L'));
+        assert!(path[10..].iter().all(|&c| c == 'R'));
+    }
+
+    #[test]
+    #[should_panic]
+    fn stern_brocot_path_negative_test() {
+        stern_brocot_path(&ratio(-1, 2));
+    }
+}
+
+#[cfg(test)]
+mod rational_sign_tests {
+    use bens_number_theory::{rational_abs, rational_is_negative, rational_signum};
+    use num::{BigInt, BigRational};
+
+    fn raw(n: i64, d: i64) -> BigRational {
+        BigRational::new_raw(BigInt::from(n), BigInt::from(d))
+    }
+
+    #[test]
+    fn rational_abs_test() {
+        let half: BigRational = BigRational::new(BigInt::from(1), BigInt::from(2));
+        assert_eq!(rational_abs(&raw(1, -2)), half);
+        assert_eq!(rational_abs(&raw(-1, 2)), half);
+        assert_eq!(rational_abs(&raw(-1, -2)), half);
+        assert_eq!(rational_abs(&raw(1, 2)), half);
+        assert_eq!(rational_abs(&raw(-4, 8)), half);
+        assert_eq!(rational_abs(&raw(0, -5)), raw(0, 1));
+    }
+
+    #[test]
+    fn rational_signum_test() {
+        assert_eq!(rational_signum(&raw(1, -2)), -1);
+        assert_eq!(rational_signum(&raw(-1, 2)), -1);
+        assert_eq!(rational_signum(&raw(-1, -2)), 1);
+        assert_eq!(rational_signum(&raw(1, 2)), 1);
+        assert_eq!(rational_signum(&raw(0, -2)), 0);
+    }
+
+    #[test]
+    fn rational_is_negative_test() {
+        assert!(rational_is_negative(&raw(1, -2)));
+        assert!(rational_is_negative(&raw(-1, 2)));
+        assert!(!rational_is_negative(&raw(-1, -2)));
+        assert!(!rational_is_negative(&raw(0, -2)));
+    }
+
+    #[test]
+    fn rational_sign_consistency_test() {
+        // both placements of the sign represent the same number
+        assert_eq!(rational_signum(&raw(1, -2)), rational_signum(&raw(-1, 2)));
+        assert_eq!(rational_abs(&raw(1, -2)), rational_abs(&raw(-1, 2)));
+        assert_eq!(
+            rational_is_negative(&raw(1, -2)),
+            rational_is_negative(&raw(-1, 2))
+        );
+    }
+}
+
+#[cfg(test)]
+mod rational_rounding_tests {
+    use bens_number_theory::rationals::{ceil_rational, floor_rational, round_rational};
+    use num::{BigInt, BigRational};
+
+    fn ratio(n: i64, d: i64) -> BigRational {
+        BigRational::new(BigInt::from(n), BigInt::from(d))
+    }
+
+    #[test]
+    fn round_rational_test() {
+        assert_eq!(round_rational(&ratio(7, 2)), BigInt::from(4));
+        assert_eq!(round_rational(&ratio(-7, 2)), BigInt::from(-4));
+        assert_eq!(round_rational(&ratio(10, 3)), BigInt::from(3));
+        assert_eq!(round_rational(&ratio(-11, 3)), BigInt::from(-4));
+        assert_eq!(round_rational(&ratio(5, 1)), BigInt::from(5));
+    }
+
+    #[test]
+    fn floor_rational_test() {
+        assert_eq!(floor_rational(&ratio(7, 2)), BigInt::from(3));
+        assert_eq!(floor_rational(&ratio(-7, 2)), BigInt::from(-4));
+        assert_eq!(floor_rational(&ratio(6, 2)), BigInt::from(3));
+    }
+
+    #[test]
+    fn ceil_rational_test() {
+        assert_eq!(ceil_rational(&ratio(7, 2)), BigInt::from(4));
+        assert_eq!(ceil_rational(&ratio(-7, 2)), BigInt::from(-3));
+        assert_eq!(ceil_rational(&ratio(6, 2)), BigInt::from(3));
+    }
+}
+
+#[cfg(test)]
+mod simplify_fraction_tests {
+    use bens_number_theory::rationals::simplify_fraction;
+
+    #[test]
+    fn simplify_fraction_test() {
+        assert_eq!(simplify_fraction(6, 8), (3, 4));
+        assert_eq!(simplify_fraction(7, 13), (7, 13));
+        assert_eq!(simplify_fraction(100, 10), (10, 1));
+        assert_eq!(simplify_fraction(2_i128.pow(100), 2_i128.pow(101)), (1, 2));
+    }
+
+    #[test]
+    fn sign_normalization_test() {
+        assert_eq!(simplify_fraction(4, -8), (-1, 2));
+        assert_eq!(simplify_fraction(-4, 8), (-1, 2));
+        assert_eq!(simplify_fraction(-4, -8), (1, 2));
+    }
+
+    #[test]
+    fn zero_numerator_test() {
+        assert_eq!(simplify_fraction(0, 5), (0, 1));
+        assert_eq!(simplify_fraction(0, -5), (0, 1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_denominator_test() {
+        simplify_fraction(1, 0);
+    }
+}
+
+#[cfg(test)]
+mod convergents_tests {
+    use bens_number_theory::rationals::convergents;
+    use num::{BigInt, BigRational};
+
+    fn ratio(n: i64, d: i64) -> BigRational {
+        BigRational::new(BigInt::from(n), BigInt::from(d))
+    }
+
+    fn coeffs(a: &[i64]) -> Vec<BigInt> {
+        a.iter().map(|&x| BigInt::from(x)).collect()
+    }
+
+    #[test]
+    fn convergents_test() {
+        assert_eq!(convergents(&[]), vec![]);
+        assert_eq!(convergents(&coeffs(&[3])), vec![ratio(3, 1)]);
+        // 415/93 = [4; 2, 6, 7]
+        assert_eq!(
+            convergents(&coeffs(&[4, 2, 6, 7])),
+            vec![ratio(4, 1), ratio(9, 2), ratio(58, 13), ratio(415, 93)]
+        );
+    }
+
+    #[test]
+    fn golden_ratio_convergents_test() {
+        // [1; 1, 1, ...] gives ratios of consecutive Fibonacci numbers
+        let c: Vec<BigRational> = convergents(&coeffs(&[1; 8]));
+        assert_eq!(c[7], ratio(34, 21));
+    }
+}
+
+#[cfg(test)]
+mod decimal_period_tests {
+    use bens_number_theory::rationals::{decimal_period, longest_recurring_cycle_below};
+
+    #[test]
+    fn decimal_period_test() {
+        assert_eq!(decimal_period(1, 7), (vec![], vec![1, 4, 2, 8, 5, 7]));
+        assert_eq!(decimal_period(1, 6), (vec![1], vec![6]));
+        assert_eq!(decimal_period(1, 3), (vec![], vec![3]));
+        assert_eq!(decimal_period(2, 3), (vec![], vec![6]));
+        assert_eq!(decimal_period(1, 12), (vec![0, 8], vec![3]));
+        assert_eq!(
+            decimal_period(1, 81),
+            (vec![], vec![0, 1, 2, 3, 4, 5, 6, 7, 9])
+        );
+    }
+
+    #[test]
+    fn terminating_decimal_test() {
+        assert_eq!(decimal_period(1, 8), (vec![1, 2, 5], vec![]));
+        assert_eq!(decimal_period(7, 7), (vec![], vec![]));
+        assert_eq!(decimal_period(0, 5), (vec![], vec![]));
+        assert_eq!(decimal_period(22, 7), decimal_period(1, 7));
+    }
+
+    #[test]
+    fn decimal_period_lengths_test() {
+        // 1/97 has the full period of 96
+        let (prefix, repeating) = decimal_period(1, 97);
+        assert!(prefix.is_empty());
+        assert_eq!(repeating.len(), 96);
+        assert_eq!(repeating[..6], [0, 1, 0, 3, 0, 9]);
+        // 1/(2^k * 3) = 0.0...(3) with k digits before the block
+        for k in 1..20 {
+            let (prefix, repeating) = decimal_period(1, 3 << k);
+            assert_eq!(prefix.len(), k);
+            assert_eq!(repeating.len(), 1);
+        }
+        // 1/(2^6 * 5^2 * 7) has six digits before the block of 142857
+        let (prefix, repeating) = decimal_period(1, 64 * 25 * 7);
+        assert_eq!(prefix.len(), 6);
+        assert_eq!(repeating.len(), 6);
+    }
+
+    #[test]
+    fn decimal_period_unreduced_test() {
+        // 21/98 = 3/14, which has the same expansion
+        assert_eq!(decimal_period(21, 98), decimal_period(3, 14));
+        assert_eq!(decimal_period(3, 14), (vec![2], vec![1, 4, 2, 8, 5, 7]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn decimal_period_zero_test() {
+        decimal_period(1, 0);
+    }
+
+    #[test]
+    fn longest_recurring_cycle_test() {
+        assert_eq!(longest_recurring_cycle_below(1000), Some(983));
+        assert_eq!(longest_recurring_cycle_below(10), Some(7));
+        assert_eq!(longest_recurring_cycle_below(3), Some(1));
+        assert_eq!(longest_recurring_cycle_below(4), Some(3));
+        assert_eq!(longest_recurring_cycle_below(1), None);
+    }
+}
+
+#[cfg(test)]
+mod continued_fraction_decimal_tests {
+    use bens_number_theory::rationals::{
+        continued_fraction_decimal, from_continued_fraction, ratio_to_str_prec,
+    };
+    use num::{BigInt, BigRational};
+
+    fn coeffs(values: &[i64]) -> Vec<BigInt> {
+        values.iter().map(|&v| BigInt::from(v)).collect()
+    }
+
+    #[test]
+    fn from_continued_fraction_test() {
+        assert_eq!(
+            from_continued_fraction(&coeffs(&[3, 7, 15, 1, 292])),
+            BigRational::new(BigInt::from(103993), BigInt::from(33102))
+        );
+        assert_eq!(
+            from_continued_fraction(&coeffs(&[5])),
+            BigRational::from_integer(BigInt::from(5))
+        );
+        // a zero inside the expansion is fine as long as nothing divides by it
+        assert_eq!(
+            from_continued_fraction(&coeffs(&[1, 0, 2])),
+            BigRational::from_integer(BigInt::from(3))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_continued_fraction_empty_test() {
+        from_continued_fraction(&[]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_continued_fraction_divide_by_zero_test() {
+        from_continued_fraction(&coeffs(&[1, 0]));
+    }
+
+    #[test]
+    fn continued_fraction_decimal_pi_test() {
+        let pi: Vec<BigInt> = coeffs(&[3, 7, 15, 1, 292]);
+        assert_eq!(continued_fraction_decimal(&pi, 0), "3");
+        assert_eq!(continued_fraction_decimal(&pi, 2), "3.14");
+        assert_eq!(continued_fraction_decimal(&pi, 5), "3.14159");
+        assert_eq!(continued_fraction_decimal(&pi, 9), "3.141592653");
+    }
+
+    #[test]
+    fn continued_fraction_decimal_sqrt_two_test() {
+        // [1; 2, 2, ...] converges to the square root of 2
+        let mut values: Vec<i64> = vec![1];
+        values.extend(std::iter::repeat_n(2, 20));
+        assert_eq!(
+            continued_fraction_decimal(&coeffs(&values), 10),
+            "1.4142135623"
+        );
+    }
+
+    #[test]
+    fn ratio_to_str_prec_test() {
+        let r = |n: i64, d: i64| BigRational::new(BigInt::from(n), BigInt::from(d));
+        assert_eq!(ratio_to_str_prec(&r(1, 40), 3), "0.025");
+        assert_eq!(ratio_to_str_prec(&r(1, 40), 5), "0.02500");
+        assert_eq!(ratio_to_str_prec(&r(1, 40), 1), "0.0");
+        assert_eq!(ratio_to_str_prec(&r(2, 3), 4), "0.6666");
+        assert_eq!(ratio_to_str_prec(&r(-1, 8), 3), "-0.125");
+        assert_eq!(ratio_to_str_prec(&r(-1, 8), 1), "-0.1");
+        assert_eq!(ratio_to_str_prec(&r(-1, 80), 1), "0.0");
+        assert_eq!(ratio_to_str_prec(&r(1234, 1), 2), "1234.00");
+        assert_eq!(ratio_to_str_prec(&r(0, 1), 0), "0");
+    }
+}
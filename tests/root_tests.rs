@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod integer_nth_root_tests {
+    use bens_number_theory::roots::integer_nth_root;
+
+    #[test]
+    fn integer_nth_root_test() {
+        assert_eq!(integer_nth_root(0, 3), 0);
+        assert_eq!(integer_nth_root(1, 3), 1);
+        assert_eq!(integer_nth_root(7, 3), 1);
+        assert_eq!(integer_nth_root(8, 3), 2);
+        assert_eq!(integer_nth_root(26, 3), 2);
+        assert_eq!(integer_nth_root(27, 3), 3);
+        assert_eq!(integer_nth_root(12345, 1), 12345);
+        assert_eq!(integer_nth_root(100, 64), 1);
+    }
+
+    #[test]
+    fn integer_nth_root_powers_test() {
+        for k in 2..=10_u32 {
+            for r in 2..100_u64 {
+                let Some(power) = r.checked_pow(k) else {
+                    break;
+                };
+                assert_eq!(integer_nth_root(power, k), r);
+                assert_eq!(integer_nth_root(power - 1, k), r - 1);
+                assert_eq!(integer_nth_root(power + 1, k), r);
+            }
+        }
+    }
+
+    #[test]
+    fn integer_nth_root_max_test() {
+        assert_eq!(integer_nth_root(u64::MAX, 2), 4294967295);
+        assert_eq!(integer_nth_root(u64::MAX, 3), 2642245);
+        assert_eq!(integer_nth_root(u64::MAX, 63), 2);
+        assert_eq!(integer_nth_root(u64::MAX, 64), 1);
+        assert_eq!(integer_nth_root(u64::MAX, 1000), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn integer_nth_root_zero_test() {
+        integer_nth_root(10, 0);
+    }
+}
+
+#[cfg(test)]
+mod isqrt_tests {
+    use bens_number_theory::roots::isqrt;
+    use num::BigInt;
+
+    #[test]
+    fn isqrt_small_test() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(1), 1);
+        assert_eq!(isqrt(2), 1);
+        assert_eq!(isqrt(3), 1);
+        assert_eq!(isqrt(4), 2);
+    }
+
+    #[test]
+    fn isqrt_around_squares_test() {
+        for r in 2..2000_u64 {
+            let square: u64 = r * r;
+            assert_eq!(isqrt(square), r);
+            assert_eq!(isqrt(square - 1), r - 1);
+            assert_eq!(isqrt(square + 1), r);
+        }
+    }
+
+    #[test]
+    fn isqrt_limits_test() {
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+        assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+        assert_eq!(isqrt(i32::MAX), 46340);
+        assert_eq!(isqrt(255_u8), 15);
+    }
+
+    #[test]
+    fn isqrt_big_test() {
+        let r: BigInt = BigInt::from(10).pow(30) + 7;
+        let square: BigInt = &r * &r;
+        assert_eq!(isqrt(square.clone()), r);
+        assert_eq!(isqrt(&square - 1), &r - 1);
+        assert_eq!(isqrt(&square + 1), r);
+    }
+
+    #[test]
+    #[should_panic]
+    fn isqrt_negative_test() {
+        isqrt(-4);
+    }
+}
@@ -0,0 +1,518 @@
+#[cfg(test)]
+mod catalan_tests {
+    use bens_number_theory::modular::mod_pow;
+    use bens_number_theory::sequences::{catalan_mod_prime, catalan_nth};
+    use num::BigInt;
+
+    #[test]
+    fn catalan_nth_test() {
+        let catalan: Vec<BigInt> = (0..10).map(catalan_nth).collect();
+        let expected: Vec<BigInt> = [1, 1, 2, 5, 14, 42, 132, 429, 1430, 4862]
+            .iter()
+            .map(|&c| BigInt::from(c))
+            .collect();
+        assert_eq!(catalan, expected);
+    }
+
+    #[test]
+    fn catalan_mod_prime_matches_catalan_nth_test() {
+        for p in [2, 3, 5, 7, 13, 101, 1000000007_u64] {
+            for n in 0..200 {
+                let expected: BigInt = catalan_nth(n) % p;
+                assert_eq!(BigInt::from(catalan_mod_prime(n, p)), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn catalan_mod_prime_large_test() {
+        // the same value built from the ratio of consecutive Catalan numbers
+        let p: u64 = 1000000007;
+        let n: u64 = 1000000;
+        let mut expected: u64 = 1;
+        for k in 0..n {
+            let numerator: u64 = 2 * (2 * k + 1) % p;
+            let inverse: u64 = mod_pow(k + 2, p - 2, p);
+            expected = expected * numerator % p * inverse % p;
+        }
+        assert_eq!(catalan_mod_prime(n, p), expected);
+    }
+
+    #[test]
+    fn catalan_mod_prime_limits_test() {
+        // C_n is odd exactly when n + 1 is a power of 2
+        assert_eq!(catalan_mod_prime(u64::MAX / 2, 2), 1);
+        assert_eq!(catalan_mod_prime(u64::MAX / 2 - 1, 2), 0);
+        // the largest prime below 2^64
+        assert_eq!(catalan_mod_prime(5, 18446744073709551557), 42);
+        assert_eq!(catalan_mod_prime(0, 18446744073709551557), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn catalan_mod_prime_index_overflow_test() {
+        catalan_mod_prime(u64::MAX / 2 + 1, 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn catalan_mod_prime_modulus_one_test() {
+        catalan_mod_prime(5, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn catalan_mod_prime_modulus_zero_test() {
+        catalan_mod_prime(5, 0);
+    }
+}
+
+#[cfg(test)]
+mod bernoulli_tests {
+    use bens_number_theory::sequences::bernoulli;
+    use num::{BigInt, BigRational, Zero};
+
+    fn ratio(n: i64, d: i64) -> BigRational {
+        BigRational::new(BigInt::from(n), BigInt::from(d))
+    }
+
+    #[test]
+    fn bernoulli_test() {
+        assert_eq!(bernoulli(0), ratio(1, 1));
+        assert_eq!(bernoulli(1), ratio(-1, 2));
+        assert_eq!(bernoulli(2), ratio(1, 6));
+        assert_eq!(bernoulli(4), ratio(-1, 30));
+        assert_eq!(bernoulli(6), ratio(1, 42));
+        assert_eq!(bernoulli(10), ratio(5, 66));
+        assert_eq!(bernoulli(12), ratio(-691, 2730));
+        assert_eq!(bernoulli(20), ratio(-174611, 330));
+    }
+
+    #[test]
+    fn bernoulli_odd_test() {
+        for n in (3..30).step_by(2) {
+            assert!(bernoulli(n).is_zero());
+        }
+    }
+}
+
+#[cfg(test)]
+mod sum_of_powers_tests {
+    use bens_number_theory::sequences::sum_of_powers;
+    use num::BigInt;
+
+    #[test]
+    fn sum_of_powers_test() {
+        assert_eq!(sum_of_powers(10, 1), BigInt::from(55));
+        assert_eq!(sum_of_powers(5, 2), BigInt::from(55));
+        assert_eq!(sum_of_powers(0, 3), BigInt::from(0));
+        assert_eq!(sum_of_powers(1, 7), BigInt::from(1));
+    }
+
+    #[test]
+    fn sum_of_powers_direct_test() {
+        for p in 0..=3_u32 {
+            for n in 0..50_u64 {
+                let expected: BigInt = (1..=n).map(|k| BigInt::from(k).pow(p)).sum();
+                assert_eq!(sum_of_powers(n, p), expected, "n = {}, p = {}", n, p);
+            }
+        }
+    }
+
+    #[test]
+    fn sum_of_cubes_test() {
+        for n in [10_u64, 1000, 123456789] {
+            let triangle: BigInt = BigInt::from(n) * BigInt::from(n + 1) / 2;
+            assert_eq!(sum_of_powers(n, 3), triangle.pow(2));
+        }
+    }
+
+    #[test]
+    fn sum_of_high_powers_test() {
+        let expected: BigInt = (1..=20_u64).map(|k| BigInt::from(k).pow(10)).sum();
+        assert_eq!(sum_of_powers(20, 10), expected);
+    }
+}
+
+#[cfg(test)]
+mod fibonacci_gcd_tests {
+    use bens_number_theory::gcd::gcd_big;
+    use bens_number_theory::sequences::{fibonacci_gcd, fibonacci_nth};
+    use num::BigInt;
+
+    fn fib(n: u64) -> BigInt {
+        fibonacci_nth(BigInt::from(n))
+    }
+
+    #[test]
+    fn fibonacci_gcd_test() {
+        assert_eq!(fibonacci_gcd(12, 18), BigInt::from(8));
+        assert_eq!(fibonacci_gcd(7, 11), BigInt::from(1));
+        assert_eq!(fibonacci_gcd(0, 10), BigInt::from(55));
+        assert_eq!(fibonacci_gcd(0, 0), BigInt::from(0));
+    }
+
+    #[test]
+    fn fibonacci_gcd_identity_test() {
+        for (m, n) in [
+            (12, 18),
+            (100, 75),
+            (1000, 750),
+            (997, 991),
+            (2048, 1536),
+            (3003, 1001),
+        ] {
+            assert_eq!(
+                fibonacci_gcd(m, n),
+                gcd_big(&fib(m), &fib(n)),
+                "({}, {})",
+                m,
+                n
+            );
+        }
+        for m in 0..40 {
+            for n in 0..40 {
+                assert_eq!(fibonacci_gcd(m, n), gcd_big(&fib(m), &fib(n)));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod fib_cache_tests {
+    use bens_number_theory::sequences::{fibonacci_nth, FibCache};
+    use num::BigInt;
+
+    #[test]
+    fn fib_cache_consistent_test() {
+        let mut cache: FibCache = FibCache::new();
+        let first: BigInt = cache.get(50);
+        assert_eq!(first, BigInt::from(12586269025_u64));
+        assert_eq!(cache.get(10), BigInt::from(55));
+        assert_eq!(cache.get(50), first);
+    }
+
+    #[test]
+    fn fib_cache_growth_test() {
+        let mut cache: FibCache = FibCache::new();
+        assert_eq!(cache.len(), 2);
+        cache.get(1);
+        assert_eq!(cache.len(), 2);
+        cache.get(50);
+        assert_eq!(cache.len(), 51);
+        // smaller queries don't shrink or grow the cache
+        cache.get(10);
+        assert_eq!(cache.len(), 51);
+        cache.get(60);
+        assert_eq!(cache.len(), 61);
+    }
+
+    #[test]
+    fn fib_cache_matches_fibonacci_nth_test() {
+        let mut cache: FibCache = FibCache::default();
+        for n in (0..300).rev() {
+            assert_eq!(cache.get(n), fibonacci_nth(BigInt::from(n)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod finite_difference_tests {
+    use bens_number_theory::sequences::{finite_differences, nth_finite_difference};
+    use num::BigInt;
+
+    fn big(values: &[i64]) -> Vec<BigInt> {
+        values.iter().map(|&x| BigInt::from(x)).collect()
+    }
+
+    #[test]
+    fn finite_differences_test() {
+        assert_eq!(finite_differences(&big(&[1, 4, 9, 16])), big(&[3, 5, 7]));
+        assert_eq!(finite_differences(&big(&[5, 2, -4])), big(&[-3, -6]));
+        assert_eq!(finite_differences(&big(&[7])), big(&[]));
+        assert_eq!(finite_differences(&[]), big(&[]));
+    }
+
+    #[test]
+    fn squares_second_difference_test() {
+        let squares: Vec<BigInt> = (0..20).map(|x| BigInt::from(x * x)).collect();
+        assert_eq!(
+            nth_finite_difference(&squares, 2),
+            vec![BigInt::from(2); 18]
+        );
+        assert_eq!(
+            nth_finite_difference(&squares, 3),
+            vec![BigInt::from(0); 17]
+        );
+    }
+
+    #[test]
+    fn cubes_third_difference_test() {
+        let cubes: Vec<BigInt> = (0..20).map(|x| BigInt::from(x * x * x)).collect();
+        assert_eq!(nth_finite_difference(&cubes, 3), vec![BigInt::from(6); 17]);
+    }
+
+    #[test]
+    fn nth_finite_difference_edge_test() {
+        let seq: Vec<BigInt> = big(&[1, 4, 9, 16]);
+        assert_eq!(nth_finite_difference(&seq, 0), seq);
+        assert_eq!(nth_finite_difference(&seq, 1), finite_differences(&seq));
+        assert_eq!(nth_finite_difference(&seq, 4), big(&[]));
+        assert_eq!(nth_finite_difference(&seq, 100), big(&[]));
+    }
+}
+
+#[cfg(test)]
+mod extrapolate_sequence_tests {
+    use bens_number_theory::sequences::{extrapolate_sequence, fibonacci_sequence};
+    use num::BigInt;
+
+    fn big(values: &[i64]) -> Vec<BigInt> {
+        values.iter().map(|&x| BigInt::from(x)).collect()
+    }
+
+    #[test]
+    fn extrapolate_squares_test() {
+        assert_eq!(
+            extrapolate_sequence(&big(&[1, 4, 9]), 4),
+            big(&[16, 25, 36, 49])
+        );
+        // extra terms don't change the answer
+        let squares: Vec<BigInt> = (0..10).map(|x| BigInt::from(x * x)).collect();
+        let next: Vec<BigInt> = (10..15).map(|x| BigInt::from(x * x)).collect();
+        assert_eq!(extrapolate_sequence(&squares, 5), next);
+    }
+
+    #[test]
+    fn extrapolate_cubes_test() {
+        assert_eq!(
+            extrapolate_sequence(&big(&[1, 8, 27, 64]), 3),
+            big(&[125, 216, 343])
+        );
+        let cubes: Vec<BigInt> = (-3_i64..3).map(|x| BigInt::from(x.pow(3))).collect();
+        let next: Vec<BigInt> = (3_i64..50).map(|x| BigInt::from(x.pow(3))).collect();
+        assert_eq!(extrapolate_sequence(&cubes, 47), next);
+    }
+
+    #[test]
+    fn extrapolate_low_degree_test() {
+        assert_eq!(extrapolate_sequence(&big(&[5]), 3), big(&[5, 5, 5]));
+        assert_eq!(extrapolate_sequence(&big(&[7, 7, 7]), 1), big(&[7]));
+        assert_eq!(extrapolate_sequence(&big(&[10, 7]), 3), big(&[4, 1, -2]));
+        assert_eq!(extrapolate_sequence(&big(&[1, 2]), 0), big(&[]));
+    }
+
+    #[test]
+    fn extrapolate_non_polynomial_test() {
+        // continues the quartic through 0, 1, 1, 2, 3 rather than the Fibonacci numbers
+        let fib: Vec<BigInt> = fibonacci_sequence(BigInt::from(5));
+        assert_eq!(extrapolate_sequence(&fib, 1), big(&[0]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn extrapolate_empty_test() {
+        extrapolate_sequence(&[], 1);
+    }
+}
+
+#[cfg(test)]
+mod left_factorial_tests {
+    use bens_number_theory::factorials::factorial;
+    use bens_number_theory::sequences::left_factorial_sequence;
+    use num::BigInt;
+    use std::str::FromStr;
+
+    #[test]
+    fn left_factorial_sequence_test() {
+        let expected: Vec<BigInt> = [
+            0, 1, 2, 4, 10, 34, 154, 874, 5914, 46234, 409114, 4037914, 43954714,
+        ]
+        .into_iter()
+        .map(BigInt::from)
+        .collect();
+        assert_eq!(left_factorial_sequence(BigInt::from(13)), expected);
+        assert_eq!(left_factorial_sequence(BigInt::from(0)), vec![]);
+        assert_eq!(
+            left_factorial_sequence(BigInt::from(1)),
+            vec![BigInt::from(0)]
+        );
+    }
+
+    #[test]
+    fn left_factorial_large_test() {
+        let left: Vec<BigInt> = left_factorial_sequence(BigInt::from(26));
+        assert_eq!(
+            left[25],
+            BigInt::from_str("647478071469567844940314").unwrap()
+        );
+        for k in 1..left.len() {
+            assert_eq!(&left[k] - &left[k - 1], factorial(BigInt::from(k - 1)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod fibonacci_word_tests {
+    use bens_number_theory::sequences::{fibonacci_nth, fibonacci_word};
+    use num::BigInt;
+
+    #[test]
+    fn fibonacci_word_test() {
+        assert_eq!(fibonacci_word(0), "0");
+        assert_eq!(fibonacci_word(1), "01");
+        assert_eq!(fibonacci_word(2), "010");
+        assert_eq!(fibonacci_word(3), "01001");
+        assert_eq!(fibonacci_word(4), "01001010");
+        assert_eq!(fibonacci_word(5), "0100101001001");
+    }
+
+    #[test]
+    fn fibonacci_word_length_test() {
+        for n in 0..25 {
+            assert_eq!(
+                BigInt::from(fibonacci_word(n).len()),
+                fibonacci_nth(BigInt::from(n + 2))
+            );
+        }
+    }
+
+    #[test]
+    fn fibonacci_word_prefix_test() {
+        // each word starts with the one before it
+        for n in 1..20 {
+            assert!(fibonacci_word(n).starts_with(&fibonacci_word(n - 1)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod look_and_say_tests {
+    use bens_number_theory::sequences::look_and_say;
+
+    #[test]
+    fn look_and_say_test() {
+        assert_eq!(
+            look_and_say("1", 8),
+            [
+                "1",
+                "11",
+                "21",
+                "1211",
+                "111221",
+                "312211",
+                "13112221",
+                "1113213211"
+            ]
+        );
+        assert_eq!(look_and_say("1", 0), Vec::<String>::new());
+        assert_eq!(look_and_say("3", 3), ["3", "13", "1113"]);
+        assert_eq!(look_and_say("22", 3), ["22", "22", "22"]);
+        assert_eq!(look_and_say("1111111111", 2), ["1111111111", "101"]);
+    }
+
+    #[test]
+    fn look_and_say_growth_test() {
+        // Conway's constant
+        let lambda: f64 = 1.303577269;
+        let terms: Vec<String> = look_and_say("1", 50);
+        let ratio: f64 = terms[49].len() as f64 / terms[48].len() as f64;
+        assert!((ratio - lambda).abs() < 0.01, "{}", ratio);
+        let ratio: f64 = (terms[49].len() as f64 / terms[39].len() as f64).powf(0.1);
+        assert!((ratio - lambda).abs() < 0.001, "{}", ratio);
+    }
+
+    #[test]
+    #[should_panic]
+    fn look_and_say_invalid_seed_test() {
+        look_and_say("12a", 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn look_and_say_empty_seed_test() {
+        look_and_say("", 3);
+    }
+}
+
+#[cfg(test)]
+mod stern_diatomic_tests {
+    use bens_number_theory::gcd::gcd;
+    use bens_number_theory::sequences::stern_diatomic;
+    use std::collections::HashSet;
+
+    #[test]
+    fn stern_diatomic_test() {
+        assert_eq!(
+            stern_diatomic(20),
+            [0, 1, 1, 2, 1, 3, 2, 3, 1, 4, 3, 5, 2, 5, 3, 4, 1, 5, 4, 7]
+        );
+        assert_eq!(stern_diatomic(0), []);
+        assert_eq!(stern_diatomic(1), [0]);
+        // a(2^k) = 1
+        let a: Vec<u64> = stern_diatomic(1025);
+        assert_eq!(a[1024], 1);
+    }
+
+    #[test]
+    fn stern_diatomic_coprime_test() {
+        for pair in stern_diatomic(10000).windows(2) {
+            assert_eq!(gcd(pair[0], pair[1]), 1);
+        }
+    }
+
+    #[test]
+    fn stern_diatomic_rationals_test() {
+        // a(n) / a(n + 1) never repeats, and every reduced p / q with p, q <= 4 shows up
+        let a: Vec<u64> = stern_diatomic(1 << 12);
+        let fractions: HashSet<(u64, u64)> = a[1..].windows(2).map(|w| (w[0], w[1])).collect();
+        assert_eq!(fractions.len(), a.len() - 2);
+        for p in 1..=4 {
+            for q in 1..=4 {
+                if gcd(p, q) == 1 {
+                    assert!(fractions.contains(&(p, q)));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod fibonacci_nth_tests {
+    use bens_number_theory::sequences::{fibonacci_nth, fibonacci_sequence};
+    use num::BigInt;
+
+    #[test]
+    fn fibonacci_nth_matches_sequence_test() {
+        let sequence: Vec<BigInt> = fibonacci_sequence(BigInt::from(20));
+        assert_eq!(sequence.len(), 20);
+        for (n, f) in sequence.iter().enumerate() {
+            assert_eq!(fibonacci_nth(BigInt::from(n)), *f);
+        }
+    }
+
+    #[test]
+    fn fibonacci_nth_small_test() {
+        assert_eq!(fibonacci_nth(BigInt::from(0)), BigInt::from(0));
+        assert_eq!(fibonacci_nth(BigInt::from(1)), BigInt::from(1));
+        assert_eq!(fibonacci_nth(BigInt::from(2)), BigInt::from(1));
+        assert_eq!(
+            fibonacci_nth(BigInt::from(93)),
+            BigInt::from(12200160415121876738_u64)
+        );
+    }
+
+    #[test]
+    fn fibonacci_nth_thousand_test() {
+        let f: String = fibonacci_nth(BigInt::from(1000)).to_string();
+        assert_eq!(f.len(), 209);
+        assert!(f.starts_with("43466557686937456435"));
+        assert!(f.ends_with("76137795166849228875"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fibonacci_nth_negative_test() {
+        fibonacci_nth(BigInt::from(-1));
+    }
+}
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod perfect_square_tests {
+    use bens_number_theory::squares::is_perfect_square;
+
+    #[test]
+    fn perfect_square_test() {
+        let squares: Vec<u64> = (0..100).filter(|&n| is_perfect_square(n)).collect();
+        assert_eq!(squares, vec![0, 1, 4, 9, 16, 25, 36, 49, 64, 81]);
+        assert!(is_perfect_square(4294967295 * 4294967295));
+        assert!(!is_perfect_square(u64::MAX));
+    }
+}
+
+#[cfg(test)]
+mod sum_of_squares_tests {
+    use bens_number_theory::squares::{four_squares, sum_of_two_squares, three_squares};
+
+    #[test]
+    fn sum_of_two_squares_test() {
+        assert_eq!(sum_of_two_squares(0), Some((0, 0)));
+        assert_eq!(sum_of_two_squares(2), Some((1, 1)));
+        assert_eq!(sum_of_two_squares(50), Some((1, 7)));
+        assert_eq!(sum_of_two_squares(3), None);
+        assert_eq!(sum_of_two_squares(12), None);
+    }
+
+    #[test]
+    fn three_squares_test() {
+        assert_eq!(three_squares(0), Some((0, 0, 0)));
+        assert!(three_squares(6).is_some());
+        assert_eq!(three_squares(7), None);
+        assert_eq!(three_squares(15), None);
+        assert_eq!(three_squares(112), None); // 16 * 7
+    }
+
+    #[test]
+    fn three_squares_legendre_test() {
+        for n in 0..2000 {
+            let mut m: u64 = n;
+            while m != 0 && m.is_multiple_of(4) {
+                m /= 4;
+            }
+            match three_squares(n) {
+                Some((a, b, c)) => {
+                    assert_ne!(m % 8, 7);
+                    assert!(a <= b && b <= c);
+                    assert_eq!(a * a + b * b + c * c, n);
+                }
+                None => assert_eq!(m % 8, 7),
+            }
+        }
+    }
+
+    #[test]
+    fn four_squares_test() {
+        let (a, b, c, d) = four_squares(7);
+        assert_eq!(a * a + b * b + c * c + d * d, 7);
+        assert_eq!(four_squares(0), (0, 0, 0, 0));
+        for n in 0..2000 {
+            let (a, b, c, d) = four_squares(n);
+            assert!(a <= b && b <= c && c <= d);
+            assert_eq!(a * a + b * b + c * c + d * d, n);
+        }
+    }
+}
+
+#[cfg(test)]
+mod taxicab_tests {
+    use bens_number_theory::squares::{smallest_taxicab, taxicab_representations};
+
+    #[test]
+    fn taxicab_representations_test() {
+        assert_eq!(taxicab_representations(1729), vec![(1, 12), (9, 10)]);
+        assert_eq!(taxicab_representations(4104), vec![(2, 16), (9, 15)]);
+        assert_eq!(
+            taxicab_representations(87539319),
+            vec![(167, 436), (228, 423), (255, 414)]
+        );
+        assert_eq!(taxicab_representations(2), vec![(1, 1)]);
+        assert_eq!(taxicab_representations(16), vec![(2, 2)]);
+        assert_eq!(taxicab_representations(0), vec![]);
+        assert_eq!(taxicab_representations(1), vec![]);
+        assert_eq!(taxicab_representations(1728), vec![]);
+    }
+
+    #[test]
+    fn taxicab_representations_brute_force_test() {
+        for n in 0..5000_u64 {
+            let mut expected: Vec<(u64, u64)> = vec![];
+            for a in 1..20_u64 {
+                for b in a..20 {
+                    if a.pow(3) + b.pow(3) == n {
+                        expected.push((a, b));
+                    }
+                }
+            }
+            assert_eq!(taxicab_representations(n), expected, "{}", n);
+        }
+    }
+
+    #[test]
+    fn smallest_taxicab_test() {
+        assert_eq!(smallest_taxicab(1), 2);
+        assert_eq!(smallest_taxicab(2), 1729);
+        assert_eq!(smallest_taxicab(3), 87539319);
+    }
+
+    #[test]
+    #[should_panic]
+    fn smallest_taxicab_zero_test() {
+        smallest_taxicab(0);
+    }
+}
@@ -0,0 +1,185 @@
+#[cfg(test)]
+mod totatives_tests {
+    use bens_number_theory::totient::totatives;
+
+    #[test]
+    fn totatives_test() {
+        assert_eq!(totatives(10), vec![1, 3, 7, 9]);
+        assert_eq!(totatives(12_u64), vec![1, 5, 7, 11]);
+        assert_eq!(totatives(1), vec![1]);
+        assert_eq!(totatives(0), vec![]);
+    }
+
+    #[test]
+    fn totatives_prime_test() {
+        assert_eq!(totatives(13), (1..13).collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn totatives_count_test() {
+        // A000010, phi(1) through phi(20)
+        let phi: [usize; 20] = [
+            1, 1, 2, 2, 4, 2, 6, 4, 6, 4, 10, 4, 12, 6, 8, 8, 16, 6, 18, 8,
+        ];
+        for (i, count) in phi.iter().enumerate() {
+            assert_eq!(totatives(i + 1).len(), *count);
+        }
+    }
+}
+
+#[cfg(test)]
+mod totient_sieve_tests {
+    use bens_number_theory::totient::{totatives, totient_sieve};
+
+    #[test]
+    fn totient_sieve_test() {
+        assert_eq!(totient_sieve(0), vec![0]);
+        assert_eq!(totient_sieve(1), vec![0, 1]);
+        assert_eq!(
+            totient_sieve(12),
+            vec![0, 1, 1, 2, 2, 4, 2, 6, 4, 6, 4, 10, 4]
+        );
+    }
+
+    #[test]
+    fn totient_sieve_matches_totatives_test() {
+        let phi: Vec<u64> = totient_sieve(500);
+        for n in 1..=500_u64 {
+            assert_eq!(phi[n as usize], totatives(n).len() as u64);
+        }
+    }
+}
+
+#[cfg(test)]
+mod coprime_pairs_tests {
+    use bens_number_theory::gcd::gcd;
+    use bens_number_theory::totient::coprime_pairs_below;
+
+    #[test]
+    fn coprime_pairs_test() {
+        assert_eq!(coprime_pairs_below(0), 0);
+        assert_eq!(coprime_pairs_below(1), 0);
+        assert_eq!(coprime_pairs_below(2), 1);
+        assert_eq!(coprime_pairs_below(5), 9);
+    }
+
+    #[test]
+    fn coprime_pairs_brute_force_test() {
+        for n in 0..=60_u64 {
+            let mut count: u64 = 0;
+            for b in 1..=n {
+                for a in 1..b {
+                    if gcd(a, b) == 1 {
+                        count += 1;
+                    }
+                }
+            }
+            assert_eq!(coprime_pairs_below(n), count);
+        }
+    }
+
+    #[test]
+    fn coprime_probability_test() {
+        // the chance two numbers are coprime tends to 6 / pi^2 = 0.6079...
+        let n: u64 = 10000;
+        let pairs: f64 = (n * (n - 1) / 2) as f64;
+        let ratio: f64 = coprime_pairs_below(n) as f64 / pairs;
+        assert!((ratio - 6.0 / std::f64::consts::PI.powi(2)).abs() < 0.001);
+    }
+}
+
+#[cfg(test)]
+mod totient_sum_tests {
+    use bens_number_theory::totient::{euler_totient, totient_sum, totient_sum_fast};
+
+    #[test]
+    fn totient_sum_test() {
+        assert_eq!(totient_sum(0), 0);
+        assert_eq!(totient_sum(1), 1);
+        assert_eq!(totient_sum(10), 32);
+        assert_eq!(totient_sum(100), 3044);
+        let direct: u64 = (1..=500_u64).map(euler_totient).sum();
+        assert_eq!(totient_sum(500), direct);
+    }
+
+    #[test]
+    fn totient_sum_fast_matches_sieve_test() {
+        for n in 0..=10000 {
+            assert_eq!(totient_sum_fast(n), totient_sum(n), "{}", n);
+        }
+    }
+
+    #[test]
+    fn totient_sum_fast_large_test() {
+        assert_eq!(totient_sum_fast(1_000_000), totient_sum(1_000_000));
+        assert_eq!(totient_sum_fast(1_000_000), 303963552392);
+        assert_eq!(totient_sum_fast(1_000_000_000), 303963551173008414);
+    }
+}
+
+#[cfg(test)]
+mod totient_iter_tests {
+    use bens_number_theory::totient::{euler_totient, totient_iter, totient_sieve};
+
+    #[test]
+    fn totient_iter_test() {
+        let phi: Vec<u64> = totient_iter().take(6).collect();
+        assert_eq!(phi, vec![1, 1, 2, 2, 4, 2]);
+    }
+
+    #[test]
+    fn totient_iter_matches_euler_totient_test() {
+        for (n, phi) in (1..=20_u64).zip(totient_iter()) {
+            assert_eq!(phi, euler_totient(n), "{}", n);
+        }
+    }
+
+    #[test]
+    fn totient_iter_grows_test() {
+        // runs past several resizes of the sieve
+        let phi: Vec<u64> = totient_iter().take(10000).collect();
+        assert_eq!(phi[..], totient_sieve(10000)[1..]);
+    }
+}
+
+#[cfg(test)]
+mod euler_totient_tests {
+    use bens_number_theory::totient::euler_totient;
+
+    #[test]
+    fn euler_totient_test() {
+        assert_eq!(euler_totient(1), 1);
+        assert_eq!(euler_totient(12), 4);
+        assert_eq!(euler_totient(36), 12);
+        assert_eq!(euler_totient(64), 32);
+        assert_eq!(euler_totient(0), 0);
+        assert_eq!(euler_totient(-5), 0);
+    }
+
+    #[test]
+    fn euler_totient_prime_test() {
+        for p in [2, 3, 5, 7, 11, 97, 7919] {
+            assert_eq!(euler_totient(p), p - 1);
+        }
+        assert_eq!(euler_totient(1_000_000_007_u64), 1_000_000_006);
+        assert_eq!(euler_totient(4_294_967_291_u64), 4_294_967_290);
+    }
+
+    #[test]
+    fn euler_totient_rsa_test() {
+        // an RSA modulus n = pq has phi(n) = (p - 1)(q - 1)
+        let (p, q): (u64, u64) = (65_521, 65_537);
+        assert_eq!(euler_totient(p * q), (p - 1) * (q - 1));
+    }
+
+    #[test]
+    fn euler_totient_multiplicative_test() {
+        for a in 1..40_u32 {
+            for b in 1..40_u32 {
+                if num::integer::gcd(a, b) == 1 {
+                    assert_eq!(euler_totient(a * b), euler_totient(a) * euler_totient(b));
+                }
+            }
+        }
+    }
+}
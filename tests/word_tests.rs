@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod number_to_words_tests {
+    use bens_number_theory::words::number_to_words;
+
+    #[test]
+    fn number_to_words_test() {
+        assert_eq!(number_to_words(0), "zero");
+        assert_eq!(number_to_words(21), "twenty-one");
+        assert_eq!(number_to_words(100), "one hundred");
+        assert_eq!(number_to_words(115), "one hundred fifteen");
+        assert_eq!(number_to_words(342), "three hundred forty-two");
+        assert_eq!(number_to_words(1000), "one thousand");
+    }
+
+    #[test]
+    fn large_number_test() {
+        assert_eq!(
+            number_to_words(1_234_567_890),
+            "one billion two hundred thirty-four million five hundred sixty-seven thousand eight hundred ninety"
+        );
+        assert_eq!(number_to_words(7_000_000_000_005), "seven trillion five");
+        assert_eq!(
+            number_to_words(u64::MAX),
+            "eighteen quintillion four hundred forty-six quadrillion seven hundred forty-four trillion \
+             seventy-three billion seven hundred nine million five hundred fifty-one thousand six hundred fifteen"
+        );
+    }
+}
+
+#[cfg(test)]
+mod number_to_words_british_tests {
+    use bens_number_theory::words::number_to_words_british;
+
+    #[test]
+    fn number_to_words_british_test() {
+        assert_eq!(number_to_words_british(0), "zero");
+        assert_eq!(number_to_words_british(21), "twenty-one");
+        assert_eq!(number_to_words_british(100), "one hundred");
+        assert_eq!(number_to_words_british(115), "one hundred and fifteen");
+        assert_eq!(number_to_words_british(342), "three hundred and forty-two");
+        assert_eq!(number_to_words_british(1000), "one thousand");
+        assert_eq!(number_to_words_british(1001), "one thousand and one");
+        assert_eq!(
+            number_to_words_british(2_000_110),
+            "two million one hundred and ten"
+        );
+    }
+
+    #[test]
+    fn letter_count_test() {
+        // Project Euler 17
+        let letters: usize = (1..=1000)
+            .map(|n| {
+                number_to_words_british(n)
+                    .chars()
+                    .filter(|c| c.is_alphabetic())
+                    .count()
+            })
+            .sum();
+        assert_eq!(letters, 21124);
+    }
+}